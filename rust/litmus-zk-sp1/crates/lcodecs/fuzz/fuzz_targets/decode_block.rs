@@ -0,0 +1,10 @@
+#![no_main]
+
+use lcodecs::binary::Decode;
+use libfuzzer_sys::fuzz_target;
+use ltypeset::chain::Block;
+
+// Decoding arbitrary bytes must fail gracefully, i.e. never panic.
+fuzz_target!(|data: &[u8]| {
+    let _ = Block::decode(data);
+});
@@ -4,12 +4,14 @@ use super::super::{
 };
 use ltypeset::{
     chain::{
-        Block, BlockHash, BlockHeight, BlockV1, BlockV1Body, BlockV1Header, BlockV2, BlockV2Body,
-        BlockV2Header, EraEndV2, EraId, ProtocolVersion,
+        Block, BlockHash, BlockHeight, BlockV1, BlockV1Body, BlockV1Header, BlockV1HeaderFields,
+        BlockV2, BlockV2Body, BlockV2Header, EraEndV1, EraEndV2, EraId, ProtocolVersion,
+        TransactionHash, TransactionV1Hash,
     },
     crypto::{Digest, PublicKey},
     primitives::Timestamp,
 };
+use std::collections::BTreeMap;
 
 // ------------------------------------------------------------------------
 // Constants.
@@ -24,17 +26,17 @@ const TAG_BLOCK_V2: u8 = 1;
 
 impl Decode for Block {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (version_tag, bstream) = u8::decode(bstream).unwrap();
+        let (version_tag, bstream) = u8::decode(bstream)?;
         let (block, bstream) = match version_tag {
             TAG_BLOCK_V1 => {
-                let (inner, bstream) = BlockV1::decode(bstream).unwrap();
+                let (inner, bstream) = BlockV1::decode(bstream)?;
                 (Block::new_v1(inner), bstream)
             }
             TAG_BLOCK_V2 => {
-                let (inner, bstream) = BlockV2::decode(bstream).unwrap();
+                let (inner, bstream) = BlockV2::decode(bstream)?;
                 (Block::new_v2(inner), bstream)
             }
-            _ => panic!("Invalid block version tag"),
+            _ => return Err(CodecError::Formatting),
         };
 
         Ok((block, bstream))
@@ -54,11 +56,11 @@ impl Encode for Block {
         match self {
             Block::V1(inner) => {
                 writer.push(TAG_BLOCK_V1);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
             Block::V2(inner) => {
                 writer.push(TAG_BLOCK_V2);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
         }
 
@@ -72,7 +74,7 @@ impl Encode for Block {
 
 impl Decode for BlockHash {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = Digest::decode(bstream).unwrap();
+        let (inner, bstream) = Digest::decode(bstream)?;
 
         Ok((BlockHash::new(inner), bstream))
     }
@@ -84,7 +86,7 @@ impl Encode for BlockHash {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
 
         Ok(())
     }
@@ -96,7 +98,7 @@ impl Encode for BlockHash {
 
 impl Decode for BlockHeight {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = u64::decode(&bstream).unwrap();
+        let (inner, bstream) = u64::decode(&bstream)?;
 
         Ok((Self::new(inner), &bstream))
     }
@@ -108,7 +110,7 @@ impl Encode for BlockHeight {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
 
         Ok(())
     }
@@ -120,9 +122,9 @@ impl Encode for BlockHeight {
 
 impl Decode for BlockV1 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (hash, bstream) = BlockHash::decode(bstream).unwrap();
-        let (header, bstream) = BlockV1Header::decode(bstream).unwrap();
-        let (body, bstream) = BlockV1Body::decode(bstream).unwrap();
+        let (hash, bstream) = BlockHash::decode(bstream)?;
+        let (header, bstream) = BlockV1Header::decode(bstream)?;
+        let (body, bstream) = BlockV1Body::decode(bstream)?;
 
         Ok((BlockV1::new(body, hash, header), bstream))
     }
@@ -136,9 +138,9 @@ impl Encode for BlockV1 {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.hash().write_encoded(writer).unwrap();
-        self.header().write_encoded(writer).unwrap();
-        self.body().write_encoded(writer).unwrap();
+        self.hash().write_encoded(writer)?;
+        self.header().write_encoded(writer)?;
+        self.body().write_encoded(writer)?;
 
         Ok(())
     }
@@ -149,18 +151,31 @@ impl Encode for BlockV1 {
 // ------------------------------------------------------------------------
 
 impl Decode for BlockV1Body {
-    fn decode(_: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        unimplemented!();
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (proposer, bstream) = PublicKey::decode(bstream)?;
+        let (deploy_hashes, bstream) = Vec::<TransactionV1Hash>::decode(bstream)?;
+        let (transfer_hashes, bstream) = Vec::<TransactionV1Hash>::decode(bstream)?;
+
+        Ok((
+            BlockV1Body::new(deploy_hashes, proposer, transfer_hashes),
+            bstream,
+        ))
     }
 }
 
 impl Encode for BlockV1Body {
     fn get_encoded_size(&self) -> usize {
-        unimplemented!();
+        self.proposer().get_encoded_size()
+            + self.deploy_hashes().get_encoded_size()
+            + self.transfer_hashes().get_encoded_size()
     }
 
-    fn write_encoded(&self, _: &mut Vec<u8>) -> Result<(), CodecError> {
-        unimplemented!();
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.proposer().write_encoded(writer)?;
+        self.deploy_hashes().write_encoded(writer)?;
+        self.transfer_hashes().write_encoded(writer)?;
+
+        Ok(())
     }
 }
 
@@ -169,18 +184,62 @@ impl Encode for BlockV1Body {
 // ------------------------------------------------------------------------
 
 impl Decode for BlockV1Header {
-    fn decode(_: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        unimplemented!();
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (parent_hash, bstream) = BlockHash::decode(bstream)?;
+        let (state_root_hash, bstream) = Digest::decode(bstream)?;
+        let (body_hash, bstream) = Digest::decode(bstream)?;
+        let (random_bit, bstream) = bool::decode(bstream)?;
+        let (accumulated_seed, bstream) = Digest::decode(bstream)?;
+        let (era_end, bstream) = Option::<EraEndV1>::decode(bstream)?;
+        let (timestamp, bstream) = Timestamp::decode(bstream)?;
+        let (era_id, bstream) = EraId::decode(bstream)?;
+        let (height, bstream) = BlockHeight::decode(bstream)?;
+        let (protocol_version, bstream) = ProtocolVersion::decode(bstream)?;
+
+        Ok((
+            BlockV1Header::new(BlockV1HeaderFields {
+                accumulated_seed,
+                body_hash,
+                era_end,
+                era_id,
+                height,
+                parent_hash,
+                protocol_version,
+                random_bit,
+                state_root_hash,
+                timestamp,
+            }),
+            bstream,
+        ))
     }
 }
 
 impl Encode for BlockV1Header {
     fn get_encoded_size(&self) -> usize {
-        unimplemented!();
+        self.parent_hash().get_encoded_size()
+            + self.state_root_hash().get_encoded_size()
+            + self.body_hash().get_encoded_size()
+            + self.random_bit().get_encoded_size()
+            + self.accumulated_seed().get_encoded_size()
+            + self.era_end().get_encoded_size()
+            + self.timestamp().get_encoded_size()
+            + self.era_id().get_encoded_size()
+            + self.height().get_encoded_size()
+            + self.protocol_version().get_encoded_size()
     }
 
-    fn write_encoded(&self, _: &mut Vec<u8>) -> Result<(), CodecError> {
-        unimplemented!();
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.parent_hash().write_encoded(writer)?;
+        self.state_root_hash().write_encoded(writer)?;
+        self.body_hash().write_encoded(writer)?;
+        self.random_bit().write_encoded(writer)?;
+        self.accumulated_seed().write_encoded(writer)?;
+        self.era_end().write_encoded(writer)?;
+        self.timestamp().write_encoded(writer)?;
+        self.era_id().write_encoded(writer)?;
+        self.height().write_encoded(writer)?;
+        self.protocol_version().write_encoded(writer)?;
+        Ok(())
     }
 }
 
@@ -190,9 +249,9 @@ impl Encode for BlockV1Header {
 
 impl Decode for BlockV2 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (hash, bstream) = BlockHash::decode(bstream).unwrap();
-        let (header, bstream) = BlockV2Header::decode(bstream).unwrap();
-        let (body, bstream) = BlockV2Body::decode(bstream).unwrap();
+        let (hash, bstream) = BlockHash::decode(bstream)?;
+        let (header, bstream) = BlockV2Header::decode(bstream)?;
+        let (body, bstream) = BlockV2Body::decode(bstream)?;
 
         Ok((BlockV2::new(body, hash, header), bstream))
     }
@@ -206,9 +265,9 @@ impl Encode for BlockV2 {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.hash().write_encoded(writer).unwrap();
-        self.header().write_encoded(writer).unwrap();
-        self.body().write_encoded(writer).unwrap();
+        self.hash().write_encoded(writer)?;
+        self.header().write_encoded(writer)?;
+        self.body().write_encoded(writer)?;
 
         Ok(())
     }
@@ -219,8 +278,11 @@ impl Encode for BlockV2 {
 // ------------------------------------------------------------------------
 
 impl Decode for BlockV2Body {
-    fn decode(_: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        unimplemented!()
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (transactions, bstream) = BTreeMap::<u8, Vec<TransactionHash>>::decode(bstream)?;
+        let (rewarded_signatures, bstream) = Vec::<Vec<u8>>::decode(bstream)?;
+
+        Ok((BlockV2Body::new(rewarded_signatures, transactions), bstream))
     }
 }
 
@@ -230,8 +292,8 @@ impl Encode for BlockV2Body {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.transactions().write_encoded(writer).unwrap();
-        self.rewarded_signatures().write_encoded(writer).unwrap();
+        self.transactions().write_encoded(writer)?;
+        self.rewarded_signatures().write_encoded(writer)?;
 
         Ok(())
     }
@@ -243,19 +305,19 @@ impl Encode for BlockV2Body {
 
 impl Decode for BlockV2Header {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (parent_hash, bstream) = BlockHash::decode(bstream).unwrap();
-        let (state_root_hash, bstream) = Digest::decode(bstream).unwrap();
-        let (body_hash, bstream) = Digest::decode(bstream).unwrap();
-        let (random_bit, bstream) = bool::decode(bstream).unwrap();
-        let (accumulated_seed, bstream) = Digest::decode(bstream).unwrap();
-        let (era_end, bstream) = Option::<EraEndV2>::decode(bstream).unwrap();
-        let (timestamp, bstream) = Timestamp::decode(bstream).unwrap();
-        let (era_id, bstream) = EraId::decode(bstream).unwrap();
-        let (height, bstream) = BlockHeight::decode(bstream).unwrap();
-        let (protocol_version, bstream) = ProtocolVersion::decode(bstream).unwrap();
-        let (proposer, bstream) = PublicKey::decode(bstream).unwrap();
-        let (current_gas_price, bstream) = u8::decode(bstream).unwrap();
-        let (last_switch_block_hash, bstream) = Option::<BlockHash>::decode(bstream).unwrap();
+        let (parent_hash, bstream) = BlockHash::decode(bstream)?;
+        let (state_root_hash, bstream) = Digest::decode(bstream)?;
+        let (body_hash, bstream) = Digest::decode(bstream)?;
+        let (random_bit, bstream) = bool::decode(bstream)?;
+        let (accumulated_seed, bstream) = Digest::decode(bstream)?;
+        let (era_end, bstream) = Option::<EraEndV2>::decode(bstream)?;
+        let (timestamp, bstream) = Timestamp::decode(bstream)?;
+        let (era_id, bstream) = EraId::decode(bstream)?;
+        let (height, bstream) = BlockHeight::decode(bstream)?;
+        let (protocol_version, bstream) = ProtocolVersion::decode(bstream)?;
+        let (proposer, bstream) = PublicKey::decode(bstream)?;
+        let (current_gas_price, bstream) = u8::decode(bstream)?;
+        let (last_switch_block_hash, bstream) = Option::<BlockHash>::decode(bstream)?;
 
         Ok((
             BlockV2Header::new(
@@ -296,19 +358,166 @@ impl Encode for BlockV2Header {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.parent_hash().write_encoded(writer).unwrap();
-        self.state_root_hash().write_encoded(writer).unwrap();
-        self.body_hash().write_encoded(writer).unwrap();
-        self.random_bit().write_encoded(writer).unwrap();
-        self.accumulated_seed().write_encoded(writer).unwrap();
-        self.era_end().write_encoded(writer).unwrap();
-        self.timestamp().write_encoded(writer).unwrap();
-        self.era_id().write_encoded(writer).unwrap();
-        self.height().write_encoded(writer).unwrap();
-        self.protocol_version().write_encoded(writer).unwrap();
-        self.proposer().write_encoded(writer).unwrap();
-        self.current_gas_price().write_encoded(writer).unwrap();
-        self.last_switch_block_hash().write_encoded(writer).unwrap();
+        self.parent_hash().write_encoded(writer)?;
+        self.state_root_hash().write_encoded(writer)?;
+        self.body_hash().write_encoded(writer)?;
+        self.random_bit().write_encoded(writer)?;
+        self.accumulated_seed().write_encoded(writer)?;
+        self.era_end().write_encoded(writer)?;
+        self.timestamp().write_encoded(writer)?;
+        self.era_id().write_encoded(writer)?;
+        self.height().write_encoded(writer)?;
+        self.protocol_version().write_encoded(writer)?;
+        self.proposer().write_encoded(writer)?;
+        self.current_gas_price().write_encoded(writer)?;
+        self.last_switch_block_hash().write_encoded(writer)?;
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_block(block in Block::new_from_arb()) {
+            assert_codec(&block);
+        }
+
+        #[test]
+        fn codec_block_hash(block_hash in BlockHash::new_from_arb()) {
+            assert_codec(&block_hash);
+        }
+
+        #[test]
+        fn codec_block_height(block_height in BlockHeight::new_from_arb()) {
+            assert_codec(&block_height);
+        }
+
+        #[test]
+        fn codec_block_v1(block in BlockV1::new_from_arb()) {
+            assert_codec(&block);
+        }
+
+        #[test]
+        fn codec_block_v1_body(body in BlockV1Body::new_from_arb()) {
+            assert_codec(&body);
+        }
+
+        #[test]
+        fn codec_block_v1_header(header in BlockV1Header::new_from_arb()) {
+            assert_codec(&header);
+        }
+
+        #[test]
+        fn codec_block_v2(block in BlockV2::new_from_arb()) {
+            assert_codec(&block);
+        }
+
+        #[test]
+        fn codec_block_v2_body(body in BlockV2Body::new_from_arb()) {
+            assert_codec(&body);
+        }
+
+        #[test]
+        fn codec_block_v2_header(header in BlockV2Header::new_from_arb()) {
+            assert_codec(&header);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::fixtures;
+    use ltypeset::chain::TransactionV2Hash;
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    #[test]
+    fn test_codec_block_v2_body() {
+        let body = BlockV2Body::new(
+            vec![vec![248], vec![]],
+            BTreeMap::from([
+                (
+                    0,
+                    vec![TransactionHash::V1(TransactionV1Hash::new(Digest::from(
+                        DIGEST_HEX,
+                    )))],
+                ),
+                (
+                    3,
+                    vec![TransactionHash::V2(TransactionV2Hash::new(Digest::from(
+                        DIGEST_HEX,
+                    )))],
+                ),
+            ]),
+        );
+        let encoded = body.encode().unwrap();
+        assert_eq!(body.get_encoded_size(), encoded.len());
+
+        let (decoded, remainder) = BlockV2Body::decode(&encoded).unwrap();
+        assert_eq!(decoded, body);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn test_codec_fixture_blocks() {
+        for block in fixtures::get_blocks() {
+            let encoded = block.encode().unwrap();
+
+            assert_eq!(Block::decode_exact(&encoded), Ok(block));
+        }
+    }
+
+    #[test]
+    fn test_encoded_header_digest_is_block_hash() {
+        for block in fixtures::get_blocks() {
+            let encoded = match &block {
+                Block::V1(inner) => inner.header().encode(),
+                Block::V2(inner) => inner.header().encode(),
+            };
+
+            assert_eq!(
+                Digest::get_blake2b(encoded.unwrap()),
+                block.hash().inner().to_owned()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encoded_body_digest_is_header_body_hash() {
+        for block in fixtures::get_blocks() {
+            let (encoded, body_hash) = match &block {
+                Block::V1(inner) => (inner.body().encode(), inner.header().body_hash()),
+                Block::V2(inner) => (inner.body().encode(), inner.header().body_hash()),
+            };
+
+            assert_eq!(Digest::get_blake2b(encoded.unwrap()), *body_hash);
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_block_version_tag() {
+        assert_eq!(Block::decode(&[u8::MAX]), Err(CodecError::Formatting));
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_block_hash() {
+        let encoded = BlockHash::from(DIGEST_HEX).encode().unwrap();
+
+        assert_eq!(
+            BlockHash::decode(&encoded[..31]),
+            Err(CodecError::EarlyEndOfStream)
+        );
+    }
+}
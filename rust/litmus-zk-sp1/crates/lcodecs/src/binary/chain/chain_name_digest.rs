@@ -7,7 +7,7 @@ use ltypeset::{chain::ChainNameDigest, crypto::Digest};
 
 impl Decode for ChainNameDigest {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = Digest::decode(bstream).unwrap();
+        let (inner, bstream) = Digest::decode(bstream)?;
 
         Ok((ChainNameDigest::new(inner), bstream))
     }
@@ -19,7 +19,7 @@ impl Encode for ChainNameDigest {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
 
         Ok(())
     }
@@ -28,3 +28,19 @@ impl Encode for ChainNameDigest {
 // ------------------------------------------------------------------------
 // Tests.
 // ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_chain_name_digest(chain_name_digest in ChainNameDigest::new_from_arb()) {
+            assert_codec(&chain_name_digest);
+        }
+    }
+}
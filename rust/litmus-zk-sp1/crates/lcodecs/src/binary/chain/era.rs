@@ -1,18 +1,106 @@
 use super::super::utils::{CodecError, Decode, Encode};
-use ltypeset::chain::{EraEndV2, EraId};
+use ltypeset::{
+    chain::{EraEndV1, EraEndV2, EraId, EraReport, EraReward, ValidatorWeight},
+    crypto::PublicKey,
+};
 use std::{collections::BTreeMap, vec::Vec};
 
+// ------------------------------------------------------------------------
+// Codec: EraEndV1.
+// ------------------------------------------------------------------------
+
+impl Decode for EraEndV1 {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (era_report, bstream) = EraReport::decode(bstream)?;
+        let (next_era_validator_weights, bstream) = Vec::decode(bstream)?;
+        Ok((
+            EraEndV1::new(era_report, next_era_validator_weights),
+            bstream,
+        ))
+    }
+}
+
+impl Encode for EraEndV1 {
+    fn get_encoded_size(&self) -> usize {
+        self.era_report().get_encoded_size()
+            + get_sorted_validator_weights(self.next_era_validator_weights()).get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.era_report().write_encoded(writer)?;
+        get_sorted_validator_weights(self.next_era_validator_weights()).write_encoded(writer)?;
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: EraReport.
+// ------------------------------------------------------------------------
+
+impl Decode for EraReport {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (equivocators, bstream) = Vec::decode(bstream)?;
+        let (rewards, bstream) = Vec::decode(bstream)?;
+        let (inactive_validators, bstream) = Vec::decode(bstream)?;
+        Ok((
+            EraReport::new(equivocators, inactive_validators, rewards),
+            bstream,
+        ))
+    }
+}
+
+impl Encode for EraReport {
+    fn get_encoded_size(&self) -> usize {
+        self.equivocators().get_encoded_size()
+            + get_sorted_rewards(self.rewards()).get_encoded_size()
+            + self.inactive_validators().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.equivocators().write_encoded(writer)?;
+        get_sorted_rewards(self.rewards()).write_encoded(writer)?;
+        self.inactive_validators().write_encoded(writer)?;
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: EraReward.
+// ------------------------------------------------------------------------
+
+// N.B. Encoded as a key/value pair so that a sorted sequence is equivalent to an encoded map.
+
+impl Decode for EraReward {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (validator, bstream) = PublicKey::decode(bstream)?;
+        let (amount, bstream) = u64::decode(bstream)?;
+        Ok((EraReward::new(validator, amount), bstream))
+    }
+}
+
+impl Encode for EraReward {
+    fn get_encoded_size(&self) -> usize {
+        self.validator().get_encoded_size() + self.amount().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.validator().write_encoded(writer)?;
+        self.amount().write_encoded(writer)?;
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------
 // Codec: EraEndV2.
 // ------------------------------------------------------------------------
 
 impl Decode for EraEndV2 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (equivocators, bstream) = Vec::decode(bstream).unwrap();
-        let (inactive_validators, bstream) = Vec::decode(bstream).unwrap();
-        let (next_era_validator_weights, bstream) = Vec::decode(bstream).unwrap();
-        let (rewards, bstream) = BTreeMap::decode(bstream).unwrap();
-        let (next_era_gas_price, bstream) = u8::decode(bstream).unwrap();
+        let (equivocators, bstream) = Vec::decode(bstream)?;
+        let (inactive_validators, bstream) = Vec::decode(bstream)?;
+        let (next_era_validator_weights, bstream) = Vec::decode(bstream)?;
+        let (rewards, bstream) = BTreeMap::decode(bstream)?;
+        let (next_era_gas_price, bstream) = u8::decode(bstream)?;
         Ok((
             EraEndV2::new(
                 equivocators,
@@ -30,30 +118,44 @@ impl Encode for EraEndV2 {
     fn get_encoded_size(&self) -> usize {
         self.equivocators().get_encoded_size()
             + self.inactive_validators().get_encoded_size()
-            + self.next_era_validator_weights().get_encoded_size()
+            + get_sorted_validator_weights(self.next_era_validator_weights()).get_encoded_size()
             + self.rewards().get_encoded_size()
             + self.next_era_gas_price().get_encoded_size()
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.equivocators().write_encoded(writer).unwrap();
-        self.inactive_validators().write_encoded(writer).unwrap();
-        self.next_era_validator_weights()
-            .write_encoded(writer)
-            .unwrap();
-        self.rewards().write_encoded(writer).unwrap();
-        self.next_era_gas_price().write_encoded(writer).unwrap();
+        self.equivocators().write_encoded(writer)?;
+        self.inactive_validators().write_encoded(writer)?;
+        get_sorted_validator_weights(self.next_era_validator_weights()).write_encoded(writer)?;
+        self.rewards().write_encoded(writer)?;
+        self.next_era_gas_price().write_encoded(writer)?;
         Ok(())
     }
 }
 
+// Returns next era validator weights ordered by validator, i.e. as per an encoded map.
+fn get_sorted_validator_weights(weights: &[ValidatorWeight]) -> Vec<ValidatorWeight> {
+    let mut result = weights.to_owned();
+    result.sort_by_key(|vw| vw.validator_id());
+
+    result
+}
+
+// Returns era rewards ordered by validator, i.e. as per an encoded map.
+fn get_sorted_rewards(rewards: &[EraReward]) -> Vec<EraReward> {
+    let mut result = rewards.to_owned();
+    result.sort_by_key(|r| r.validator().to_owned());
+
+    result
+}
+
 // ------------------------------------------------------------------------
 // Codec: EraId.
 // ------------------------------------------------------------------------
 
 impl Decode for EraId {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = u64::decode(&bstream).unwrap();
+        let (inner, bstream) = u64::decode(&bstream)?;
 
         Ok((Self::new(inner), &bstream))
     }
@@ -65,7 +167,7 @@ impl Encode for EraId {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
         Ok(())
     }
 }
@@ -97,5 +199,25 @@ mod proptests {
         fn codec_era_id(era_id in arbs::era_id()) {
             assert_codec(&era_id);
         }
+
+        #[test]
+        fn codec_era_end_v1(era_end in EraEndV1::new_from_arb()) {
+            assert_codec(&era_end);
+        }
+
+        #[test]
+        fn codec_era_end_v2(era_end in EraEndV2::new_from_arb()) {
+            assert_codec(&era_end);
+        }
+
+        #[test]
+        fn codec_era_report(era_report in EraReport::new_from_arb()) {
+            assert_codec(&era_report);
+        }
+
+        #[test]
+        fn codec_era_reward(era_reward in EraReward::new_from_arb()) {
+            assert_codec(&era_reward);
+        }
     }
 }
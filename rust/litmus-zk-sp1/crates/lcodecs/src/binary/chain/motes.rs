@@ -1,25 +1,103 @@
-use crate::binary::utils::{CodecError, Decode, Encode};
+use super::super::{
+    constants,
+    utils::{safe_split_at, CodecError, Decode, Encode},
+};
 use ltypeset::chain::Motes;
 
 // ------------------------------------------------------------------------
 // Codec: Motes.
 // ------------------------------------------------------------------------
 
+// N.B. Motes are encoded as a U512, i.e. a length prefix followed by trimmed little endian bytes.
+
 impl Decode for Motes {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = u64::decode(&bstream).unwrap();
+        let (size, bstream) = u8::decode(bstream)?;
+        if size as usize > constants::ENCODED_SIZE_U512 {
+            return Err(CodecError::Formatting);
+        }
+        let (bytes, bstream) = safe_split_at(bstream, size as usize)?;
+        if bytes[bytes.len().min(constants::ENCODED_SIZE_U64)..]
+            .iter()
+            .any(|b| *b != 0)
+        {
+            return Err(CodecError::NotRepresentable);
+        }
+        let mut inner = [0_u8; constants::ENCODED_SIZE_U64];
+        let size = bytes.len().min(constants::ENCODED_SIZE_U64);
+        inner[..size].copy_from_slice(&bytes[..size]);
 
-        Ok((Self::new(inner), &bstream))
+        Ok((Self::new(u64::from_le_bytes(inner)), bstream))
     }
 }
 
 impl Encode for Motes {
     fn get_encoded_size(&self) -> usize {
-        self.inner().get_encoded_size()
+        constants::ENCODED_SIZE_U8 + get_trimmed_size(self)
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        let size = get_trimmed_size(self);
+        writer.push(size as u8);
+        writer.extend_from_slice(&self.inner().to_le_bytes()[..size]);
         Ok(())
     }
 }
+
+// Returns number of little endian bytes remaining once trailing zeros are trimmed.
+fn get_trimmed_size(motes: &Motes) -> usize {
+    constants::ENCODED_SIZE_U64 - (motes.inner().leading_zeros() / 8) as usize
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod arbs {
+    use super::*;
+
+    #[cfg(test)]
+    pub(super) fn motes() -> impl Strategy<Value = Motes> {
+        any::<u64>().prop_map(Motes::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_is_trimmed() {
+        for (motes, expected) in [
+            (0, vec![0]),
+            (1, vec![1, 1]),
+            (256, vec![2, 0, 1]),
+            (u64::MAX, vec![8, 255, 255, 255, 255, 255, 255, 255, 255]),
+        ] {
+            assert_eq!(Motes::new(motes).encode().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_overflow() {
+        let encoded = vec![9, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert_eq!(Motes::decode(&encoded), Err(CodecError::NotRepresentable));
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_motes(motes in arbs::motes()) {
+            assert_codec(&motes);
+        }
+    }
+}
@@ -7,7 +7,7 @@ use ltypeset::{chain::ProtocolVersion, primitives::SemanticVersion};
 
 impl Decode for ProtocolVersion {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = SemanticVersion::decode(&bstream).unwrap();
+        let (inner, bstream) = SemanticVersion::decode(&bstream)?;
 
         Ok((Self::new(inner), &bstream))
     }
@@ -19,7 +19,27 @@ impl Encode for ProtocolVersion {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_protocol_version(protocol_version in ProtocolVersion::new_from_arb()) {
+            assert_codec(&protocol_version);
+        }
+    }
+}
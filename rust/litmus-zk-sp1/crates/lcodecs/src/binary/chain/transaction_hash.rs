@@ -1,16 +1,74 @@
-use super::super::utils::{CodecError, Decode, Encode};
+use super::super::{
+    constants,
+    utils::{CodecError, Decode, Encode},
+};
 use ltypeset::{
-    chain::{TransactionV1Hash, TransactionV2Hash},
+    chain::{TransactionHash, TransactionV1Hash, TransactionV2Hash},
     crypto::Digest,
 };
 
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const TAG_TRANSACTION_V1: u8 = 0;
+const TAG_TRANSACTION_V2: u8 = 1;
+
+// ------------------------------------------------------------------------
+// Codec: TransactionHash.
+// ------------------------------------------------------------------------
+
+impl Decode for TransactionHash {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (version_tag, bstream) = u8::decode(bstream)?;
+        let (transaction_hash, bstream) = match version_tag {
+            TAG_TRANSACTION_V1 => {
+                let (inner, bstream) = TransactionV1Hash::decode(bstream)?;
+                (TransactionHash::V1(inner), bstream)
+            }
+            TAG_TRANSACTION_V2 => {
+                let (inner, bstream) = TransactionV2Hash::decode(bstream)?;
+                (TransactionHash::V2(inner), bstream)
+            }
+            _ => return Err(CodecError::Formatting),
+        };
+
+        Ok((transaction_hash, bstream))
+    }
+}
+
+impl Encode for TransactionHash {
+    fn get_encoded_size(&self) -> usize {
+        constants::ENCODED_SIZE_U8
+            + match self {
+                TransactionHash::V1(inner) => inner.get_encoded_size(),
+                TransactionHash::V2(inner) => inner.get_encoded_size(),
+            }
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        match self {
+            TransactionHash::V1(inner) => {
+                writer.push(TAG_TRANSACTION_V1);
+                inner.write_encoded(writer)?;
+            }
+            TransactionHash::V2(inner) => {
+                writer.push(TAG_TRANSACTION_V2);
+                inner.write_encoded(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------
 // Codec: TransactionV1Hash.
 // ------------------------------------------------------------------------
 
 impl Decode for TransactionV1Hash {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = Digest::decode(bstream).unwrap();
+        let (inner, bstream) = Digest::decode(bstream)?;
 
         Ok((TransactionV1Hash::new(inner), bstream))
     }
@@ -22,7 +80,7 @@ impl Encode for TransactionV1Hash {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
         Ok(())
     }
 }
@@ -33,7 +91,7 @@ impl Encode for TransactionV1Hash {
 
 impl Decode for TransactionV2Hash {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = Digest::decode(bstream).unwrap();
+        let (inner, bstream) = Digest::decode(bstream)?;
 
         Ok((TransactionV2Hash::new(inner), bstream))
     }
@@ -45,7 +103,37 @@ impl Encode for TransactionV2Hash {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        self.inner().write_encoded(writer)?;
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_transaction_hash(transaction_hash in TransactionHash::new_from_arb()) {
+            assert_codec(&transaction_hash);
+        }
+
+        #[test]
+        fn codec_transaction_v1_hash(transaction_hash in TransactionV1Hash::new_from_arb()) {
+            assert_codec(&transaction_hash);
+        }
+
+        #[test]
+        fn codec_transaction_v2_hash(transaction_hash in TransactionV2Hash::new_from_arb()) {
+            assert_codec(&transaction_hash);
+        }
+    }
+}
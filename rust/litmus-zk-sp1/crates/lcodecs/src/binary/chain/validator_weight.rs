@@ -1,22 +1,52 @@
-use crate::binary::utils::{CodecError, Decode, Encode};
-use ltypeset::chain::ValidatorWeight;
+use super::super::utils::{CodecError, Decode, Encode};
+use ltypeset::{
+    chain::{Motes, ValidatorWeight},
+    crypto::PublicKey,
+};
 
 // ------------------------------------------------------------------------
-// Codec: Motes.
+// Codec: ValidatorWeight.
 // ------------------------------------------------------------------------
 
+// N.B. Encoded as a key/value pair so that a sorted sequence is equivalent to an encoded map.
+
 impl Decode for ValidatorWeight {
-    fn decode(_: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        unimplemented!()
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (validator_id, bstream) = PublicKey::decode(bstream)?;
+        let (weight, bstream) = Motes::decode(bstream)?;
+
+        Ok((ValidatorWeight::new(validator_id, weight), bstream))
     }
 }
 
 impl Encode for ValidatorWeight {
     fn get_encoded_size(&self) -> usize {
-        unimplemented!()
+        self.validator_id().get_encoded_size() + self.weight().get_encoded_size()
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        unimplemented!()
+        self.validator_id().write_encoded(writer)?;
+        self.weight().write_encoded(writer)?;
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_validator_weight(validator_weight in ValidatorWeight::new_from_arb()) {
+            assert_codec(&validator_weight);
+        }
     }
 }
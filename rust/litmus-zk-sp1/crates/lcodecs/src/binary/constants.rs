@@ -31,6 +31,9 @@ pub(super) const ENCODED_SIZE_U256: usize = ENCODED_SIZE_U128 * 2;
 /// The number of bytes in a serialized [`U512`](crate::U512).
 pub(super) const ENCODED_SIZE_U512: usize = ENCODED_SIZE_U256 * 2;
 
+/// Upper bound upon length prefix of a decoded sequence, i.e. guards against forged prefixes.
+pub(super) const MAX_SEQUENCE_LENGTH: usize = 1 << 24;
+
 /// The tag representing a `None` value.
 pub(super) const TAG_OPTION_NONE: u8 = 0;
 
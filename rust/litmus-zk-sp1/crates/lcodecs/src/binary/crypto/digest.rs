@@ -7,7 +7,7 @@ use ltypeset::{crypto::Digest, primitives::bites::Bytes32};
 
 impl Decode for Digest {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = Bytes32::decode(bstream).unwrap();
+        let (inner, bstream) = Bytes32::decode(bstream)?;
 
         // N.B. defaults to BLAKE2B as there is no tag prefix.
         Ok((Digest::new(inner), bstream))
@@ -22,7 +22,7 @@ impl Encode for Digest {
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
         match self {
             Digest::BLAKE2B(inner) => {
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
         }
         Ok(())
@@ -33,6 +33,22 @@ impl Encode for Digest {
 // Tests.
 // ------------------------------------------------------------------------
 
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_digest(digest in Digest::new_from_arb()) {
+            assert_codec(&digest);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,13 +17,13 @@ const TAG_SECP256K1: u8 = 2;
 
 impl Decode for Signature {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (sig_tag, bstream) = u8::decode(bstream).unwrap();
-        let (sig_bytes, bstream) = Bytes64::decode(bstream).unwrap();
+        let (sig_tag, bstream) = u8::decode(bstream)?;
+        let (sig_bytes, bstream) = Bytes64::decode(bstream)?;
         Ok((
             match sig_tag {
                 TAG_ED25519 => Signature::new_ed25519(sig_bytes),
                 TAG_SECP256K1 => Signature::new_secp256k1(sig_bytes),
-                _ => panic!("Unsupported signature key type prefix"),
+                _ => return Err(CodecError::Formatting),
             },
             bstream,
         ))
@@ -39,11 +39,11 @@ impl Encode for Signature {
         match self {
             Signature::ED25519(inner) => {
                 writer.push(TAG_ED25519);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
             Signature::SECP256K1(inner) => {
                 writer.push(TAG_SECP256K1);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
         }
         Ok(())
@@ -54,6 +54,22 @@ impl Encode for Signature {
 // Tests.
 // ------------------------------------------------------------------------
 
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_signature(sig in Signature::new_from_arb()) {
+            assert_codec(&sig);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,6 +11,7 @@ use ltypeset::{
 // Constants.
 // ------------------------------------------------------------------------
 
+const TAG_SYSTEM: u8 = 0;
 const TAG_ED25519: u8 = 1;
 const TAG_SECP256K1: u8 = 2;
 
@@ -20,17 +21,18 @@ const TAG_SECP256K1: u8 = 2;
 
 impl Decode for VerificationKey {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (vkey_tag, bstream) = u8::decode(bstream).unwrap();
+        let (vkey_tag, bstream) = u8::decode(bstream)?;
         let (vkey, bstream) = match vkey_tag {
+            TAG_SYSTEM => (VerificationKey::SYSTEM, bstream),
             TAG_ED25519 => {
-                let (vk, bstream) = Bytes32::decode(bstream).unwrap();
+                let (vk, bstream) = Bytes32::decode(bstream)?;
                 (VerificationKey::new_ed25519(vk), bstream)
             }
             TAG_SECP256K1 => {
-                let (vk, bstream) = Bytes33::decode(bstream).unwrap();
+                let (vk, bstream) = Bytes33::decode(bstream)?;
                 (VerificationKey::new_secp256k1(vk), bstream)
             }
-            _ => panic!("Invalid verification type tag"),
+            _ => return Err(CodecError::Formatting),
         };
 
         Ok((vkey, bstream))
@@ -41,6 +43,7 @@ impl Encode for VerificationKey {
     fn get_encoded_size(&self) -> usize {
         constants::ENCODED_SIZE_U8
             + match self {
+                VerificationKey::SYSTEM => 0,
                 VerificationKey::ED25519(_) => Bytes32::len(),
                 VerificationKey::SECP256K1(_) => Bytes33::len(),
             }
@@ -48,16 +51,39 @@ impl Encode for VerificationKey {
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
         match self {
+            VerificationKey::SYSTEM => {
+                writer.push(TAG_SYSTEM);
+            }
             VerificationKey::ED25519(inner) => {
                 writer.push(TAG_ED25519);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
             VerificationKey::SECP256K1(inner) => {
                 writer.push(TAG_SECP256K1);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
         }
 
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_verification_key(vkey in VerificationKey::new_from_arb()) {
+            assert_codec(&vkey);
+        }
+    }
+}
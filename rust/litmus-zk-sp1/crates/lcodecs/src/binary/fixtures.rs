@@ -0,0 +1,23 @@
+use ltypeset::chain::{Block, BlockWithProofs};
+use std::{env, fs};
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+// Returns set of blocks loaded from chain fixtures, i.e. blocks issued by a Casper node.
+pub(crate) fn get_blocks() -> Vec<Block> {
+    let path_to_root = format!(
+        "{}/../../resources/chain/blocks",
+        env::var("CARGO_MANIFEST_DIR").unwrap()
+    );
+
+    fs::read_dir(path_to_root)
+        .unwrap()
+        .map(|f| {
+            let block_with_proofs: BlockWithProofs =
+                serde_json::from_str(&fs::read_to_string(f.unwrap().path()).unwrap()).unwrap();
+            block_with_proofs.block().to_owned()
+        })
+        .collect()
+}
@@ -1,7 +1,11 @@
 mod chain;
 mod constants;
 mod crypto;
+#[cfg(test)]
+mod fixtures;
 mod primitives;
+mod trie;
 mod utils;
+mod verification;
 
 pub use utils::{CodecError, Decode, Encode};
@@ -8,11 +8,11 @@ use crate::binary::utils::{CodecError, Decode, Encode};
 impl Decode for bool {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
         let (value, bstream) = match bstream.split_first() {
-            None => panic!("Invalid bool encoding"),
+            None => return Err(CodecError::EarlyEndOfStream),
             Some((val, bstream)) => match val {
                 1 => (true, bstream),
                 0 => (false, bstream),
-                _ => panic!("Invalid bool encoding"),
+                _ => return Err(CodecError::Formatting),
             },
         };
 
@@ -44,7 +44,7 @@ impl<T: Decode> Decode for Option<T> {
                 let (t, bstream) = T::decode(bstream)?;
                 (Some(t), bstream)
             }
-            _ => panic!("Invalid Option<T> type tag"),
+            _ => return Err(CodecError::Formatting),
         };
 
         Ok((d, bstream))
@@ -66,7 +66,7 @@ impl<T: Encode> Encode for Option<T> {
             }
             Some(inner) => {
                 writer.push(constants::TAG_OPTION_SOME);
-                inner.write_encoded(writer).unwrap();
+                inner.write_encoded(writer)?;
             }
         }
         Ok(())
@@ -89,7 +89,7 @@ impl<T: Decode, E: Decode> Decode for Result<T, E> {
                 let (value, bstream) = T::decode(bstream)?;
                 (Ok(value), bstream)
             }
-            _ => panic!("Invalid Result<T, E> type tag"),
+            _ => return Err(CodecError::Formatting),
         };
 
         Ok((result, bstream))
@@ -108,17 +108,42 @@ impl<T: Encode, E: Encode> Encode for Result<T, E> {
         match self {
             Err(error) => {
                 writer.push(constants::TAG_RESULT_ERR);
-                error.write_encoded(writer).unwrap();
+                error.write_encoded(writer)?;
             }
             Ok(value) => {
                 writer.push(constants::TAG_RESULT_OK);
-                value.write_encoded(writer).unwrap();
+                value.write_encoded(writer)?;
             }
         }
         Ok(())
     }
 }
 
+// ------------------------------------------------------------------------
+// Codec: (T1, T2).
+// ------------------------------------------------------------------------
+
+impl<T1: Decode, T2: Decode> Decode for (T1, T2) {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (t1, bstream) = T1::decode(bstream)?;
+        let (t2, bstream) = T2::decode(bstream)?;
+
+        Ok(((t1, t2), bstream))
+    }
+}
+
+impl<T1: Encode, T2: Encode> Encode for (T1, T2) {
+    fn get_encoded_size(&self) -> usize {
+        self.0.get_encoded_size() + self.1.get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.0.write_encoded(writer)?;
+        self.1.write_encoded(writer)?;
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------
 // Codec: unit.
 // ------------------------------------------------------------------------
@@ -10,9 +10,9 @@ const ENCODED_SIZE: usize = constants::ENCODED_SIZE_U32 * 3;
 
 impl Decode for SemanticVersion {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (major, bstream) = u32::decode(&bstream).unwrap();
-        let (minor, bstream) = u32::decode(&bstream).unwrap();
-        let (patch, bstream) = u32::decode(&bstream).unwrap();
+        let (major, bstream) = u32::decode(&bstream)?;
+        let (minor, bstream) = u32::decode(&bstream)?;
+        let (patch, bstream) = u32::decode(&bstream)?;
 
         Ok((SemanticVersion::new(major, minor, patch), bstream))
     }
@@ -24,9 +24,29 @@ impl Encode for SemanticVersion {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.major.write_encoded(writer).unwrap();
-        self.minor.write_encoded(writer).unwrap();
-        self.patch.write_encoded(writer).unwrap();
+        self.major.write_encoded(writer)?;
+        self.minor.write_encoded(writer)?;
+        self.patch.write_encoded(writer)?;
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::binary::utils::assert_codec;
+
+    proptest! {
+        #[test]
+        fn codec_semantic_version(version in SemanticVersion::new_from_arb()) {
+            assert_codec(&version);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::super::{
     constants,
-    utils::{CodecError, Decode, Encode},
+    utils::{decode_length, CodecError, Decode, Encode},
 };
 use std::collections::BTreeMap;
 
@@ -11,15 +11,15 @@ use std::collections::BTreeMap;
 impl<T: Decode> Decode for Vec<T> {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
         // Vec size.
-        let (size, mut bstream) = u32::decode(bstream).unwrap();
+        let (size, mut bstream) = decode_length(bstream)?;
         if size == 0 {
             return Ok((Vec::new(), bstream));
         }
 
-        // Vec data.
-        let mut result = Vec::<T>::with_capacity(size as usize);
+        // Vec data - N.B. capacity is bounded by stream length so that a forged prefix cannot force a large allocation.
+        let mut result = Vec::<T>::with_capacity(size.min(bstream.len()));
         for _ in 0..size {
-            let (entity, bstream_1) = T::decode(bstream).unwrap();
+            let (entity, bstream_1) = T::decode(bstream)?;
             result.push(entity);
             bstream = bstream_1;
         }
@@ -42,13 +42,12 @@ impl<T: Encode> Encode for Vec<T> {
         let size: u32 = self
             .len()
             .try_into()
-            .map_err(|_| CodecError::NotRepresentable)
-            .unwrap();
-        size.write_encoded(writer).unwrap();
+            .map_err(|_| CodecError::NotRepresentable)?;
+        size.write_encoded(writer)?;
 
         // Vec data.
         for entity in self.iter() {
-            entity.write_encoded(writer).unwrap();
+            entity.write_encoded(writer)?;
         }
 
         Ok(())
@@ -66,13 +65,13 @@ where
 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
         // BTreeMap size.
-        let (size, mut bstream) = u32::decode(bstream).unwrap();
+        let (size, mut bstream) = decode_length(bstream)?;
 
         // BTreeMap data.
         let mut result = BTreeMap::new();
         for _ in 0..size {
-            let (k, bstream_1) = K::decode(bstream).unwrap();
-            let (v, bstream_1) = V::decode(bstream_1).unwrap();
+            let (k, bstream_1) = K::decode(bstream)?;
+            let (v, bstream_1) = V::decode(bstream_1)?;
             result.insert(k, v);
             bstream = bstream_1;
         }
@@ -99,16 +98,64 @@ where
         let size: u32 = self
             .len()
             .try_into()
-            .map_err(|_| CodecError::NotRepresentable)
-            .unwrap();
-        size.write_encoded(writer).unwrap();
+            .map_err(|_| CodecError::NotRepresentable)?;
+        size.write_encoded(writer)?;
 
         // BTreeMap data.
         for (key, value) in self.iter() {
-            key.write_encoded(writer).unwrap();
-            value.write_encoded(writer).unwrap();
+            key.write_encoded(writer)?;
+            value.write_encoded(writer)?;
         }
 
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_vec() {
+        let entity: Vec<u64> = vec![1, 2, 3];
+        let encoded = entity.encode().unwrap();
+
+        assert_eq!(Vec::<u64>::decode_exact(&encoded), Ok(entity));
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_vec() {
+        let encoded = vec![1_u64, 2, 3].encode().unwrap();
+
+        assert_eq!(
+            Vec::<u64>::decode(&encoded[..encoded.len() - 1]),
+            Err(CodecError::EarlyEndOfStream)
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_oversized_length_prefix() {
+        let encoded = u32::MAX.encode().unwrap();
+
+        assert_eq!(Vec::<u8>::decode(&encoded), Err(CodecError::Formatting));
+        assert_eq!(
+            BTreeMap::<u8, u8>::decode(&encoded),
+            Err(CodecError::Formatting)
+        );
+    }
+
+    #[test]
+    fn test_decode_does_not_trust_length_prefix() {
+        // N.B. a length within cap but beyond stream length fails without allocating upfront.
+        let encoded = (constants::MAX_SEQUENCE_LENGTH as u32).encode().unwrap();
+
+        assert_eq!(
+            Vec::<u64>::decode(&encoded),
+            Err(CodecError::EarlyEndOfStream)
+        );
+    }
+}
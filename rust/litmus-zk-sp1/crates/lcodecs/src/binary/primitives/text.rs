@@ -1,8 +1,8 @@
 use crate::binary::{
     constants,
     utils::{
-        encode_byte_slice, get_encoded_size_of_byte_slice, safe_split_at, CodecError, Decode,
-        Encode,
+        decode_length, encode_byte_slice, get_encoded_size_of_byte_slice, safe_split_at,
+        CodecError, Decode, Encode,
     },
 };
 
@@ -16,7 +16,7 @@ impl Encode for str {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        writer.extend_from_slice(encode_byte_slice(self.as_bytes()).unwrap().as_slice());
+        writer.extend_from_slice(encode_byte_slice(self.as_bytes())?.as_slice());
         Ok(())
     }
 }
@@ -25,9 +25,11 @@ impl Encode for str {
 // Codec: &str.
 // ------------------------------------------------------------------------
 
+// N.B. A borrowed string cannot outlive the stream from which it is decoded, hence decode into a `String`.
+
 impl Decode for &str {
     fn decode(_: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        unimplemented!()
+        Err(CodecError::NotRepresentable)
     }
 }
 
@@ -37,7 +39,7 @@ impl Encode for &str {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        writer.extend_from_slice((*self).encode().unwrap().as_slice());
+        writer.extend_from_slice((*self).encode()?.as_slice());
         Ok(())
     }
 }
@@ -48,8 +50,8 @@ impl Encode for &str {
 
 impl Decode for String {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (size, bstream) = u32::decode(bstream)?;
-        let (str_bytes, bstream) = safe_split_at(bstream, size as usize)?;
+        let (size, bstream) = decode_length(bstream)?;
+        let (str_bytes, bstream) = safe_split_at(bstream, size)?;
         let result = String::from_utf8(str_bytes.to_vec()).map_err(|_| CodecError::Formatting)?;
         Ok((result, bstream))
     }
@@ -61,7 +63,7 @@ impl Encode for String {
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        writer.extend_from_slice(encode_byte_slice(self.as_bytes()).unwrap().as_slice());
+        writer.extend_from_slice(encode_byte_slice(self.as_bytes())?.as_slice());
         Ok(())
     }
 }
@@ -1,25 +1,32 @@
-use super::super::utils::{CodecError, Decode, Encode};
+use super::super::{
+    constants,
+    utils::{CodecError, Decode, Encode},
+};
 use ltypeset::primitives::time::Timestamp;
 
 // ------------------------------------------------------------------------
-// Codec: Digest.
+// Codec: Timestamp.
 // ------------------------------------------------------------------------
 
+// N.B. Timestamps are encoded as milliseconds since the unix epoch within a u64.
+
 impl Decode for Timestamp {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (inner, bstream) = u128::decode(&bstream).unwrap();
+        let (inner, bstream) = u64::decode(&bstream)?;
 
-        Ok((Self::new(inner), &bstream))
+        Ok((Self::new(inner as u128), &bstream))
     }
 }
 
 impl Encode for Timestamp {
     fn get_encoded_size(&self) -> usize {
-        self.inner().get_encoded_size()
+        constants::ENCODED_SIZE_U64
     }
 
     fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
-        self.inner().write_encoded(writer).unwrap();
+        u64::try_from(self.inner())
+            .map_err(|_| CodecError::NotRepresentable)?
+            .write_encoded(writer)?;
         Ok(())
     }
 }
@@ -37,7 +44,7 @@ mod arbs {
 
     #[cfg(test)]
     pub fn timestamp() -> impl Strategy<Value = Timestamp> {
-        any::<u128>().prop_map(Timestamp::new)
+        any::<u64>().prop_map(|ms| Timestamp::new(ms as u128))
     }
 }
 
@@ -52,4 +59,10 @@ mod proptests {
             assert_codec(&timestamp);
         }
     }
+
+    #[test]
+    fn test_encode_rejects_overflow() {
+        let timestamp = Timestamp::new(u64::MAX as u128 + 1);
+        assert_eq!(timestamp.encode(), Err(CodecError::NotRepresentable));
+    }
 }
@@ -7,7 +7,7 @@ use super::super::utils::{deconstruct_bytes, CodecError, Decode, Encode};
 
 impl Decode for i32 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (bytes, bstream) = deconstruct_bytes::<4>(&bstream).unwrap();
+        let (bytes, bstream) = deconstruct_bytes::<4>(bstream)?;
 
         Ok((<i32>::from_le_bytes(bytes), bstream))
     }
@@ -30,7 +30,7 @@ impl Encode for i32 {
 
 impl Decode for i64 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (bytes, bstream) = deconstruct_bytes::<8>(&bstream).unwrap();
+        let (bytes, bstream) = deconstruct_bytes::<8>(bstream)?;
 
         Ok((<i64>::from_le_bytes(bytes), bstream))
     }
@@ -77,7 +77,7 @@ impl Encode for u8 {
 
 impl Decode for u16 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (bytes, bstream) = deconstruct_bytes::<2>(&bstream).unwrap();
+        let (bytes, bstream) = deconstruct_bytes::<2>(bstream)?;
 
         Ok((<u16>::from_le_bytes(bytes), bstream))
     }
@@ -100,7 +100,7 @@ impl Encode for u16 {
 
 impl Decode for u32 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (bytes, bstream) = deconstruct_bytes::<4>(&bstream).unwrap();
+        let (bytes, bstream) = deconstruct_bytes::<4>(bstream)?;
 
         Ok((<u32>::from_le_bytes(bytes), bstream))
     }
@@ -123,7 +123,7 @@ impl Encode for u32 {
 
 impl Decode for u64 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (bytes, bstream) = deconstruct_bytes::<8>(&bstream).unwrap();
+        let (bytes, bstream) = deconstruct_bytes::<8>(bstream)?;
 
         Ok((<u64>::from_le_bytes(bytes), bstream))
     }
@@ -146,7 +146,7 @@ impl Encode for u64 {
 
 impl Decode for u128 {
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
-        let (bytes, bstream) = deconstruct_bytes::<16>(&bstream).unwrap();
+        let (bytes, bstream) = deconstruct_bytes::<16>(bstream)?;
 
         Ok((<u128>::from_le_bytes(bytes), bstream))
     }
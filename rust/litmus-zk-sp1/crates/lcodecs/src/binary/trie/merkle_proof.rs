@@ -0,0 +1,141 @@
+use super::super::{
+    constants,
+    utils::{CodecError, Decode, Encode},
+};
+use ltypeset::trie::{Pointer, TrieMerkleProof, TrieMerkleProofStep};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const TAG_STEP_NODE: u8 = 0;
+const TAG_STEP_EXTENSION: u8 = 1;
+
+// ------------------------------------------------------------------------
+// Codec: TrieMerkleProof.
+// ------------------------------------------------------------------------
+
+// N.B. Key & value are length prefixed as they are carried as opaque encodings.
+impl Decode for TrieMerkleProof {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (key, bstream) = Vec::<u8>::decode(bstream)?;
+        let (value, bstream) = Vec::<u8>::decode(bstream)?;
+        let (proof_steps, bstream) = Vec::<TrieMerkleProofStep>::decode(bstream)?;
+
+        Ok((TrieMerkleProof::new(key, proof_steps, value), bstream))
+    }
+}
+
+impl Encode for TrieMerkleProof {
+    fn get_encoded_size(&self) -> usize {
+        self.key().get_encoded_size()
+            + self.value().get_encoded_size()
+            + self.proof_steps().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.key().write_encoded(writer)?;
+        self.value().write_encoded(writer)?;
+        self.proof_steps().write_encoded(writer)?;
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: TrieMerkleProofStep.
+// ------------------------------------------------------------------------
+
+impl Decode for TrieMerkleProofStep {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (tag, bstream) = u8::decode(bstream)?;
+        let (step, bstream) = match tag {
+            TAG_STEP_NODE => {
+                let (hole_index, bstream) = u8::decode(bstream)?;
+                let (indexed_pointers_with_hole, bstream) = Vec::<(u8, Pointer)>::decode(bstream)?;
+                (
+                    TrieMerkleProofStep::Node {
+                        hole_index,
+                        indexed_pointers_with_hole,
+                    },
+                    bstream,
+                )
+            }
+            TAG_STEP_EXTENSION => {
+                let (affix, bstream) = Vec::<u8>::decode(bstream)?;
+                (TrieMerkleProofStep::Extension { affix }, bstream)
+            }
+            _ => return Err(CodecError::Formatting),
+        };
+
+        Ok((step, bstream))
+    }
+}
+
+impl Encode for TrieMerkleProofStep {
+    fn get_encoded_size(&self) -> usize {
+        constants::ENCODED_SIZE_U8
+            + match self {
+                TrieMerkleProofStep::Node {
+                    hole_index,
+                    indexed_pointers_with_hole,
+                } => hole_index.get_encoded_size() + indexed_pointers_with_hole.get_encoded_size(),
+                TrieMerkleProofStep::Extension { affix } => affix.get_encoded_size(),
+            }
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        match self {
+            TrieMerkleProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                writer.push(TAG_STEP_NODE);
+                hole_index.write_encoded(writer)?;
+                indexed_pointers_with_hole.write_encoded(writer)?;
+            }
+            TrieMerkleProofStep::Extension { affix } => {
+                writer.push(TAG_STEP_EXTENSION);
+                affix.write_encoded(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::crypto::Digest;
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    #[test]
+    fn test_codec_trie_merkle_proof() {
+        let proof = TrieMerkleProof::new(
+            vec![0, 1, 2],
+            vec![
+                TrieMerkleProofStep::Node {
+                    hole_index: 4,
+                    indexed_pointers_with_hole: vec![
+                        (0, Pointer::Leaf(Digest::from(DIGEST_HEX))),
+                        (255, Pointer::Node(Digest::from(DIGEST_HEX))),
+                    ],
+                },
+                TrieMerkleProofStep::Extension { affix: vec![5, 6] },
+            ],
+            vec![7, 8, 9],
+        );
+        let encoded = proof.encode().unwrap();
+        assert_eq!(proof.get_encoded_size(), encoded.len());
+
+        let (decoded, remainder) = TrieMerkleProof::decode(&encoded).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(remainder.is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+mod merkle_proof;
+mod node;
+mod pointer;
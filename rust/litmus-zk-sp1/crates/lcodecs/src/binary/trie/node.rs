@@ -0,0 +1,116 @@
+use super::super::{
+    constants,
+    utils::{CodecError, Encode},
+};
+use ltypeset::trie::{Pointer, Trie, TRIE_RADIX};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const TAG_LEAF: u8 = 0;
+const TAG_NODE: u8 = 1;
+const TAG_EXTENSION: u8 = 2;
+
+// ------------------------------------------------------------------------
+// Codec: Trie.
+// ------------------------------------------------------------------------
+
+// N.B. Decode is not supported as leaf keys & values are carried as opaque encodings,
+// i.e. a leaf's bytes cannot be split without parsing Casper `Key` & `StoredValue` types.
+impl Encode for Trie {
+    fn get_encoded_size(&self) -> usize {
+        constants::ENCODED_SIZE_U8
+            + match self {
+                Trie::Leaf { key, value } => key.len() + value.len(),
+                Trie::Node { .. } => get_pointer_block(self)
+                    .iter()
+                    .map(|p| p.get_encoded_size())
+                    .sum(),
+                Trie::Extension { affix, pointer } => {
+                    affix.get_encoded_size() + pointer.get_encoded_size()
+                }
+            }
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        match self {
+            Trie::Leaf { key, value } => {
+                writer.push(TAG_LEAF);
+                writer.extend_from_slice(key);
+                writer.extend_from_slice(value);
+            }
+            Trie::Node { .. } => {
+                writer.push(TAG_NODE);
+                for pointer in get_pointer_block(self) {
+                    pointer.write_encoded(writer)?;
+                }
+            }
+            Trie::Extension { affix, pointer } => {
+                writer.push(TAG_EXTENSION);
+                affix.write_encoded(writer)?;
+                pointer.write_encoded(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Returns full set of a node's child slots, i.e. a Casper `PointerBlock`.
+fn get_pointer_block(trie: &Trie) -> Vec<Option<Pointer>> {
+    let mut result = vec![None; TRIE_RADIX];
+    if let Trie::Node { indexed_pointers } = trie {
+        for (idx, pointer) in indexed_pointers {
+            result[*idx as usize] = Some(pointer.to_owned());
+        }
+    }
+
+    result
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::crypto::Digest;
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    #[test]
+    fn test_encode_leaf() {
+        let encoded = Trie::new_leaf(vec![1, 2], vec![3]).encode().unwrap();
+
+        assert_eq!(encoded, vec![TAG_LEAF, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_encode_node() {
+        let pointer = Pointer::Node(Digest::from(DIGEST_HEX));
+        let trie = Trie::new_node(vec![(7, pointer)]).unwrap();
+        let encoded = trie.encode().unwrap();
+
+        assert_eq!(trie.get_encoded_size(), encoded.len());
+        assert_eq!(encoded.len(), 1 + (TRIE_RADIX - 1) + (1 + 1 + 32));
+        assert_eq!(encoded[0], TAG_NODE);
+        assert_eq!(&encoded[1..8], &[0; 7]);
+        assert_eq!(&encoded[8..10], &[1, 1]);
+        assert_eq!(&encoded[10..42], Digest::from(DIGEST_HEX).as_slice());
+    }
+
+    #[test]
+    fn test_encode_extension() {
+        let pointer = Pointer::Leaf(Digest::from(DIGEST_HEX));
+        let encoded = Trie::new_extension(vec![9, 9], pointer).encode().unwrap();
+
+        assert_eq!(&encoded[..8], &[TAG_EXTENSION, 2, 0, 0, 0, 9, 9, 0]);
+        assert_eq!(&encoded[8..], Digest::from(DIGEST_HEX).as_slice());
+    }
+}
@@ -0,0 +1,46 @@
+use super::super::{
+    constants,
+    utils::{CodecError, Decode, Encode},
+};
+use ltypeset::{crypto::Digest, trie::Pointer};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const TAG_LEAF: u8 = 0;
+const TAG_NODE: u8 = 1;
+
+// ------------------------------------------------------------------------
+// Codec: Pointer.
+// ------------------------------------------------------------------------
+
+impl Decode for Pointer {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (tag, bstream) = u8::decode(bstream)?;
+        let (digest, bstream) = Digest::decode(bstream)?;
+        let pointer = match tag {
+            TAG_LEAF => Pointer::Leaf(digest),
+            TAG_NODE => Pointer::Node(digest),
+            _ => return Err(CodecError::Formatting),
+        };
+
+        Ok((pointer, bstream))
+    }
+}
+
+impl Encode for Pointer {
+    fn get_encoded_size(&self) -> usize {
+        constants::ENCODED_SIZE_U8 + self.digest().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        writer.push(match self {
+            Pointer::Leaf(_) => TAG_LEAF,
+            Pointer::Node(_) => TAG_NODE,
+        });
+        self.digest().write_encoded(writer)?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use super::constants;
-use core::fmt::{Debug, Display};
+use core::fmt::Debug;
 use serde::{Deserialize, Serialize};
 
 // ------------------------------------------------------------------------
@@ -15,7 +15,7 @@ pub enum CodecError {
     EarlyEndOfStream = 0,
     /// Formatting error while deserializing.
     Formatting,
-    /// Not all input bytes were consumed in [`deserialize`].
+    /// Not all input bytes were consumed in [`Decode::decode_exact`].
     LeftOverBytes,
     /// Out of memory error.
     OutOfMemory,
@@ -30,6 +30,16 @@ pub trait Decode: Sized {
     /// Decodes slice into instance of `Self`.
     fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError>;
 
+    /// Decodes slice into instance of `Self`, failing if any bytes are not consumed.
+    fn decode_exact(bstream: &[u8]) -> Result<Self, CodecError> {
+        let (entity, remainder) = Self::decode(bstream)?;
+        if !remainder.is_empty() {
+            return Err(CodecError::LeftOverBytes);
+        }
+
+        Ok(entity)
+    }
+
     /// Decodes `Vec<u8>` into instance of `Self`.
     fn from_vec(bstream: Vec<u8>) -> Result<(Self, Vec<u8>), CodecError> {
         Self::decode(bstream.as_slice()).map(|(x, remainder)| (x, Vec::from(remainder)))
@@ -80,7 +90,7 @@ pub(crate) fn allocate_buffer<T: Encode + Sized>(
 #[cfg(test)]
 pub(crate) fn assert_codec<T>(entity: &T)
 where
-    T: Decode + Encode + Debug + Display + PartialEq,
+    T: Decode + Encode + Debug + PartialEq,
 {
     let encoded = T::encode(&entity).unwrap();
 
@@ -96,6 +106,17 @@ where
     assert_eq!(bstream.len(), 0);
 }
 
+/// Decodes a sequence's u32 length prefix, failing if it exceeds maximum sequence length.
+pub(crate) fn decode_length(bstream: &[u8]) -> Result<(usize, &[u8]), CodecError> {
+    let (length, bstream) = u32::decode(bstream)?;
+    let length = length as usize;
+    if length > constants::MAX_SEQUENCE_LENGTH {
+        return Err(CodecError::Formatting);
+    }
+
+    Ok((length, bstream))
+}
+
 /// Deconstructs a byte sequence into left & right sequences at a certain index.
 pub(crate) fn deconstruct_bytes<const N: usize>(
     bytes: &[u8],
@@ -0,0 +1 @@
+mod public_values;
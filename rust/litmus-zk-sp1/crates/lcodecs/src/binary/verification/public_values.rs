@@ -0,0 +1,404 @@
+use super::super::{
+    constants,
+    utils::{CodecError, Decode, Encode},
+};
+use ltypeset::{
+    chain::{BlockHash, BlockHeight, ChainNameDigest, EraId, TransactionV2Hash},
+    crypto::{Digest, VerificationKey},
+    verification::{
+        BlockPublicValues, EraPublicValues, PublicValues, StatePublicValues,
+        TransactionPublicValues,
+    },
+};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// N.B. tags are aligned with zk-vm program verification types.
+const TAG_DIGEST: u8 = 0;
+const TAG_SIGNATURE: u8 = 1;
+const TAG_BLOCK_V1: u8 = 10;
+const TAG_BLOCK_V2: u8 = 11;
+const TAG_TRANSACTION_V2_INCLUSION: u8 = 12;
+const TAG_STATE_V2_INCLUSION: u8 = 13;
+const TAG_BLOCK_RANGE_V2: u8 = 14;
+const TAG_ERA_V2: u8 = 15;
+
+// ------------------------------------------------------------------------
+// Codec: PublicValues.
+// ------------------------------------------------------------------------
+
+impl Decode for PublicValues {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (tag, bstream) = u8::decode(bstream)?;
+        let (public_values, bstream) = match tag {
+            TAG_DIGEST => {
+                let (digest, bstream) = Digest::decode(bstream)?;
+                (PublicValues::Digest(digest), bstream)
+            }
+            TAG_SIGNATURE => {
+                let (digest, bstream) = Digest::decode(bstream)?;
+                let (vkey, bstream) = VerificationKey::decode(bstream)?;
+                (PublicValues::Signature(digest, vkey), bstream)
+            }
+            TAG_BLOCK_V1 => {
+                let (inner, bstream) = BlockPublicValues::decode(bstream)?;
+                (PublicValues::BlockV1(inner), bstream)
+            }
+            TAG_BLOCK_V2 => {
+                let (inner, bstream) = BlockPublicValues::decode(bstream)?;
+                (PublicValues::BlockV2(inner), bstream)
+            }
+            TAG_BLOCK_RANGE_V2 => {
+                let (first, bstream) = BlockPublicValues::decode(bstream)?;
+                let (last, bstream) = BlockPublicValues::decode(bstream)?;
+                (PublicValues::BlockRangeV2(first, last), bstream)
+            }
+            TAG_TRANSACTION_V2_INCLUSION => {
+                let (inner, bstream) = TransactionPublicValues::decode(bstream)?;
+                (PublicValues::TransactionV2Inclusion(inner), bstream)
+            }
+            TAG_STATE_V2_INCLUSION => {
+                let (inner, bstream) = StatePublicValues::decode(bstream)?;
+                (PublicValues::StateV2Inclusion(inner), bstream)
+            }
+            TAG_ERA_V2 => {
+                let (inner, bstream) = EraPublicValues::decode(bstream)?;
+                (PublicValues::EraV2(inner), bstream)
+            }
+            _ => return Err(CodecError::Formatting),
+        };
+
+        Ok((public_values, bstream))
+    }
+}
+
+impl Encode for PublicValues {
+    fn get_encoded_size(&self) -> usize {
+        constants::ENCODED_SIZE_U8
+            + match self {
+                PublicValues::Digest(digest) => digest.get_encoded_size(),
+                PublicValues::Signature(digest, vkey) => {
+                    digest.get_encoded_size() + vkey.get_encoded_size()
+                }
+                PublicValues::BlockV1(inner) => inner.get_encoded_size(),
+                PublicValues::BlockV2(inner) => inner.get_encoded_size(),
+                PublicValues::BlockRangeV2(first, last) => {
+                    first.get_encoded_size() + last.get_encoded_size()
+                }
+                PublicValues::TransactionV2Inclusion(inner) => inner.get_encoded_size(),
+                PublicValues::StateV2Inclusion(inner) => inner.get_encoded_size(),
+                PublicValues::EraV2(inner) => inner.get_encoded_size(),
+            }
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        match self {
+            PublicValues::Digest(digest) => {
+                writer.push(TAG_DIGEST);
+                digest.write_encoded(writer)?;
+            }
+            PublicValues::Signature(digest, vkey) => {
+                writer.push(TAG_SIGNATURE);
+                digest.write_encoded(writer)?;
+                vkey.write_encoded(writer)?;
+            }
+            PublicValues::BlockV1(inner) => {
+                writer.push(TAG_BLOCK_V1);
+                inner.write_encoded(writer)?;
+            }
+            PublicValues::BlockV2(inner) => {
+                writer.push(TAG_BLOCK_V2);
+                inner.write_encoded(writer)?;
+            }
+            PublicValues::BlockRangeV2(first, last) => {
+                writer.push(TAG_BLOCK_RANGE_V2);
+                first.write_encoded(writer)?;
+                last.write_encoded(writer)?;
+            }
+            PublicValues::TransactionV2Inclusion(inner) => {
+                writer.push(TAG_TRANSACTION_V2_INCLUSION);
+                inner.write_encoded(writer)?;
+            }
+            PublicValues::StateV2Inclusion(inner) => {
+                writer.push(TAG_STATE_V2_INCLUSION);
+                inner.write_encoded(writer)?;
+            }
+            PublicValues::EraV2(inner) => {
+                writer.push(TAG_ERA_V2);
+                inner.write_encoded(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: BlockPublicValues.
+// ------------------------------------------------------------------------
+
+impl Decode for BlockPublicValues {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (block_hash, bstream) = BlockHash::decode(bstream)?;
+        let (height, bstream) = BlockHeight::decode(bstream)?;
+        let (era_id, bstream) = EraId::decode(bstream)?;
+        let (state_root_hash, bstream) = Digest::decode(bstream)?;
+        let (chain_name_digest, bstream) = Option::<ChainNameDigest>::decode(bstream)?;
+        let (validator_set_digest, bstream) = Option::<Digest>::decode(bstream)?;
+
+        Ok((
+            BlockPublicValues::new(
+                block_hash,
+                chain_name_digest,
+                era_id,
+                height,
+                state_root_hash,
+                validator_set_digest,
+            ),
+            bstream,
+        ))
+    }
+}
+
+impl Encode for BlockPublicValues {
+    fn get_encoded_size(&self) -> usize {
+        self.block_hash().get_encoded_size()
+            + self.height().get_encoded_size()
+            + self.era_id().get_encoded_size()
+            + self.state_root_hash().get_encoded_size()
+            + self.chain_name_digest().get_encoded_size()
+            + self.validator_set_digest().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.block_hash().write_encoded(writer)?;
+        self.height().write_encoded(writer)?;
+        self.era_id().write_encoded(writer)?;
+        self.state_root_hash().write_encoded(writer)?;
+        self.chain_name_digest().write_encoded(writer)?;
+        self.validator_set_digest().write_encoded(writer)?;
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: EraPublicValues.
+// ------------------------------------------------------------------------
+
+impl Decode for EraPublicValues {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (block, bstream) = BlockPublicValues::decode(bstream)?;
+        let (trusted_block_hash, bstream) = BlockHash::decode(bstream)?;
+        let (vkey_hash, bstream) = Digest::decode(bstream)?;
+
+        Ok((
+            EraPublicValues::new(block, trusted_block_hash, vkey_hash),
+            bstream,
+        ))
+    }
+}
+
+impl Encode for EraPublicValues {
+    fn get_encoded_size(&self) -> usize {
+        self.block().get_encoded_size()
+            + self.trusted_block_hash().get_encoded_size()
+            + self.vkey_hash().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.block().write_encoded(writer)?;
+        self.trusted_block_hash().write_encoded(writer)?;
+        self.vkey_hash().write_encoded(writer)?;
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: StatePublicValues.
+// ------------------------------------------------------------------------
+
+impl Decode for StatePublicValues {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (block, bstream) = BlockPublicValues::decode(bstream)?;
+        let (key, bstream) = Vec::<u8>::decode(bstream)?;
+        let (value, bstream) = Vec::<u8>::decode(bstream)?;
+
+        Ok((StatePublicValues::new(block, key, value), bstream))
+    }
+}
+
+impl Encode for StatePublicValues {
+    fn get_encoded_size(&self) -> usize {
+        self.block().get_encoded_size()
+            + self.key().get_encoded_size()
+            + self.value().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.block().write_encoded(writer)?;
+        self.key().write_encoded(writer)?;
+        self.value().write_encoded(writer)?;
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Codec: TransactionPublicValues.
+// ------------------------------------------------------------------------
+
+impl Decode for TransactionPublicValues {
+    fn decode(bstream: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (block, bstream) = BlockPublicValues::decode(bstream)?;
+        let (category, bstream) = u8::decode(bstream)?;
+        let (transaction_hash, bstream) = TransactionV2Hash::decode(bstream)?;
+
+        Ok((
+            TransactionPublicValues::new(block, category, transaction_hash),
+            bstream,
+        ))
+    }
+}
+
+impl Encode for TransactionPublicValues {
+    fn get_encoded_size(&self) -> usize {
+        self.block().get_encoded_size()
+            + self.category().get_encoded_size()
+            + self.transaction_hash().get_encoded_size()
+    }
+
+    fn write_encoded(&self, writer: &mut Vec<u8>) -> Result<(), CodecError> {
+        self.block().write_encoded(writer)?;
+        self.category().write_encoded(writer)?;
+        self.transaction_hash().write_encoded(writer)?;
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_block_public_values(chain_name_digest: Option<ChainNameDigest>) -> BlockPublicValues {
+        BlockPublicValues::new(
+            BlockHash::from(DIGEST_HEX),
+            chain_name_digest,
+            EraId::new(5),
+            BlockHeight::new(50),
+            Digest::from(DIGEST_HEX),
+            None,
+        )
+    }
+
+    fn assert_roundtrip(public_values: PublicValues) {
+        let encoded = public_values.encode().unwrap();
+        assert_eq!(public_values.get_encoded_size(), encoded.len());
+
+        let (decoded, remainder) = PublicValues::decode(&encoded).unwrap();
+        assert_eq!(decoded, public_values);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn test_codec_public_values() {
+        for public_values in [
+            PublicValues::Digest(Digest::from(DIGEST_HEX)),
+            PublicValues::Signature(
+                Digest::from(DIGEST_HEX),
+                VerificationKey::from(
+                    "01764f83295812c03354e0cd64718a7e50b452696799dc9d6e446338d668f3b2d9",
+                ),
+            ),
+            PublicValues::BlockV1(get_block_public_values(None)),
+            PublicValues::BlockV2(get_block_public_values(Some(
+                ChainNameDigest::new_from_chain_name("casper"),
+            ))),
+            PublicValues::BlockV2(BlockPublicValues::new(
+                BlockHash::from(DIGEST_HEX),
+                Some(ChainNameDigest::new_from_chain_name("casper")),
+                EraId::new(5),
+                BlockHeight::new(50),
+                Digest::from(DIGEST_HEX),
+                Some(Digest::from(DIGEST_HEX)),
+            )),
+            PublicValues::BlockRangeV2(
+                get_block_public_values(Some(ChainNameDigest::new_from_chain_name("casper"))),
+                get_block_public_values(Some(ChainNameDigest::new_from_chain_name("casper"))),
+            ),
+            PublicValues::TransactionV2Inclusion(TransactionPublicValues::new(
+                get_block_public_values(Some(ChainNameDigest::new_from_chain_name("casper"))),
+                3,
+                TransactionV2Hash::new(Digest::from(DIGEST_HEX)),
+            )),
+            PublicValues::StateV2Inclusion(StatePublicValues::new(
+                get_block_public_values(Some(ChainNameDigest::new_from_chain_name("casper"))),
+                vec![1, 2, 3],
+                vec![4, 5],
+            )),
+            PublicValues::EraV2(EraPublicValues::new(
+                get_block_public_values(Some(ChainNameDigest::new_from_chain_name("casper"))),
+                BlockHash::from(DIGEST_HEX),
+                Digest::from(DIGEST_HEX),
+            )),
+        ] {
+            assert_roundtrip(public_values);
+        }
+    }
+
+    #[test]
+    fn test_block_public_values_layout() {
+        // N.B. fixed width prefix: tag || block hash || height || era id || state root hash.
+        let encoded = PublicValues::BlockV2(get_block_public_values(None))
+            .encode()
+            .unwrap();
+
+        assert_eq!(encoded.len(), 1 + 32 + 8 + 8 + 32 + 1 + 1);
+        assert_eq!(encoded[0], TAG_BLOCK_V2);
+        assert_eq!(&encoded[1..33], Digest::from(DIGEST_HEX).as_slice());
+        assert_eq!(&encoded[33..41], 50_u64.to_le_bytes().as_slice());
+    }
+
+    #[test]
+    fn test_decode_exact_rejects_left_over_bytes() {
+        let public_values = PublicValues::BlockV2(get_block_public_values(None));
+        let mut encoded = public_values.encode().unwrap();
+        assert_eq!(PublicValues::decode_exact(&encoded), Ok(public_values));
+
+        encoded.push(0);
+        assert_eq!(
+            PublicValues::decode_exact(&encoded),
+            Err(CodecError::LeftOverBytes)
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_input() {
+        let encoded = PublicValues::BlockV2(get_block_public_values(None))
+            .encode()
+            .unwrap();
+
+        // Truncated.
+        for length in 0..encoded.len() {
+            assert_eq!(
+                PublicValues::decode(&encoded[..length]),
+                Err(CodecError::EarlyEndOfStream)
+            );
+        }
+
+        // Unsupported tag.
+        let mut encoded = encoded;
+        encoded[0] = u8::MAX;
+        assert_eq!(PublicValues::decode(&encoded), Err(CodecError::Formatting));
+    }
+}
@@ -1,3 +1,38 @@
+use std::fmt;
+
+/// Reasons for which a signature may fail verification.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CryptoError {
+    /// Message cannot be decoded, e.g. a secp256k1 message that is not a 32 byte digest.
+    InvalidMessage,
+
+    /// Signature bytes cannot be decoded.
+    InvalidSignature,
+
+    /// Signing key bytes cannot be decoded, e.g. a secp256k1 scalar outside of curve order.
+    InvalidSigningKey,
+
+    /// Verification key bytes cannot be decoded.
+    InvalidVerificationKey,
+
+    /// Signature was not issued over message by verification key's signing counterpart.
+    VerificationFailure,
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CryptoError::InvalidMessage => write!(f, "Invalid message encoding"),
+            CryptoError::InvalidSignature => write!(f, "Invalid signature encoding"),
+            CryptoError::InvalidSigningKey => write!(f, "Invalid signing key encoding"),
+            CryptoError::InvalidVerificationKey => write!(f, "Invalid verification key encoding"),
+            CryptoError::VerificationFailure => write!(f, "Signature verification failure"),
+        }
+    }
+}
+
+impl std::error::Error for CryptoError {}
+
 /// Returns a blake2b digest over passed data.
 ///
 /// # Arguments
@@ -18,6 +53,115 @@ pub fn get_hash_blake2b(data: Vec<u8>) -> [u8; 32] {
     buffer
 }
 
+/// Returns a SHA-256 digest over passed data.
+///
+/// # Arguments
+///
+/// * `data` - Data against which to generate a SHA-256 digest.
+///
+pub fn get_hash_sha256(data: Vec<u8>) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(data).into()
+}
+
+/// Returns an ed25519 signing key deterministically derived from a seed.
+///
+/// # Arguments
+///
+/// * `seed` - Arbitrary data from which to derive a signing key.
+///
+pub fn get_signing_key_ed25519(seed: &[u8]) -> [u8; 32] {
+    get_hash_blake2b(seed.to_vec())
+}
+
+/// Returns a secp256k1 signing key deterministically derived from a seed.
+///
+/// N.B. Seed is rehashed until its digest is a valid secp256k1 scalar.
+///
+/// # Arguments
+///
+/// * `seed` - Arbitrary data from which to derive a signing key.
+///
+pub fn get_signing_key_secp256k1(seed: &[u8]) -> [u8; 32] {
+    let mut skey = get_hash_blake2b(seed.to_vec());
+    while get_verification_key_secp256k1(&skey).is_err() {
+        skey = get_hash_blake2b(skey.to_vec());
+    }
+
+    skey
+}
+
+/// Returns ed25519 verification key counterpart to a signing key.
+///
+/// # Arguments
+///
+/// * `skey` - Signing key.
+///
+pub fn get_verification_key_ed25519(skey: &[u8; 32]) -> [u8; 32] {
+    ed25519_consensus::SigningKey::from(*skey)
+        .verification_key()
+        .to_bytes()
+}
+
+/// Returns secp256k1 verification key (compressed) counterpart to a signing key.
+///
+/// # Arguments
+///
+/// * `skey` - Signing key.
+///
+pub fn get_verification_key_secp256k1(skey: &[u8; 32]) -> Result<[u8; 33], CryptoError> {
+    use secp256k1::{Secp256k1, SecretKey};
+
+    let skey =
+        SecretKey::from_slice(skey.as_slice()).map_err(|_| CryptoError::InvalidSigningKey)?;
+
+    Ok(skey.public_key(&Secp256k1::signing_only()).serialize())
+}
+
+/// Returns ed25519 signature over arbitrary data.
+///
+/// # Arguments
+///
+/// * `skey` - Signing key.
+/// * `msg` - Data to be signed over.
+///
+pub fn sign_ed25519(skey: &[u8; 32], msg: &[u8]) -> [u8; 64] {
+    ed25519_consensus::SigningKey::from(*skey)
+        .sign(msg)
+        .to_bytes()
+}
+
+/// Returns secp256k1 signature over a 32 byte digest.
+///
+/// # Arguments
+///
+/// * `skey` - Signing key.
+/// * `msg` - Digest to be signed over.
+///
+pub fn sign_secp256k1(skey: &[u8; 32], msg: &[u8]) -> Result<[u8; 64], CryptoError> {
+    use secp256k1::{Message, Secp256k1, SecretKey};
+
+    let msg = Message::from_digest_slice(msg).map_err(|_| CryptoError::InvalidMessage)?;
+    let skey =
+        SecretKey::from_slice(skey.as_slice()).map_err(|_| CryptoError::InvalidSigningKey)?;
+
+    Ok(Secp256k1::signing_only()
+        .sign_ecdsa(&msg, &skey)
+        .serialize_compact())
+}
+
+/// Returns secp256k1 signature over a SHA-256 digest of arbitrary data, i.e. as per Casper.
+///
+/// # Arguments
+///
+/// * `skey` - Signing key.
+/// * `msg` - Data to be signed over.
+///
+pub fn sign_secp256k1_prehashed(skey: &[u8; 32], msg: &[u8]) -> Result<[u8; 64], CryptoError> {
+    sign_secp256k1(skey, &get_hash_sha256(msg.to_vec()))
+}
+
 /// Verifies ed25519 signature against arbitrary data.
 ///
 /// # Arguments
@@ -26,18 +170,82 @@ pub fn get_hash_blake2b(data: Vec<u8>) -> [u8; 32] {
 /// * `vkey` - Verification key counterpart to signing key.
 /// * `msg` - Data over which signature was issued.
 ///
-pub fn verify_signature_ed25519(sig: &[u8; 64], vkey: &[u8; 32], msg: &[u8]) {
+pub fn verify_signature_ed25519(
+    sig: &[u8; 64],
+    vkey: &[u8; 32],
+    msg: &[u8],
+) -> Result<(), CryptoError> {
     use ed25519_consensus::{Signature, VerificationKey};
 
-    let sig = Signature::try_from(sig.as_slice()).unwrap();
-    let vkey = VerificationKey::try_from(vkey.as_slice()).unwrap();
-    match vkey.verify(&sig, &msg) {
-        Result::Ok(_) => {}
-        Result::Err(_) => panic!("ED25519 signature verification failure"),
+    let sig = Signature::from(*sig);
+    let vkey = VerificationKey::try_from(vkey.as_slice())
+        .map_err(|_| CryptoError::InvalidVerificationKey)?;
+
+    vkey.verify(&sig, msg)
+        .map_err(|_| CryptoError::VerificationFailure)
+}
+
+/// Verifies a batch of ed25519 signatures, each against arbitrary data.
+///
+/// N.B. Batch verification agrees with individual verification (ZIP215) but does not identify
+/// which signature is invalid. Its random coefficients are seeded from a digest over the batch
+/// so that verification is deterministic, e.g. within a zk-vm, yet unpredictable to a signer.
+///
+/// # Arguments
+///
+/// * `batch` - Set of signatures, verification keys & data over which signatures were issued.
+///
+pub fn verify_signatures_ed25519(
+    batch: &[(&[u8; 64], &[u8; 32], &[u8])],
+) -> Result<(), CryptoError> {
+    use ed25519_consensus::{batch::Verifier, Signature, VerificationKeyBytes};
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    let mut transcript = Vec::new();
+    let mut verifier = Verifier::new();
+    for (sig, vkey, msg) in batch {
+        transcript.extend_from_slice(sig.as_slice());
+        transcript.extend_from_slice(vkey.as_slice());
+        transcript.extend_from_slice(msg);
+        verifier.queue((
+            VerificationKeyBytes::from(**vkey),
+            Signature::from(**sig),
+            msg,
+        ));
     }
+
+    verifier
+        .verify(ChaCha20Rng::from_seed(get_hash_blake2b(transcript)))
+        .map_err(|_| CryptoError::VerificationFailure)
+}
+
+/// Verifies secp256k1 signature against a 32 byte digest.
+///
+/// # Arguments
+///
+/// * `sig` - Signature over digest to be verified.
+/// * `vkey` - Verification key counterpart to signing key.
+/// * `msg` - Digest over which signature was issued.
+///
+pub fn verify_signature_secp256k1(
+    sig: &[u8; 64],
+    vkey: &[u8; 33],
+    msg: &[u8],
+) -> Result<(), CryptoError> {
+    use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1};
+
+    let msg = Message::from_digest_slice(msg).map_err(|_| CryptoError::InvalidMessage)?;
+    let pbk =
+        PublicKey::from_slice(vkey.as_slice()).map_err(|_| CryptoError::InvalidVerificationKey)?;
+    let sig = Signature::from_compact(sig.as_slice()).map_err(|_| CryptoError::InvalidSignature)?;
+
+    Secp256k1::new()
+        .verify_ecdsa(&msg, &sig, &pbk)
+        .map_err(|_| CryptoError::VerificationFailure)
 }
 
-/// Verifies secp256k1 signature against arbitrary data.
+/// Verifies secp256k1 signature against arbitrary data, i.e. a signature issued over a SHA-256
+/// digest of the data as per Casper.
 ///
 /// # Arguments
 ///
@@ -45,14 +253,135 @@ pub fn verify_signature_ed25519(sig: &[u8; 64], vkey: &[u8; 32], msg: &[u8]) {
 /// * `vkey` - Verification key counterpart to signing key.
 /// * `msg` - Data over which signature was issued.
 ///
-pub fn verify_signature_secp256k1(sig: &[u8; 64], vkey: &[u8; 33], msg: &[u8]) {
-    use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1};
+pub fn verify_signature_secp256k1_prehashed(
+    sig: &[u8; 64],
+    vkey: &[u8; 33],
+    msg: &[u8],
+) -> Result<(), CryptoError> {
+    verify_signature_secp256k1(sig, vkey, &get_hash_sha256(msg.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MSG: &[u8] = "أبو يوسف يعقوب بن إسحاق الصبّاح الكندي‎".as_bytes();
+
+    const SECP256K1_SKEY_HEX: &str =
+        "0b0e6094077ebae48bc119b35a134890424e0e5dbb71e667edae1978c49e7089";
+
+    const SECP256K1_VKEY_HEX: &str =
+        "03eed4eb0b40b3131679c365e3a23780eabfeaeb01776b0f908223ad1d4bd06f0d";
+
+    const SECP256K1_SIG_HEX: &str = "afff3803b1fc41eb4d66c3bbe373729f4fa288aff7d640b8357adc18396188a6501a813c45cc28245e082133894eab4e2dab0c7b5a6bb252388f026292ea2393";
+
+    fn get_batch_ed25519(size: usize) -> Vec<([u8; 64], [u8; 32], Vec<u8>)> {
+        (0..size)
+            .map(|idx| {
+                let skey = get_signing_key_ed25519(&rand::random::<[u8; 32]>());
+                let msg = [MSG, &[idx as u8]].concat();
+
+                (
+                    sign_ed25519(&skey, &msg),
+                    get_verification_key_ed25519(&skey),
+                    msg,
+                )
+            })
+            .collect()
+    }
+
+    fn verify_batch_ed25519(batch: &[([u8; 64], [u8; 32], Vec<u8>)]) -> Result<(), CryptoError> {
+        verify_signatures_ed25519(
+            &batch
+                .iter()
+                .map(|(sig, vkey, msg)| (sig, vkey, msg.as_slice()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_that_batch_of_valid_ed25519_signatures_is_verified() {
+        let batch = get_batch_ed25519(100);
+
+        assert_eq!(verify_batch_ed25519(&batch), Ok(()));
+        assert_eq!(verify_batch_ed25519(&[]), Ok(()));
+        for (sig, vkey, msg) in &batch {
+            assert_eq!(verify_signature_ed25519(sig, vkey, msg), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_that_batch_with_an_invalid_ed25519_signature_is_rejected() {
+        let mut batch = get_batch_ed25519(10);
+        batch[7].2 = MSG.to_vec();
+
+        assert_eq!(
+            verify_batch_ed25519(&batch),
+            Err(CryptoError::VerificationFailure)
+        );
+    }
+
+    #[test]
+    fn test_that_secp256k1_signature_over_prehashed_data_is_verified() {
+        let skey = get_signing_key_secp256k1(&rand::random::<[u8; 32]>());
+        let vkey = get_verification_key_secp256k1(&skey).unwrap();
+        let sig = sign_secp256k1_prehashed(&skey, MSG).unwrap();
+
+        assert_eq!(
+            verify_signature_secp256k1_prehashed(&sig, &vkey, MSG),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature_secp256k1(&sig, &vkey, &get_hash_sha256(MSG.to_vec())),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature_secp256k1_prehashed(&sig, &vkey, &MSG[1..]),
+            Err(CryptoError::VerificationFailure)
+        );
+    }
+
+    #[test]
+    fn test_that_secp256k1_signature_issued_by_k256_is_verified() {
+        // Vector issued by k256 `SigningKey::sign`, i.e. as per casper-types `crypto::sign`.
+        let skey: [u8; 32] = hex::decode(SECP256K1_SKEY_HEX).unwrap().try_into().unwrap();
+        let vkey: [u8; 33] = hex::decode(SECP256K1_VKEY_HEX).unwrap().try_into().unwrap();
+        let sig: [u8; 64] = hex::decode(SECP256K1_SIG_HEX).unwrap().try_into().unwrap();
+
+        assert_eq!(get_verification_key_secp256k1(&skey), Ok(vkey));
+        assert_eq!(sign_secp256k1_prehashed(&skey, MSG), Ok(sig));
+        assert_eq!(
+            verify_signature_secp256k1_prehashed(&sig, &vkey, MSG),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature_secp256k1(&sig, &vkey, &get_hash_blake2b(MSG.to_vec())),
+            Err(CryptoError::VerificationFailure)
+        );
+    }
+
+    #[test]
+    fn test_that_signing_keys_are_derived_deterministically() {
+        assert_eq!(get_signing_key_ed25519(MSG), get_signing_key_ed25519(MSG));
+        assert_eq!(
+            get_signing_key_secp256k1(MSG),
+            get_signing_key_secp256k1(MSG)
+        );
+        assert_ne!(
+            get_signing_key_ed25519(MSG),
+            get_signing_key_ed25519(&MSG[1..])
+        );
+    }
 
-    let msg = Message::from_digest_slice(msg).unwrap();
-    let pbk = PublicKey::from_slice(vkey.as_slice()).unwrap();
-    let sig = Signature::from_compact(&sig.as_slice()).unwrap();
-    match Secp256k1::new().verify_ecdsa(&msg, &sig, &pbk) {
-        Result::Ok(_) => {}
-        Result::Err(_) => panic!("SECP256K1 signature verification failure"),
+    #[test]
+    fn test_error_if_secp256k1_signing_key_is_invalid() {
+        assert_eq!(
+            get_verification_key_secp256k1(&[0_u8; 32]),
+            Err(CryptoError::InvalidSigningKey)
+        );
+        assert_eq!(
+            sign_secp256k1(&[u8::MAX; 32], &get_hash_blake2b(MSG.to_vec())),
+            Err(CryptoError::InvalidSigningKey)
+        );
     }
 }
@@ -0,0 +1,13 @@
+use sp1_build::{build_program_with_args, BuildArgs};
+
+// N.B. Program ELF is emitted to workspace's elf directory, i.e. as loaded by prover, so that it is never stale.
+fn main() {
+    build_program_with_args(
+        "../../program",
+        BuildArgs {
+            elf_name: Some(String::from("riscv32im-succinct-zkvm-elf")),
+            output_directory: Some(String::from("elf")),
+            ..Default::default()
+        },
+    )
+}
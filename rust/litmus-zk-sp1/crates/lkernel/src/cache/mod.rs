@@ -1,9 +1,567 @@
-use super::kernel::Config;
+//! Content-addressed store of blocks & proofs.
+//!
+//! Entries are written beneath the configured root directory as follows:
+//!
+//! ```text
+//! <path_to_root>/
+//!     index.json                          block hashes indexed by block height & by era id (switch blocks only).
+//!     blocks/<block hash>.cbor            CBOR encoded `BlockWithProofs`.
+//!     proofs/<block hash>/<digest>.bin    checksummed proof, where digest is over program inputs.
+//! ```
+//!
+//! Checksummed entries are prefixed by a blake2b digest over their payload.  Entries failing
+//! an integrity check upon load are evicted & reported as a cache miss, whilst I/O failures
+//! (including failure to evict) are returned to caller.
+//!
+//! N.B. Blocks are cached as fetched, i.e. unverified, hence validator sets are never derived
+//! from cached blocks other than by verifying them.
 
-pub struct Cache {}
+use super::{
+    fetcher::{Fetcher, FetcherBackend},
+    kernel::config::Config,
+};
+use camino::{Utf8Path, Utf8PathBuf};
+use ltypeset::{
+    chain::{BlockHash, BlockHeight, BlockID, BlockWithProofs, EraId},
+    crypto::Digest,
+};
+use ltypeset_utils::crypto::get_hash_for_block;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Error, ErrorKind},
+    sync::RwLock,
+};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const DNAME_OF_BLOCKS: &str = "blocks";
+const DNAME_OF_PROOFS: &str = "proofs";
+const FNAME_OF_INDEX: &str = "index.json";
+const SIZE_OF_CHECKSUM: usize = 32;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+pub struct Cache {
+    index: RwLock<CacheIndex>,
+    path_to_root: Utf8PathBuf,
+}
+
+/// Fetcher fronted by a cache, i.e. a block is fetched from backend upon a cache miss & then cached.
+pub struct CachedFetcher<'a> {
+    cache: &'a Cache,
+    fetcher: &'a Fetcher,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheIndex {
+    /// Block hashes indexed by block height.
+    by_height: BTreeMap<u64, BlockHash>,
+
+    /// Switch block hashes indexed by era id.
+    by_era: BTreeMap<u64, BlockHash>,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
 
 impl Cache {
-    pub fn new(_: Config) -> Self {
-        Self {}
+    pub fn new(config: Config) -> Self {
+        Self::new_from_path(Utf8Path::new(config.cache().path_to_root()))
+    }
+
+    pub fn new_from_path(path_to_root: &Utf8Path) -> Self {
+        let index = match fs::read_to_string(path_to_root.join(FNAME_OF_INDEX)) {
+            Ok(inner) => serde_json::from_str(&inner).unwrap_or_default(),
+            Err(_) => CacheIndex::default(),
+        };
+
+        Self {
+            index: RwLock::new(index),
+            path_to_root: path_to_root.to_owned(),
+        }
+    }
+}
+
+impl<'a> CachedFetcher<'a> {
+    pub fn new(cache: &'a Cache, fetcher: &'a Fetcher) -> Self {
+        Self { cache, fetcher }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl Cache {
+    pub fn path_to_root(&self) -> &Utf8Path {
+        &self.path_to_root
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl Cache {
+    /// Returns a cached block (if any), evicting it if it fails an integrity check.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_id` - Identifier of a cached block.
+    ///
+    pub fn get_block_with_proofs(
+        &self,
+        block_id: BlockID,
+    ) -> Result<Option<BlockWithProofs>, Error> {
+        let block_hash = match block_id {
+            BlockID::BlockHash(inner) => inner,
+            BlockID::BlockHeight(inner) => {
+                match self.index.read().unwrap().by_height.get(&inner.inner()) {
+                    Some(block_hash) => *block_hash,
+                    None => return Ok(None),
+                }
+            }
+        };
+        let encoded = match read_file(&self.get_path_to_block(&block_hash))? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+
+        // Integrity check: block must be that requested & its hash must be recomputable.
+        match serde_cbor::from_slice::<BlockWithProofs>(&encoded) {
+            Ok(inner)
+                if inner.block().hash() == &block_hash
+                    && get_hash_for_block(inner.block()) == block_hash =>
+            {
+                Ok(Some(inner))
+            }
+            _ => {
+                self.evict_block(&block_hash)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns a cached proof (if any), evicting it if it fails an integrity check.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - Hash of block to which proof pertains.
+    /// * `inputs_digest` - Digest over program inputs from which proof was generated.
+    ///
+    pub fn get_proof(
+        &self,
+        block_hash: &BlockHash,
+        inputs_digest: &Digest,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        read_checksummed(&self.get_path_to_proof(block_hash, inputs_digest))
+    }
+
+    /// Returns a cached switch block by era (if any).
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Identifier of era which switch block ended.
+    ///
+    pub fn get_switch_block_with_proofs(
+        &self,
+        era_id: EraId,
+    ) -> Result<Option<BlockWithProofs>, Error> {
+        let block_hash = match self.index.read().unwrap().by_era.get(&era_id.inner()) {
+            Some(block_hash) => *block_hash,
+            None => return Ok(None),
+        };
+
+        self.get_block_with_proofs(BlockID::from(block_hash))
+    }
+
+    /// Caches a block.
+    ///
+    /// N.B. Block is cached unverified, i.e. it must be verified upon retrieval.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_with_proofs` - Block to be cached.
+    ///
+    pub fn set_block_with_proofs(&self, block_with_proofs: &BlockWithProofs) -> Result<(), Error> {
+        let block = block_with_proofs.block();
+        write_file(
+            &self.get_path_to_block(block.hash()),
+            serde_cbor::to_vec(block_with_proofs)
+                .map_err(Error::other)?
+                .as_slice(),
+        )?;
+
+        let mut index = self.index.write().unwrap();
+        index
+            .by_height
+            .insert(block.height().inner(), *block.hash());
+        if block.is_switch() {
+            index.by_era.insert(block.era_id().inner(), *block.hash());
+        }
+        self.write_index(&index)
+    }
+
+    /// Caches a proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - Hash of block to which proof pertains.
+    /// * `inputs_digest` - Digest over program inputs from which proof was generated.
+    /// * `proof` - Encoded proof.
+    ///
+    pub fn set_proof(
+        &self,
+        block_hash: &BlockHash,
+        inputs_digest: &Digest,
+        proof: &[u8],
+    ) -> Result<(), Error> {
+        write_checksummed(&self.get_path_to_proof(block_hash, inputs_digest), proof)
+    }
+
+    /// Evicts a block plus associated proofs.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - Hash of block to be evicted.
+    ///
+    pub fn evict_block(&self, block_hash: &BlockHash) -> Result<(), Error> {
+        let mut index = self.index.write().unwrap();
+        index.by_height.retain(|_, v| v != block_hash);
+        index.by_era.retain(|_, v| v != block_hash);
+
+        remove_path(&self.get_path_to_block(block_hash))?;
+        remove_path(
+            &self
+                .path_to_root
+                .join(DNAME_OF_PROOFS)
+                .join(to_hex(block_hash)),
+        )?;
+
+        self.write_index(&index)
+    }
+
+    /// Evicts block at a block height (if any).
+    ///
+    /// # Arguments
+    ///
+    /// * `block_height` - Height of block to be evicted.
+    ///
+    pub fn evict_block_at_height(&self, block_height: BlockHeight) -> Result<(), Error> {
+        let block_hash = self
+            .index
+            .read()
+            .unwrap()
+            .by_height
+            .get(&block_height.inner())
+            .copied();
+
+        match block_hash {
+            Some(block_hash) => self.evict_block(&block_hash),
+            None => Ok(()),
+        }
+    }
+
+    /// Evicts all blocks below a block height.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_height` - Height of lowest block to be retained.
+    ///
+    pub fn evict_blocks_below(&self, block_height: BlockHeight) -> Result<(), Error> {
+        let set_of_block_hashes: Vec<BlockHash> = self
+            .index
+            .read()
+            .unwrap()
+            .by_height
+            .range(..block_height.inner())
+            .map(|(_, v)| *v)
+            .collect();
+
+        for block_hash in set_of_block_hashes {
+            self.evict_block(&block_hash)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_path_to_block(&self, block_hash: &BlockHash) -> Utf8PathBuf {
+        self.path_to_root
+            .join(DNAME_OF_BLOCKS)
+            .join(format!("{}.cbor", to_hex(block_hash)))
+    }
+
+    fn get_path_to_proof(&self, block_hash: &BlockHash, inputs_digest: &Digest) -> Utf8PathBuf {
+        self.path_to_root
+            .join(DNAME_OF_PROOFS)
+            .join(to_hex(block_hash))
+            .join(format!("{}.bin", hex::encode(inputs_digest.as_slice())))
+    }
+
+    fn write_index(&self, index: &CacheIndex) -> Result<(), Error> {
+        write_file(
+            &self.path_to_root.join(FNAME_OF_INDEX),
+            serde_json::to_vec(index)?.as_slice(),
+        )
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl FetcherBackend for CachedFetcher<'_> {
+    fn get_block_with_proofs(&self, block_id: BlockID) -> Result<Option<BlockWithProofs>, Error> {
+        if let Some(inner) = self.cache.get_block_with_proofs(block_id)? {
+            return Ok(Some(inner));
+        }
+
+        let block_with_proofs = match self.fetcher.get_block_with_proofs(block_id)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        self.cache.set_block_with_proofs(&block_with_proofs)?;
+
+        Ok(Some(block_with_proofs))
+    }
+
+    fn init(&self) -> Result<(), Error> {
+        self.fetcher.init()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+fn read_checksummed(path_to_entry: &Utf8Path) -> Result<Option<Vec<u8>>, Error> {
+    let bytes = match read_file(path_to_entry)? {
+        Some(inner) => inner,
+        None => return Ok(None),
+    };
+    if bytes.len() >= SIZE_OF_CHECKSUM {
+        let (checksum, payload) = bytes.split_at(SIZE_OF_CHECKSUM);
+        if lcrypto::get_hash_blake2b(payload.to_vec()).as_slice() == checksum {
+            return Ok(Some(payload.to_vec()));
+        }
+    }
+
+    // Integrity check failed therefore evict.
+    fs::remove_file(path_to_entry)?;
+    Ok(None)
+}
+
+// N.B. A missing file is a cache miss rather than a failure.
+fn read_file(path_to_file: &Utf8Path) -> Result<Option<Vec<u8>>, Error> {
+    match fs::read(path_to_file) {
+        Ok(inner) => Ok(Some(inner)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn remove_path(path: &Utf8Path) -> Result<(), Error> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else if path.exists() {
+        fs::remove_file(path)
+    } else {
+        Ok(())
+    }
+}
+
+fn to_hex(block_hash: &BlockHash) -> String {
+    hex::encode(block_hash.inner().as_slice())
+}
+
+fn write_checksummed(path_to_entry: &Utf8Path, payload: &[u8]) -> Result<(), Error> {
+    let mut bytes = lcrypto::get_hash_blake2b(payload.to_vec()).to_vec();
+    bytes.extend_from_slice(payload);
+
+    write_file(path_to_entry, &bytes)
+}
+
+fn write_file(path_to_file: &Utf8Path, bytes: &[u8]) -> Result<(), Error> {
+    fs::create_dir_all(path_to_file.parent().unwrap())?;
+    fs::write(path_to_file, bytes)
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetcher::FileSystemFetcher;
+    use ltypeset_utils::fixtures::{get_block_with_proofs, get_path_to_chain_fixtures};
+    use rand::Rng;
+    use std::env;
+
+    fn get_cache() -> Cache {
+        let path_to_root = Utf8PathBuf::from_path_buf(env::temp_dir())
+            .unwrap()
+            .join(format!("lkernel-cache-{}", rand::thread_rng().gen::<u64>()));
+
+        Cache::new_from_path(&path_to_root)
+    }
+
+    #[test]
+    fn test_that_block_can_be_cached() {
+        let cache = get_cache();
+        let block_with_proofs = get_block_with_proofs(12);
+        let block_hash = *block_with_proofs.block().hash();
+        cache.set_block_with_proofs(&block_with_proofs).unwrap();
+
+        assert_eq!(
+            cache
+                .get_block_with_proofs(BlockID::from(block_hash))
+                .unwrap(),
+            Some(block_with_proofs.clone())
+        );
+        assert_eq!(
+            cache.get_block_with_proofs(BlockID::from(12)).unwrap(),
+            Some(block_with_proofs)
+        );
+        assert_eq!(
+            cache.get_block_with_proofs(BlockID::from(13)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_that_switch_block_is_cached() {
+        let cache = get_cache();
+        let block_with_proofs = get_block_with_proofs(11);
+        cache.set_block_with_proofs(&block_with_proofs).unwrap();
+
+        let era_id = block_with_proofs.block().era_id();
+        assert_eq!(
+            cache.get_switch_block_with_proofs(era_id).unwrap(),
+            Some(block_with_proofs)
+        );
+        assert!(!cache.path_to_root().join("eras").exists());
+    }
+
+    #[test]
+    fn test_that_index_is_persisted() {
+        let cache = get_cache();
+        cache
+            .set_block_with_proofs(&get_block_with_proofs(12))
+            .unwrap();
+
+        let cache = Cache::new_from_path(cache.path_to_root());
+        assert!(cache
+            .get_block_with_proofs(BlockID::from(12))
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_that_tampered_block_is_evicted() {
+        let cache = get_cache();
+        let block_with_proofs = get_block_with_proofs(12);
+        let block_hash = *block_with_proofs.block().hash();
+        cache.set_block_with_proofs(&block_with_proofs).unwrap();
+
+        // Swap content of cached block for that of another block.
+        fs::write(
+            cache.get_path_to_block(&block_hash),
+            serde_cbor::to_vec(&get_block_with_proofs(13)).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            cache
+                .get_block_with_proofs(BlockID::from(block_hash))
+                .unwrap(),
+            None
+        );
+        assert!(!cache.get_path_to_block(&block_hash).exists());
+        assert_eq!(
+            cache.get_block_with_proofs(BlockID::from(12)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_that_proof_can_be_cached() {
+        let cache = get_cache();
+        let block_hash = *get_block_with_proofs(12).block().hash();
+        let inputs_digest = Digest::from(lcrypto::get_hash_blake2b(vec![1, 2, 3]).as_slice());
+        cache
+            .set_proof(&block_hash, &inputs_digest, &[4, 5, 6])
+            .unwrap();
+
+        assert_eq!(
+            cache.get_proof(&block_hash, &inputs_digest).unwrap(),
+            Some(vec![4, 5, 6])
+        );
+    }
+
+    #[test]
+    fn test_that_corrupted_proof_is_evicted() {
+        let cache = get_cache();
+        let block_hash = *get_block_with_proofs(12).block().hash();
+        let inputs_digest = Digest::from(lcrypto::get_hash_blake2b(vec![1, 2, 3]).as_slice());
+        cache
+            .set_proof(&block_hash, &inputs_digest, &[4, 5, 6])
+            .unwrap();
+
+        let path_to_proof = cache.get_path_to_proof(&block_hash, &inputs_digest);
+        let mut bytes = fs::read(&path_to_proof).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        fs::write(&path_to_proof, bytes).unwrap();
+
+        assert_eq!(cache.get_proof(&block_hash, &inputs_digest).unwrap(), None);
+        assert!(!path_to_proof.exists());
+    }
+
+    #[test]
+    fn test_error_if_block_cannot_be_cached() {
+        // Root of cache is a file, i.e. entries cannot be written beneath it.
+        let path_to_root = get_cache().path_to_root().to_owned();
+        write_file(&path_to_root, &[]).unwrap();
+        let cache = Cache::new_from_path(&path_to_root);
+        let fetcher = Fetcher::FileSystem(FileSystemFetcher::new(get_path_to_chain_fixtures()));
+
+        assert!(CachedFetcher::new(&cache, &fetcher)
+            .get_block_with_proofs(BlockID::from(12))
+            .is_err());
+    }
+
+    #[test]
+    fn test_that_blocks_below_a_height_are_evicted() {
+        let cache = get_cache();
+        for height in 10..14 {
+            cache
+                .set_block_with_proofs(&get_block_with_proofs(height))
+                .unwrap();
+        }
+        cache.evict_blocks_below(BlockHeight::from(12)).unwrap();
+
+        assert_eq!(
+            cache.get_block_with_proofs(BlockID::from(10)).unwrap(),
+            None
+        );
+        assert_eq!(
+            cache.get_block_with_proofs(BlockID::from(11)).unwrap(),
+            None
+        );
+        assert!(cache
+            .get_block_with_proofs(BlockID::from(12))
+            .unwrap()
+            .is_some());
+        assert!(cache
+            .get_block_with_proofs(BlockID::from(13))
+            .unwrap()
+            .is_some());
     }
 }
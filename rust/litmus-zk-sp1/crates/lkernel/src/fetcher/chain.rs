@@ -1,22 +1,180 @@
 use super::FetcherBackend;
 use ltypeset::chain::{BlockID, BlockWithProofs};
-use std::io::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    io::{Error, ErrorKind},
+    time::Duration,
+};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// JSON-RPC error code: node does not hold requested block, e.g. it has not yet been produced.
+const ERR_CODE_NO_SUCH_BLOCK: i64 = -32001;
+
+// JSON-RPC protocol version.
+const JSONRPC_VERSION: &str = "2.0";
+
+// JSON-RPC method: returns a block plus associated finality signatures.
+const METHOD_CHAIN_GET_BLOCK: &str = "chain_get_block";
+
+// JSON-RPC method: returns node status information.
+const METHOD_INFO_GET_STATUS: &str = "info_get_status";
+
+// Path at which a node exposes its JSON-RPC API.
+const RPC_PATH: &str = "rpc";
+
+// Default timeout applied to each request made against a node.
+const TIMEOUT_IN_SECS: u64 = 10;
 
 // ------------------------------------------------------------------------
 // Declarations.
 // ------------------------------------------------------------------------
 
 pub struct Fetcher {
+    agent: ureq::Agent,
     ip_address_set: Vec<String>,
 }
 
+// JSON-RPC request envelope.
+#[derive(Debug, Serialize)]
+struct RpcRequest<'a> {
+    id: u64,
+    jsonrpc: &'a str,
+    method: &'a str,
+    params: Value,
+}
+
+// JSON-RPC response envelope.
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    error: Option<RpcError>,
+    result: Option<T>,
+}
+
+// JSON-RPC error information.
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+// Result of a `chain_get_block` call.
+#[derive(Debug, Deserialize)]
+struct GetBlockResult {
+    block_with_signatures: Option<BlockWithProofs>,
+}
+
 // ------------------------------------------------------------------------
 // Constructors.
 // ------------------------------------------------------------------------
 
 impl Fetcher {
     pub fn new(ip_address_set: Vec<String>) -> Self {
-        Self { ip_address_set }
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(TIMEOUT_IN_SECS))
+            .build();
+
+        Self {
+            agent,
+            ip_address_set,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl Fetcher {
+    pub fn ip_address_set(&self) -> &Vec<String> {
+        &self.ip_address_set
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl Fetcher {
+    /// Dispatches a JSON-RPC request to a node and returns the decoded result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address of node to which request will be dispatched.
+    /// * `method` - Name of JSON-RPC method to be invoked.
+    /// * `params` - Parameters to be passed to JSON-RPC method.
+    ///
+    fn call<T>(&self, address: &str, method: &str, params: Value) -> Result<Option<T>, Error>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let response: RpcResponse<T> = self.dispatch(address, method, params)?;
+
+        match response.error {
+            Some(err) => Err(get_rpc_error(method, err)),
+            None => Ok(response.result),
+        }
+    }
+
+    /// Dispatches a JSON-RPC request to a node and returns the decoded response envelope.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address of node to which request will be dispatched.
+    /// * `method` - Name of JSON-RPC method to be invoked.
+    /// * `params` - Parameters to be passed to JSON-RPC method.
+    ///
+    fn dispatch<T>(
+        &self,
+        address: &str,
+        method: &str,
+        params: Value,
+    ) -> Result<RpcResponse<T>, Error>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let request = RpcRequest {
+            id: 1,
+            jsonrpc: JSONRPC_VERSION,
+            method,
+            params,
+        };
+
+        self.agent
+            .post(&get_rpc_endpoint(address))
+            .send_json(&request)
+            .map_err(|err| Error::new(ErrorKind::ConnectionRefused, err.to_string()))?
+            .into_json()
+    }
+
+    /// Queries a single node for a block plus associated finality signatures.
+    ///
+    /// N.B. A node that does not hold block answers with a "No such block" error, which is mapped to `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address of node to be queried.
+    /// * `block_id` - Identifier of block being queried.
+    ///
+    fn get_block_with_proofs_from_node(
+        &self,
+        address: &str,
+        block_id: BlockID,
+    ) -> Result<Option<BlockWithProofs>, Error> {
+        let params = json!({ "block_identifier": get_block_identifier(block_id) });
+        let response: RpcResponse<GetBlockResult> =
+            self.dispatch(address, METHOD_CHAIN_GET_BLOCK, params)?;
+
+        match response.error {
+            Some(err) if err.code == ERR_CODE_NO_SUCH_BLOCK => Ok(None),
+            Some(err) => Err(get_rpc_error(METHOD_CHAIN_GET_BLOCK, err)),
+            None => Ok(response
+                .result
+                .and_then(|result| result.block_with_signatures)),
+        }
     }
 }
 
@@ -25,11 +183,312 @@ impl Fetcher {
 // ------------------------------------------------------------------------
 
 impl FetcherBackend for Fetcher {
-    fn get_block_with_proofs(&self, _: BlockID) -> Option<BlockWithProofs> {
-        unimplemented!()
+    fn get_block_with_proofs(&self, block_id: BlockID) -> Result<Option<BlockWithProofs>, Error> {
+        // Fail over across node set until a node returns block, i.e. a node may lag behind others.
+        let mut is_unknown = false;
+        let mut last_err = None;
+        for address in &self.ip_address_set {
+            match self.get_block_with_proofs_from_node(address, block_id) {
+                Ok(Some(block_with_proofs)) => return Ok(Some(block_with_proofs)),
+                Ok(None) => is_unknown = true,
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        // Block is unknown if any node responded, otherwise every node failed.
+        match (is_unknown, last_err) {
+            (true, _) => Ok(None),
+            (false, Some(err)) => Err(err),
+            (false, None) => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Chain fetcher requires at least one node address",
+            )),
+        }
     }
 
     fn init(&self) -> Result<(), Error> {
-        unimplemented!()
+        if self.ip_address_set.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Chain fetcher requires at least one node address",
+            ));
+        }
+
+        // At least one node must be reachable.
+        for address in &self.ip_address_set {
+            if self
+                .call::<Value>(address, METHOD_INFO_GET_STATUS, json!([]))
+                .is_ok()
+            {
+                return Ok(());
+            }
+        }
+
+        Err(Error::new(
+            ErrorKind::NotConnected,
+            "Chain fetcher was unable to reach any node",
+        ))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Maps a block identifier to a JSON-RPC block identifier.
+fn get_block_identifier(block_id: BlockID) -> Value {
+    match block_id {
+        BlockID::BlockHash(inner) => json!({ "Hash": inner }),
+        BlockID::BlockHeight(inner) => json!({ "Height": inner.inner() }),
+    }
+}
+
+// Maps a JSON-RPC error returned by a node to an I/O error.
+fn get_rpc_error(method: &str, err: RpcError) -> Error {
+    Error::new(
+        ErrorKind::Other,
+        format!("{} :: code={} :: {}", method, err.code, err.message),
+    )
+}
+
+// Maps a node address to a JSON-RPC endpoint, e.g. 127.0.0.1:11101 -> http://127.0.0.1:11101/rpc.
+fn get_rpc_endpoint(address: &str) -> String {
+    let address = address.trim_end_matches('/');
+    if address.starts_with("http://") || address.starts_with("https://") {
+        if address.ends_with(RPC_PATH) {
+            address.to_string()
+        } else {
+            format!("{address}/{RPC_PATH}")
+        }
+    } else {
+        format!("http://{address}/{RPC_PATH}")
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{get_rpc_endpoint, Fetcher, FetcherBackend};
+    use ltypeset::chain::{BlockHash, BlockID, BlockWithProofs};
+    use serde_json::{json, Value};
+    use std::{env, fs, thread};
+    use tiny_http::{Response, Server};
+
+    // Address at which nothing is listening.
+    const DEAD_ADDRESS: &str = "127.0.0.1:1";
+
+    fn get_path_to_root() -> String {
+        format!(
+            "{}/fixtures/blocks",
+            env::var("CARGO_MANIFEST_DIR").unwrap()
+        )
+    }
+
+    fn get_fixtures() -> Vec<Value> {
+        let mut result: Vec<Value> = fs::read_dir(get_path_to_root())
+            .unwrap()
+            .map(|f| serde_json::from_str(&fs::read_to_string(f.unwrap().path()).unwrap()).unwrap())
+            .collect();
+        result.sort_by_key(|f| f["block"]["Version2"]["header"]["height"].as_u64());
+
+        result
+    }
+
+    // Spins up a mock node serving fixture blocks over JSON-RPC & returns its address.
+    fn start_mock_node() -> String {
+        start_mock_node_with_fixtures(get_fixtures())
+    }
+
+    // Spins up a mock node serving a set of blocks over JSON-RPC & returns its address.
+    fn start_mock_node_with_fixtures(fixtures: Vec<Value>) -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap().to_string();
+
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let body: Value = serde_json::from_str(&body).unwrap();
+
+                let response = match body["method"].as_str().unwrap() {
+                    "info_get_status" => json!({
+                        "jsonrpc": "2.0",
+                        "id": body["id"],
+                        "result": { "api_version": "2.0.0" },
+                    }),
+                    "chain_get_block" => {
+                        let block_id = &body["params"]["block_identifier"];
+                        let block_with_signatures = fixtures.iter().find(|f| {
+                            let header = &f["block"]["Version2"]["header"];
+                            let hash = &f["block"]["Version2"]["hash"];
+                            block_id["Hash"] == *hash || block_id["Height"] == header["height"]
+                        });
+                        // N.B. as per a Casper node, an unknown block is an error.
+                        match block_with_signatures {
+                            Some(block_with_signatures) => json!({
+                                "jsonrpc": "2.0",
+                                "id": body["id"],
+                                "result": {
+                                    "api_version": "2.0.0",
+                                    "block_with_signatures": block_with_signatures,
+                                },
+                            }),
+                            None => json!({
+                                "jsonrpc": "2.0",
+                                "id": body["id"],
+                                "error": { "code": -32001, "message": "No such block" },
+                            }),
+                        }
+                    }
+                    _ => json!({
+                        "jsonrpc": "2.0",
+                        "id": body["id"],
+                        "error": { "code": -32601, "message": "Method not found" },
+                    }),
+                };
+
+                request
+                    .respond(Response::from_string(response.to_string()))
+                    .unwrap();
+            }
+        });
+
+        address
+    }
+
+    fn get_expected_block(height: u64) -> BlockWithProofs {
+        let fixture = get_fixtures()
+            .into_iter()
+            .find(|f| f["block"]["Version2"]["header"]["height"] == height)
+            .unwrap();
+
+        serde_json::from_value(fixture).unwrap()
+    }
+
+    #[test]
+    fn test_that_instance_can_be_instantiated() {
+        Fetcher::new(vec![String::from(DEAD_ADDRESS)]);
+    }
+
+    #[test]
+    fn test_that_rpc_endpoint_is_derived_from_address() {
+        for (address, expected) in [
+            ("127.0.0.1:11101", "http://127.0.0.1:11101/rpc"),
+            ("http://127.0.0.1:11101", "http://127.0.0.1:11101/rpc"),
+            ("http://127.0.0.1:11101/rpc", "http://127.0.0.1:11101/rpc"),
+            ("https://node.example/", "https://node.example/rpc"),
+        ] {
+            assert_eq!(get_rpc_endpoint(address), expected);
+        }
+    }
+
+    #[test]
+    fn test_that_init_fails_without_addresses() {
+        assert!(Fetcher::new(vec![]).init().is_err());
+    }
+
+    #[test]
+    fn test_that_init_fails_if_no_node_is_reachable() {
+        assert!(Fetcher::new(vec![String::from(DEAD_ADDRESS)])
+            .init()
+            .is_err());
+    }
+
+    #[test]
+    fn test_that_init_succeeds_if_a_node_is_reachable() {
+        let fetcher = Fetcher::new(vec![String::from(DEAD_ADDRESS), start_mock_node()]);
+        assert!(fetcher.init().is_ok());
+    }
+
+    #[test]
+    fn test_that_block_can_be_fetched_by_hash() {
+        let expected = get_expected_block(1);
+        let fetcher = Fetcher::new(vec![start_mock_node()]);
+        let block_id = BlockID::from(expected.block().hash().to_owned());
+
+        assert_eq!(
+            fetcher.get_block_with_proofs(block_id).unwrap(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_that_block_can_be_fetched_by_height() {
+        let expected = get_expected_block(1);
+        let fetcher = Fetcher::new(vec![start_mock_node()]);
+
+        assert_eq!(
+            fetcher.get_block_with_proofs(BlockID::from(1)).unwrap(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_that_fetcher_fails_over_to_next_node() {
+        let expected = get_expected_block(1);
+        let fetcher = Fetcher::new(vec![String::from(DEAD_ADDRESS), start_mock_node()]);
+
+        assert_eq!(
+            fetcher.get_block_with_proofs(BlockID::from(1)).unwrap(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_that_fetcher_fails_over_if_a_node_lacks_block() {
+        let expected = get_expected_block(1);
+        let fetcher = Fetcher::new(vec![
+            start_mock_node_with_fixtures(vec![]),
+            start_mock_node(),
+        ]);
+
+        assert_eq!(
+            fetcher.get_block_with_proofs(BlockID::from(1)).unwrap(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_that_unknown_method_is_an_error() {
+        let address = start_mock_node();
+        let fetcher = Fetcher::new(vec![address.clone()]);
+
+        assert!(fetcher
+            .call::<Value>(&address, "unknown_method", json!({}))
+            .is_err());
+    }
+
+    #[test]
+    fn test_that_unknown_block_yields_none() {
+        let fetcher = Fetcher::new(vec![start_mock_node()]);
+        let block_hash =
+            BlockHash::from("0000000000000000000000000000000000000000000000000000000000000001");
+
+        assert_eq!(
+            fetcher
+                .get_block_with_proofs(BlockID::from(block_hash))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_that_unknown_block_yields_none_if_another_node_is_unreachable() {
+        let fetcher = Fetcher::new(vec![String::from(DEAD_ADDRESS), start_mock_node()]);
+
+        assert_eq!(
+            fetcher.get_block_with_proofs(BlockID::from(1_000)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_that_fetch_fails_if_no_node_is_reachable() {
+        let fetcher = Fetcher::new(vec![String::from(DEAD_ADDRESS)]);
+
+        assert!(fetcher.get_block_with_proofs(BlockID::from(1)).is_err());
     }
 }
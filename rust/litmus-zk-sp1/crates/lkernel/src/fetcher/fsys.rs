@@ -1,24 +1,76 @@
 use super::FetcherBackend;
 use camino::{Utf8Path, Utf8PathBuf};
+use flate2::read::GzDecoder;
 use ltypeset::chain::{BlockHash, BlockHeight, BlockID, BlockWithProofs};
 use std::{
-    fs::{self, DirEntry},
-    io::Error,
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{Error, ErrorKind, Read},
+    sync::RwLock,
+    time::{Duration, SystemTime},
 };
 
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Prefix of name of each block file, i.e. block-{height}-{hash}.{extension}.
+const FNAME_PREFIX: &str = "block-";
+
+// Set of supported block file extensions plus respective encodings.
+const FNAME_EXTENSIONS: [(&str, BlockFileEncoding); 4] = [
+    (".cbor", BlockFileEncoding::Cbor),
+    (".cbor.gz", BlockFileEncoding::CborGzip),
+    (".json", BlockFileEncoding::Json),
+    (".json.gz", BlockFileEncoding::JsonGzip),
+];
+
+// Coarsest supported resolution of directory modification times.
+const MTIME_RESOLUTION: Duration = Duration::from_secs(1);
+
 // ------------------------------------------------------------------------
 // Declarations.
 // ------------------------------------------------------------------------
 
-#[derive(Debug)]
+/// Encoding of a block file's content.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BlockFileEncoding {
+    Cbor,
+    CborGzip,
+    Json,
+    JsonGzip,
+}
+
+#[derive(Clone, Debug)]
 struct BlockFileInfo {
+    encoding: BlockFileEncoding,
     hash: BlockHash,
     height: BlockHeight,
     path: Utf8PathBuf,
 }
 
+/// Index over a directory of block files, keyed by block height & block hash.
+#[derive(Debug, Default)]
+struct BlockFileIndex {
+    by_hash: HashMap<BlockHash, u64>,
+    by_height: BTreeMap<u64, BlockFileInfo>,
+
+    /// Modification times of scanned directories, i.e. used to detect block files written since.
+    mtimes: Vec<(Utf8PathBuf, SystemTime)>,
+
+    /// Time at which directory was scanned.
+    scanned_at: Option<SystemTime>,
+
+    /// Block files skipped due to an invalid file name or content.
+    skipped: Vec<Utf8PathBuf>,
+}
+
+/// Fetches blocks from a directory, plus nested (e.g. era) directories, of block files.
+///
+/// N.B. Files not prefixed `block-` are ignored, as are hidden files & directories.
 pub struct Fetcher {
-    fileset: Vec<BlockFileInfo>,
+    index: RwLock<BlockFileIndex>,
+    path_to_root: Utf8PathBuf,
 }
 
 // ------------------------------------------------------------------------
@@ -26,8 +78,14 @@ pub struct Fetcher {
 // ------------------------------------------------------------------------
 
 impl BlockFileInfo {
-    fn new(hash: BlockHash, height: BlockHeight, path_to_file: Utf8PathBuf) -> Self {
+    fn new(
+        encoding: BlockFileEncoding,
+        hash: BlockHash,
+        height: BlockHeight,
+        path_to_file: Utf8PathBuf,
+    ) -> Self {
         Self {
+            encoding,
             hash,
             height,
             path: path_to_file,
@@ -35,17 +93,60 @@ impl BlockFileInfo {
     }
 }
 
-impl Fetcher {
-    pub fn new(path_to_root: &Utf8Path) -> Self {
-        fn get_fileset(path_to_root: &Utf8Path) -> Vec<BlockFileInfo> {
-            fs::read_dir(path_to_root)
-                .unwrap()
-                .map(|f| BlockFileInfo::try_from(&f.unwrap()).unwrap())
-                .collect()
+impl BlockFileIndex {
+    /// Constructor: returns an index over a directory scanned recursively for block files.
+    ///
+    /// # Arguments
+    ///
+    /// * `path_to_root` - Path to directory to be scanned.
+    ///
+    fn new(path_to_root: &Utf8Path) -> Result<Self, Error> {
+        let mut index = Self {
+            scanned_at: Some(SystemTime::now()),
+            ..Default::default()
+        };
+        for path_to_file in get_paths_to_block_files(path_to_root, &mut index.mtimes)? {
+            let file_info = match BlockFileInfo::try_from(path_to_file.as_path()) {
+                Ok(file_info) => file_info,
+                Err(_) => {
+                    index.skipped.push(path_to_file);
+                    continue;
+                }
+            };
+            if index.by_height.contains_key(&file_info.height().inner()) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Duplicate block file for height: {path_to_file}"),
+                ));
+            }
+            index
+                .by_hash
+                .insert(*file_info.hash(), file_info.height().inner());
+            index
+                .by_height
+                .insert(file_info.height().inner(), file_info);
         }
 
+        Ok(index)
+    }
+}
+
+impl Fetcher {
+    /// Constructor: returns a fetcher over a directory of block files.
+    ///
+    /// N.B. Directory is indexed upon initialisation, and re-indexed whenever a block is not found
+    /// and a scanned directory has since been modified, i.e. so as to pick up block files written
+    /// since.  Block files whose name is invalid are skipped, see `set_of_skipped_files`.  A block
+    /// file whose content is invalid is also reported as such, whilst fetching it is an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `path_to_root` - Path to directory of block files.
+    ///
+    pub fn new(path_to_root: impl AsRef<Utf8Path>) -> Self {
         Self {
-            fileset: get_fileset(path_to_root),
+            index: RwLock::new(BlockFileIndex::default()),
+            path_to_root: path_to_root.as_ref().to_owned(),
         }
     }
 }
@@ -55,6 +156,10 @@ impl Fetcher {
 // ------------------------------------------------------------------------
 
 impl BlockFileInfo {
+    fn encoding(&self) -> BlockFileEncoding {
+        self.encoding
+    }
+
     fn hash(&self) -> &BlockHash {
         &self.hash
     }
@@ -68,57 +173,208 @@ impl BlockFileInfo {
     }
 }
 
+impl Fetcher {
+    pub fn path_to_root(&self) -> &Utf8Path {
+        &self.path_to_root
+    }
+
+    /// Returns block files skipped since directory was last indexed, i.e. due to an invalid name or
+    /// content.
+    pub fn set_of_skipped_files(&self) -> Vec<Utf8PathBuf> {
+        self.index.read().unwrap().skipped.clone()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl BlockFileEncoding {
+    fn is_compressed(&self) -> bool {
+        matches!(self, Self::CborGzip | Self::JsonGzip)
+    }
+}
+
+impl BlockFileInfo {
+    /// Reads & decodes block file, asserting that block matches file name.
+    fn read(&self) -> Result<BlockWithProofs, Error> {
+        let mut bytes = fs::read(self.path())?;
+        if self.encoding().is_compressed() {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+            bytes = decompressed;
+        }
+
+        let block_with_proofs: BlockWithProofs = match self.encoding() {
+            BlockFileEncoding::Cbor | BlockFileEncoding::CborGzip => {
+                serde_cbor::from_slice(&bytes).map_err(|err| get_decoding_error(self, err))?
+            }
+            BlockFileEncoding::Json | BlockFileEncoding::JsonGzip => {
+                serde_json::from_slice(&bytes).map_err(|err| get_decoding_error(self, err))?
+            }
+        };
+        let block = block_with_proofs.block();
+        if block.height() != self.height() || block.hash() != self.hash() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Block does not match block file name: {}", self.path()),
+            ));
+        }
+
+        Ok(block_with_proofs)
+    }
+}
+
+impl BlockFileIndex {
+    fn get(&self, block_id: BlockID) -> Option<&BlockFileInfo> {
+        let height = match block_id {
+            BlockID::BlockHash(block_hash) => *self.by_hash.get(&block_hash)?,
+            BlockID::BlockHeight(block_height) => block_height.inner(),
+        };
+
+        self.by_height.get(&height)
+    }
+
+    /// Returns true if a scanned directory has been modified since it was scanned.
+    ///
+    /// N.B. A directory modified within resolution of modification times of scan is deemed
+    /// modified, i.e. as a block file written during that interval may not have altered it.
+    fn is_stale(&self) -> bool {
+        let scanned_at = match self.scanned_at {
+            Some(inner) => inner,
+            None => return true,
+        };
+
+        self.mtimes.iter().any(|(path_to_dir, mtime)| {
+            *mtime + MTIME_RESOLUTION >= scanned_at
+                || fs::metadata(path_to_dir)
+                    .and_then(|metadata| metadata.modified())
+                    .map_or(true, |inner| inner != *mtime)
+        })
+    }
+}
+
 // ------------------------------------------------------------------------
 // Traits.
 // ------------------------------------------------------------------------
 
 impl FetcherBackend for Fetcher {
-    fn get_block_with_proofs(&self, block_id: BlockID) -> Option<BlockWithProofs> {
-        for file_info in &self.fileset {
-            match block_id {
-                BlockID::BlockHash(block_hash) => {
-                    if file_info.hash == block_hash {
-                        return Option::Some(BlockWithProofs::from(file_info));
-                    }
-                }
-                BlockID::BlockHeight(block_height) => {
-                    if file_info.height() == block_height {
-                        return Option::Some(BlockWithProofs::from(file_info));
+    // N.B. Errors if a block file is invalid.
+    fn get_block_with_proofs(&self, block_id: BlockID) -> Result<Option<BlockWithProofs>, Error> {
+        let get_file_info = || self.index.read().unwrap().get(block_id).cloned();
+
+        let file_info = match get_file_info() {
+            Some(file_info) => Some(file_info),
+            None if self.index.read().unwrap().is_stale() => {
+                self.init()?;
+                get_file_info()
+            }
+            None => None,
+        };
+
+        match file_info {
+            Some(file_info) => match file_info.read() {
+                Ok(block_with_proofs) => Ok(Some(block_with_proofs)),
+                Err(err) => {
+                    let mut index = self.index.write().unwrap();
+                    if !index.skipped.contains(file_info.path()) {
+                        index.skipped.push(file_info.path().to_owned());
                     }
+                    Err(err)
                 }
-            }
+            },
+            None => Ok(None),
         }
-
-        None
     }
 
     fn init(&self) -> Result<(), Error> {
+        let index = BlockFileIndex::new(&self.path_to_root)?;
+        *self.index.write().unwrap() = index;
+
         Ok(())
     }
 }
 
+impl TryFrom<&Utf8Path> for BlockFileInfo {
+    type Error = Error;
+
+    fn try_from(value: &Utf8Path) -> Result<Self, Self::Error> {
+        let get_error = || {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid block file name: {value}"),
+            )
+        };
+
+        // Parse: block-{height}-{hash}.{extension}.
+        let fname = value.file_name().ok_or_else(get_error)?;
+        let (stem, encoding) = FNAME_EXTENSIONS
+            .iter()
+            .find_map(|(extension, encoding)| {
+                fname.strip_suffix(extension).map(|stem| (stem, *encoding))
+            })
+            .ok_or_else(get_error)?;
+        let (height, hash) = stem
+            .strip_prefix(FNAME_PREFIX)
+            .and_then(|stem| stem.split_once('-'))
+            .ok_or_else(get_error)?;
+        let height: u64 = height.parse().map_err(|_| get_error())?;
+        let hash: [u8; 32] = hex::decode(hash)
+            .ok()
+            .and_then(|hash| hash.try_into().ok())
+            .ok_or_else(get_error)?;
+
+        Ok(BlockFileInfo::new(
+            encoding,
+            BlockHash::from(hash.as_slice()),
+            BlockHeight::new(height),
+            value.to_owned(),
+        ))
+    }
+}
+
 // ------------------------------------------------------------------------
-// Traits.
+// Helpers.
 // ------------------------------------------------------------------------
 
-impl From<&BlockFileInfo> for BlockWithProofs {
-    fn from(value: &BlockFileInfo) -> Self {
-        serde_json::from_str(&fs::read_to_string(&value.path).unwrap()).unwrap()
-    }
+// Returns an error wrapping a failure to decode a block file's content.
+fn get_decoding_error(file_info: &BlockFileInfo, err: impl ToString) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "Invalid block file content: {} :: {}",
+            file_info.path(),
+            err.to_string()
+        ),
+    )
 }
 
-impl From<&DirEntry> for BlockFileInfo {
-    fn from(value: &DirEntry) -> Self {
-        let fpath = Utf8PathBuf::from_path_buf(value.path()).unwrap();
-        let fname = value.file_name().into_string().unwrap();
-        let fparts: Vec<&str> = fname.split("-").collect();
+// Returns paths to block files within a directory, descending into nested directories, whilst
+// recording modification time of each directory.
+fn get_paths_to_block_files(
+    path_to_dir: &Utf8Path,
+    mtimes: &mut Vec<(Utf8PathBuf, SystemTime)>,
+) -> Result<Vec<Utf8PathBuf>, Error> {
+    // N.B. Modification time is read prior to listing so that a concurrent write is not missed.
+    mtimes.push((
+        path_to_dir.to_owned(),
+        fs::metadata(path_to_dir)?.modified()?,
+    ));
 
-        BlockFileInfo::new(
-            BlockHash::from(&fparts[2][..64]),
-            BlockHeight::from(fparts[1]),
-            fpath,
-        )
+    let mut result = Vec::new();
+    for entry in path_to_dir.read_dir_utf8()? {
+        let entry = entry?;
+        if entry.file_name().starts_with('.') {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            result.extend(get_paths_to_block_files(entry.path(), mtimes)?);
+        } else if entry.file_name().starts_with(FNAME_PREFIX) {
+            result.push(entry.path().to_owned());
+        }
     }
+
+    Ok(result)
 }
 
 // ------------------------------------------------------------------------
@@ -127,8 +383,21 @@ impl From<&DirEntry> for BlockFileInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::Fetcher;
-    use std::env;
+    use super::{Fetcher, FetcherBackend};
+    use camino::{Utf8Path, Utf8PathBuf};
+    use flate2::{write::GzEncoder, Compression};
+    use lsimulator::{get_fname_of_block, Simulator, SimulatorConfig};
+    use ltypeset::chain::{BlockHash, BlockHeight, BlockID, BlockWithProofs};
+    use ltypeset_utils::fixtures::get_path_to_chain_fixtures;
+    use rand::Rng;
+    use std::{
+        env, fs,
+        io::{ErrorKind, Write},
+        time::{Duration, SystemTime},
+    };
+
+    const BLOCK_00_HASH: &str = "b3f473058b6065c1d08a400dbc76054e491513a8b102c1709561730294c5a88e";
+    const BLOCK_01_HASH: &str = "4b292bedb5ec07c86e730c6117d6ae3560cc24cce093db6801628a39f477d1d2";
 
     fn get_path_to_root() -> String {
         format!(
@@ -137,8 +406,352 @@ mod tests {
         )
     }
 
+    fn get_path_to_temp_dir() -> Utf8PathBuf {
+        Utf8PathBuf::from_path_buf(env::temp_dir())
+            .unwrap()
+            .join(format!("lkernel-fsys-{}", rand::thread_rng().gen::<u64>()))
+    }
+
+    fn get_simulated_chain(path_to_root: &Utf8Path, count: usize) -> Vec<BlockWithProofs> {
+        Simulator::new(SimulatorConfig::default())
+            .unwrap()
+            .write_chain(path_to_root, count)
+            .unwrap()
+    }
+
     #[test]
     fn test_that_instance_can_be_instantiated() {
         Fetcher::new(get_path_to_root());
     }
+
+    #[test]
+    fn test_that_chain_can_be_walked_to_target_height() {
+        let fetcher = Fetcher::new(Utf8Path::new(&get_path_to_root()));
+        let chain = fetcher
+            .get_chain_of_blocks_with_proofs(BlockHash::from(BLOCK_00_HASH), BlockID::from(1))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].block().hash(), &BlockHash::from(BLOCK_00_HASH));
+        assert_eq!(chain[1].block().hash(), &BlockHash::from(BLOCK_01_HASH));
+    }
+
+    #[test]
+    fn test_that_chain_can_be_walked_to_target_hash() {
+        let fetcher = Fetcher::new(Utf8Path::new(&get_path_to_root()));
+        let chain = fetcher
+            .get_chain_of_blocks_with_proofs(
+                BlockHash::from(BLOCK_00_HASH),
+                BlockID::from(BlockHash::from(BLOCK_01_HASH)),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(chain.len(), 2);
+    }
+
+    #[test]
+    fn test_that_chain_of_one_block_is_returned_if_trusted_block_is_target() {
+        let fetcher = Fetcher::new(Utf8Path::new(&get_path_to_root()));
+        let chain = fetcher
+            .get_chain_of_blocks_with_proofs(
+                BlockHash::from(BLOCK_01_HASH),
+                BlockID::from(BlockHash::from(BLOCK_01_HASH)),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(chain.len(), 1);
+    }
+
+    #[test]
+    fn test_that_chain_is_none_if_a_block_is_unavailable() {
+        let fetcher = Fetcher::new(Utf8Path::new(&get_path_to_root()));
+
+        assert!(fetcher
+            .get_chain_of_blocks_with_proofs(BlockHash::from(BLOCK_00_HASH), BlockID::from(2))
+            .unwrap()
+            .is_none());
+        assert!(fetcher
+            .get_chain_of_blocks_with_proofs(BlockHash::from(BLOCK_01_HASH), BlockID::from(0))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_that_next_switch_block_can_be_fetched() {
+        let fetcher = Fetcher::new(get_path_to_chain_fixtures());
+        for (height, switch_height) in [(0, 11), (11, 22), (12, 22), (21, 22)] {
+            let block_with_proofs = fetcher
+                .get_next_switch_block_with_proofs(BlockHeight::new(height))
+                .unwrap()
+                .unwrap();
+            assert_eq!(block_with_proofs.block().height().inner(), switch_height);
+        }
+    }
+
+    #[test]
+    fn test_that_next_switch_block_is_none_if_a_block_is_unavailable() {
+        let fetcher = Fetcher::new(Utf8Path::new(&get_path_to_root()));
+
+        assert!(fetcher
+            .get_next_switch_block_with_proofs(BlockHeight::new(0))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_that_simulated_chain_can_be_walked() {
+        let path_to_root = get_path_to_temp_dir();
+        let chain = get_simulated_chain(&path_to_root, 120);
+        let fetcher = Fetcher::new(&path_to_root);
+
+        assert_eq!(
+            fetcher
+                .get_chain_of_blocks_with_proofs(
+                    *chain[0].block().hash(),
+                    BlockID::from(*chain[119].block().hash())
+                )
+                .unwrap()
+                .unwrap(),
+            chain
+        );
+        assert_eq!(
+            fetcher
+                .get_next_switch_block_with_proofs(BlockHeight::new(95))
+                .unwrap()
+                .unwrap(),
+            chain[100]
+        );
+    }
+
+    #[test]
+    fn test_that_stray_files_are_ignored() {
+        let path_to_root = get_path_to_temp_dir();
+        let chain = get_simulated_chain(&path_to_root, 3);
+        fs::write(path_to_root.join("README.md"), "stray").unwrap();
+        fs::write(path_to_root.join(".DS_Store"), "stray").unwrap();
+        let fetcher = Fetcher::new(&path_to_root);
+
+        assert!(fetcher.init().is_ok());
+        assert_eq!(
+            fetcher
+                .get_block_with_proofs(BlockID::from(2))
+                .unwrap()
+                .unwrap(),
+            chain[2]
+        );
+    }
+
+    #[test]
+    fn test_that_invalid_file_name_is_skipped() {
+        for fname in [
+            "block-02.json",
+            "block-xx-4b292bedb5ec07c86e730c6117d6ae3560cc24cce093db6801628a39f477d1d2.json",
+            "block-02-4b292bedb5ec07c86e730c6117d6ae3560cc24cce093db6801628a39f477d1.json",
+            "block-02-4b292bedb5ec07c86e730c6117d6ae3560cc24cce093db6801628a39f477d1d2.txt",
+        ] {
+            let path_to_root = get_path_to_temp_dir();
+            get_simulated_chain(&path_to_root, 2);
+            fs::write(path_to_root.join(fname), "{}").unwrap();
+            let fetcher = Fetcher::new(&path_to_root);
+
+            assert!(fetcher.init().is_ok());
+            assert_eq!(
+                fetcher.set_of_skipped_files(),
+                vec![path_to_root.join(fname)]
+            );
+            assert!(fetcher
+                .get_block_with_proofs(BlockID::from(1))
+                .unwrap()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn test_that_duplicate_height_is_an_error() {
+        let path_to_root = get_path_to_temp_dir();
+        let chain = get_simulated_chain(&path_to_root, 2);
+        fs::write(
+            path_to_root.join(get_fname_of_block(&chain[1]).replace(".json", ".cbor")),
+            serde_cbor::to_vec(&chain[1]).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Fetcher::new(&path_to_root).init().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_that_invalid_file_content_is_an_error() {
+        let path_to_root = get_path_to_temp_dir();
+        let chain = get_simulated_chain(&path_to_root, 3);
+        fs::write(path_to_root.join(get_fname_of_block(&chain[1])), "{}").unwrap();
+        fs::write(
+            path_to_root.join(get_fname_of_block(&chain[2])),
+            serde_json::to_string(&chain[0]).unwrap(),
+        )
+        .unwrap();
+        let fetcher = Fetcher::new(&path_to_root);
+
+        assert!(fetcher.init().is_ok());
+        for height in [1, 2] {
+            assert_eq!(
+                fetcher
+                    .get_block_with_proofs(BlockID::from(height))
+                    .unwrap_err()
+                    .kind(),
+                ErrorKind::InvalidData
+            );
+        }
+        assert_eq!(
+            fetcher.set_of_skipped_files(),
+            vec![
+                path_to_root.join(get_fname_of_block(&chain[1])),
+                path_to_root.join(get_fname_of_block(&chain[2]))
+            ]
+        );
+        assert!(fetcher
+            .get_block_with_proofs(BlockID::from(0))
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_that_missing_directory_is_an_error() {
+        let fetcher = Fetcher::new(get_path_to_temp_dir());
+
+        assert_eq!(fetcher.init().unwrap_err().kind(), ErrorKind::NotFound);
+        assert_eq!(
+            fetcher
+                .get_block_with_proofs(BlockID::from(0))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_that_nested_era_directories_are_scanned() {
+        let path_to_root = get_path_to_temp_dir();
+        let chain = get_simulated_chain(&get_path_to_temp_dir(), 25);
+        for block_with_proofs in &chain {
+            let path_to_era = path_to_root.join(format!(
+                "era-{:04}",
+                block_with_proofs.block().era_id().inner()
+            ));
+            fs::create_dir_all(&path_to_era).unwrap();
+            fs::write(
+                path_to_era.join(get_fname_of_block(block_with_proofs)),
+                serde_json::to_string(block_with_proofs).unwrap(),
+            )
+            .unwrap();
+        }
+        let fetcher = Fetcher::new(&path_to_root);
+
+        assert!(fetcher.init().is_ok());
+        assert_eq!(
+            fetcher
+                .get_chain_of_blocks_with_proofs(*chain[0].block().hash(), BlockID::from(24))
+                .unwrap()
+                .unwrap(),
+            chain
+        );
+    }
+
+    #[test]
+    fn test_that_compressed_and_cbor_block_files_are_fetched() {
+        let path_to_root = get_path_to_temp_dir();
+        let chain = get_simulated_chain(&get_path_to_temp_dir(), 4);
+        fs::create_dir_all(&path_to_root).unwrap();
+        for (block_with_proofs, extension) in
+            chain.iter().zip(["json", "json.gz", "cbor", "cbor.gz"])
+        {
+            let encoded = match extension.starts_with("json") {
+                true => serde_json::to_vec(block_with_proofs).unwrap(),
+                false => serde_cbor::to_vec(block_with_proofs).unwrap(),
+            };
+            let encoded = match extension.ends_with(".gz") {
+                true => {
+                    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                    encoder.write_all(&encoded).unwrap();
+                    encoder.finish().unwrap()
+                }
+                false => encoded,
+            };
+            let fname = get_fname_of_block(block_with_proofs).replace("json", extension);
+            fs::write(path_to_root.join(fname), encoded).unwrap();
+        }
+        let fetcher = Fetcher::new(&path_to_root);
+
+        for (height, block_with_proofs) in chain.iter().enumerate() {
+            assert_eq!(
+                &fetcher
+                    .get_block_with_proofs(BlockID::from(height as u64))
+                    .unwrap()
+                    .unwrap(),
+                block_with_proofs
+            );
+        }
+    }
+
+    #[test]
+    fn test_that_block_files_written_after_init_are_fetched() {
+        let path_to_root = get_path_to_temp_dir();
+        let mut simulator = Simulator::new(SimulatorConfig::default()).unwrap();
+        simulator.write_chain(&path_to_root, 2).unwrap();
+        let fetcher = Fetcher::new(&path_to_root);
+        fetcher.init().unwrap();
+
+        assert!(fetcher
+            .get_block_with_proofs(BlockID::from(2))
+            .unwrap()
+            .is_none());
+        let chain = simulator.write_chain(&path_to_root, 1).unwrap();
+        assert_eq!(
+            fetcher
+                .get_block_with_proofs(BlockID::from(2))
+                .unwrap()
+                .unwrap(),
+            chain[0]
+        );
+    }
+
+    #[test]
+    fn test_that_unmodified_directory_is_not_re_indexed() {
+        let set_modified = |path_to_dir: &Utf8Path, mtime: SystemTime| {
+            fs::File::open(path_to_dir)
+                .unwrap()
+                .set_modified(mtime)
+                .unwrap()
+        };
+        let mtime = SystemTime::now() - Duration::from_secs(3_600);
+
+        let path_to_root = get_path_to_temp_dir();
+        let mut simulator = Simulator::new(SimulatorConfig::default()).unwrap();
+        simulator.write_chain(&path_to_root, 2).unwrap();
+        set_modified(&path_to_root, mtime);
+        let fetcher = Fetcher::new(&path_to_root);
+        fetcher.init().unwrap();
+
+        // Block file written since, but directory appears unmodified.
+        let chain = simulator.write_chain(&path_to_root, 1).unwrap();
+        set_modified(&path_to_root, mtime);
+        assert!(fetcher
+            .get_block_with_proofs(BlockID::from(2))
+            .unwrap()
+            .is_none());
+
+        set_modified(&path_to_root, SystemTime::now());
+        assert_eq!(
+            fetcher
+                .get_block_with_proofs(BlockID::from(2))
+                .unwrap()
+                .unwrap(),
+            chain[0]
+        );
+    }
 }
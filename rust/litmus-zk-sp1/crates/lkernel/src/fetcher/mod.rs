@@ -1,7 +1,7 @@
 use super::kernel::config::{Config, FetcherConfig};
 pub use chain::Fetcher as ChainFetcher;
 pub use fsys::Fetcher as FileSystemFetcher;
-use ltypeset::chain::{BlockID, BlockWithProofs};
+use ltypeset::chain::{BlockHash, BlockHeight, BlockID, BlockWithProofs};
 use std::io::Error;
 
 pub mod chain;
@@ -45,18 +45,93 @@ impl Fetcher {
 pub trait FetcherBackend {
     /// Retrieves a block by an identifier.
     ///
+    /// N.B. Returns `Ok(None)` if block is unknown, & an error if it could not be retrieved.
+    ///
     /// # Arguments
     ///
     /// * `block_id` - Identifier of a block for which to issue a query.
     ///
-    fn get_block_with_proofs(&self, block_id: BlockID) -> Option<BlockWithProofs>;
+    fn get_block_with_proofs(&self, block_id: BlockID) -> Result<Option<BlockWithProofs>, Error>;
+
+    /// Retrieves a contiguous chain of blocks by walking forwards from a trusted block.
+    ///
+    /// N.B. Returns `Ok(None)` if any block between trusted block & target block is unavailable.  Target
+    /// block is resolved prior to walking, i.e. walk is bounded by target block height.
+    ///
+    /// # Arguments
+    ///
+    /// * `trusted_block_hash` - Hash of block from which to start walking, i.e. first block in chain.
+    /// * `target_block_id` - Identifier of block at which to stop walking, i.e. last block in chain.
+    ///
+    fn get_chain_of_blocks_with_proofs(
+        &self,
+        trusted_block_hash: BlockHash,
+        target_block_id: BlockID,
+    ) -> Result<Option<Vec<BlockWithProofs>>, Error> {
+        let trusted_block_with_proofs =
+            match self.get_block_with_proofs(BlockID::from(trusted_block_hash))? {
+                Some(inner) => inner,
+                None => return Ok(None),
+            };
+        let target_block_with_proofs = match self.get_block_with_proofs(target_block_id)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        let trusted_height = trusted_block_with_proofs.block().height().inner();
+        let target_height = target_block_with_proofs.block().height().inner();
+        if target_height < trusted_height {
+            return Ok(None);
+        }
+        if target_height == trusted_height {
+            return Ok(
+                (target_block_with_proofs.block().hash() == &trusted_block_hash)
+                    .then(|| vec![trusted_block_with_proofs]),
+            );
+        }
+
+        let mut result = vec![trusted_block_with_proofs];
+        for height in trusted_height + 1..target_height {
+            match self.get_block_with_proofs(BlockID::from(height))? {
+                Some(inner) => result.push(inner),
+                None => return Ok(None),
+            }
+        }
+        result.push(target_block_with_proofs);
+
+        Ok(Some(result))
+    }
+
+    /// Retrieves first switch block above a block height by walking forwards.
+    ///
+    /// N.B. Returns `Ok(None)` if a block is unavailable prior to encountering a switch block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_height` - Height of block from which to start walking, e.g. height of previous switch block.
+    ///
+    fn get_next_switch_block_with_proofs(
+        &self,
+        block_height: BlockHeight,
+    ) -> Result<Option<BlockWithProofs>, Error> {
+        let mut height = block_height.inner() + 1;
+        loop {
+            let block_with_proofs = match self.get_block_with_proofs(BlockID::from(height))? {
+                Some(inner) => inner,
+                None => return Ok(None),
+            };
+            if block_with_proofs.block().is_switch() {
+                return Ok(Some(block_with_proofs));
+            }
+            height += 1;
+        }
+    }
 
     /// Fetcher initializer.
     fn init(&self) -> Result<(), Error>;
 }
 
 impl FetcherBackend for Fetcher {
-    fn get_block_with_proofs(&self, block_id: BlockID) -> Option<BlockWithProofs> {
+    fn get_block_with_proofs(&self, block_id: BlockID) -> Result<Option<BlockWithProofs>, Error> {
         match self {
             Self::Chain(inner) => inner.get_block_with_proofs(block_id),
             Self::FileSystem(inner) => inner.get_block_with_proofs(block_id),
@@ -0,0 +1,454 @@
+//! Follows a chain's tip, i.e. verifies each newly finalized block against validator set of its era.
+//!
+//! Verified blocks are emitted to a sink as newline delimited JSON, one record per block:
+//!
+//! ```text
+//! {"block_hash":"<hex>","header":{...},"signatures":<count>}
+//! ```
+
+use super::fetcher::FetcherBackend;
+use camino::Utf8Path;
+use ltypeset::{
+    chain::{
+        Block, BlockHash, BlockID, BlockV2Header, BlockWithProofs, ChainNameDigest,
+        EraConsensusInfo,
+    },
+    verification::VerificationError,
+};
+use lverifiers::FinalityThreshold;
+use serde::Serialize;
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, Error, Stdout, Write},
+};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Number of consecutive polls over which a block may remain not yet final, i.e. beyond which
+// its insufficient finality signature weight is deemed a verification failure.
+const MAX_POLLS_PENDING_FINALITY: u32 = 30;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Light client state, i.e. last verified block plus validator set in force for its children.
+pub struct Follower {
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+    polls_pending_finality: u32,
+    tip: BlockWithProofs,
+}
+
+/// Destination of verified block records.
+pub enum FollowerSink {
+    File(File),
+    Stdout(Stdout),
+}
+
+/// Error raised whilst following a chain's tip.
+#[derive(Debug)]
+pub enum FollowerError {
+    /// Trusted block is unavailable from fetcher.
+    TrustedBlockUnavailable,
+
+    /// A block could not be fetched.
+    Fetch(Error),
+
+    /// A block not yet deemed final could not be evicted from cache.
+    Cache(Error),
+
+    /// A block failed verification.
+    Verification(VerificationError),
+
+    /// A record could not be written to sink.
+    Sink(Error),
+}
+
+#[derive(Serialize)]
+struct FollowerRecord<'a> {
+    block_hash: &'a BlockHash,
+    header: &'a BlockV2Header,
+    signatures: usize,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl Follower {
+    /// Constructor: returns a follower whose tip is a verified trusted block.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_name_digest` - Digest over name of a blockchain.
+    /// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+    /// * `trusted_block_with_proofs` - Block from which to start following.
+    /// * `era_consensus_info` - Validator set in force for blocks subsequent to trusted block.
+    ///
+    pub fn new(
+        chain_name_digest: ChainNameDigest,
+        finality_threshold: FinalityThreshold,
+        trusted_block_with_proofs: BlockWithProofs,
+        era_consensus_info: EraConsensusInfo,
+    ) -> Result<Self, VerificationError> {
+        let (tip, era_consensus_info) = lverifiers::verify_chain_v2_with_proofs(
+            trusted_block_with_proofs,
+            era_consensus_info,
+            vec![],
+            chain_name_digest,
+            finality_threshold,
+        )?;
+
+        Ok(Self {
+            chain_name_digest,
+            era_consensus_info,
+            finality_threshold,
+            polls_pending_finality: 0,
+            tip,
+        })
+    }
+}
+
+impl FollowerSink {
+    /// Constructor: returns a sink appending records to a file, which is created if necessary.
+    ///
+    /// # Arguments
+    ///
+    /// * `path_to_file` - Path to a file to which records are appended.
+    ///
+    pub fn new_file(path_to_file: &Utf8Path) -> Result<Self, Error> {
+        if let Some(path_to_parent) = path_to_file.parent() {
+            fs::create_dir_all(path_to_parent)?;
+        }
+
+        Ok(Self::File(
+            OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(path_to_file)?,
+        ))
+    }
+
+    /// Constructor: returns a sink writing records to standard output.
+    pub fn new_stdout() -> Self {
+        Self::Stdout(io::stdout())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl Follower {
+    pub fn era_consensus_info(&self) -> &EraConsensusInfo {
+        &self.era_consensus_info
+    }
+
+    pub fn tip(&self) -> &BlockWithProofs {
+        &self.tip
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl Follower {
+    /// Verifies a child of current tip &, if valid, moves tip to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_with_proofs` - Child of current tip plus finality signatures.
+    ///
+    pub fn advance(&mut self, block_with_proofs: BlockWithProofs) -> Result<(), VerificationError> {
+        self.era_consensus_info = lverifiers::verify_child_block_v2_with_proofs(
+            &self.tip,
+            block_with_proofs.clone(),
+            self.chain_name_digest,
+            self.era_consensus_info.clone(),
+            self.finality_threshold,
+        )?;
+        self.tip = block_with_proofs;
+
+        Ok(())
+    }
+
+    /// Fetches & verifies block at height above current tip, returning it if available & final.
+    ///
+    /// N.B. A block whose finality signature weight does not yet exceed finality threshold is
+    /// deemed not yet final, i.e. `Ok(None)` is returned so that it may be re-polled once further
+    /// finality signatures have been gathered.  Once a block has remained not yet final over
+    /// `MAX_POLLS_PENDING_FINALITY` consecutive polls its verification failure is returned as an
+    /// error, as are fetcher failures.
+    ///
+    /// # Arguments
+    ///
+    /// * `fetcher` - Fetcher from which to pull next block.
+    ///
+    pub fn poll(
+        &mut self,
+        fetcher: &impl FetcherBackend,
+    ) -> Result<Option<&BlockWithProofs>, FollowerError> {
+        let height = self.tip.block().height().inner() + 1;
+        match fetcher
+            .get_block_with_proofs(BlockID::from(height))
+            .map_err(FollowerError::Fetch)?
+        {
+            Some(block_with_proofs) => match self.advance(block_with_proofs) {
+                Ok(()) => {
+                    self.polls_pending_finality = 0;
+                    Ok(Some(&self.tip))
+                }
+                Err(VerificationError::InsufficientSignatureWeight)
+                    if self.polls_pending_finality + 1 < MAX_POLLS_PENDING_FINALITY =>
+                {
+                    self.polls_pending_finality += 1;
+                    Ok(None)
+                }
+                Err(err) => Err(FollowerError::Verification(err)),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+impl FollowerSink {
+    /// Writes a verified block as a single line JSON record.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_with_proofs` - A verified block.
+    ///
+    pub fn emit(&mut self, block_with_proofs: &BlockWithProofs) -> Result<(), Error> {
+        let header = match block_with_proofs.block() {
+            Block::V2(inner) => inner.header(),
+            Block::V1(_) => unreachable!("followed blocks are version two"),
+        };
+        let mut line = serde_json::to_vec(&FollowerRecord {
+            block_hash: block_with_proofs.block().hash(),
+            header,
+            signatures: block_with_proofs.proofs().len(),
+        })?;
+        line.push(b'\n');
+
+        match self {
+            Self::File(inner) => {
+                inner.write_all(&line)?;
+                inner.flush()
+            }
+            Self::Stdout(inner) => {
+                let mut inner = inner.lock();
+                inner.write_all(&line)?;
+                inner.flush()
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Display for FollowerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TrustedBlockUnavailable => write!(f, "trusted block is unavailable"),
+            Self::Fetch(inner) => write!(f, "fetch failure: {inner}"),
+            Self::Cache(inner) => write!(f, "cache failure: {inner}"),
+            Self::Verification(inner) => write!(f, "verification failure: {inner}"),
+            Self::Sink(inner) => write!(f, "sink failure: {inner}"),
+        }
+    }
+}
+
+impl std::error::Error for FollowerError {}
+
+impl From<Error> for FollowerError {
+    fn from(value: Error) -> Self {
+        Self::Sink(value)
+    }
+}
+
+impl From<VerificationError> for FollowerError {
+    fn from(value: VerificationError) -> Self {
+        Self::Verification(value)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetcher::FileSystemFetcher;
+    use camino::Utf8PathBuf;
+    use ltypeset::chain::EraId;
+    use ltypeset_utils::fixtures::get_path_to_chain_fixtures;
+    use rand::Rng;
+    use std::env;
+
+    fn get_fetcher() -> FileSystemFetcher {
+        FileSystemFetcher::new(get_path_to_chain_fixtures())
+    }
+
+    fn get_follower(height: u64) -> Follower {
+        let fetcher = get_fetcher();
+        let trusted_block_with_proofs = fetcher
+            .get_block_with_proofs(BlockID::from(height))
+            .unwrap()
+            .unwrap();
+        let switch_block_with_proofs = match trusted_block_with_proofs.block() {
+            Block::V2(inner) if inner.header().era_end().is_none() => fetcher
+                .get_block_with_proofs(BlockID::from(
+                    inner.header().last_switch_block_hash().unwrap(),
+                ))
+                .unwrap()
+                .unwrap(),
+            _ => trusted_block_with_proofs.clone(),
+        };
+        let era_consensus_info = lverifiers::get_trusted_era_consensus_info(
+            &trusted_block_with_proofs,
+            &switch_block_with_proofs,
+        )
+        .unwrap();
+
+        Follower::new(
+            ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            FinalityThreshold::default(),
+            trusted_block_with_proofs,
+            era_consensus_info,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_that_tip_is_followed_across_era_transitions() {
+        let fetcher = get_fetcher();
+        let mut follower = get_follower(0);
+        while follower.poll(&fetcher).unwrap().is_some() {}
+
+        assert_eq!(follower.tip().block().height().inner(), 50);
+        assert_eq!(follower.era_consensus_info().era_id(), &EraId::new(5));
+    }
+
+    #[test]
+    fn test_that_poll_is_none_when_next_block_is_unavailable() {
+        let fetcher = get_fetcher();
+        let mut follower = get_follower(50);
+
+        assert!(matches!(follower.poll(&fetcher), Ok(None)));
+        assert_eq!(follower.tip().block().height().inner(), 50);
+    }
+
+    #[test]
+    fn test_that_poll_is_none_when_next_block_is_not_yet_final() {
+        let fetcher = get_fetcher();
+        let mut follower = Follower::new(
+            ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            FinalityThreshold::new(1, 1).unwrap(),
+            fetcher
+                .get_block_with_proofs(BlockID::from(23))
+                .unwrap()
+                .unwrap(),
+            get_follower(23).era_consensus_info().to_owned(),
+        )
+        .unwrap();
+
+        assert!(matches!(follower.poll(&fetcher), Ok(None)));
+        assert_eq!(follower.tip().block().height().inner(), 23);
+    }
+
+    #[test]
+    fn test_error_if_next_block_remains_not_yet_final() {
+        let fetcher = get_fetcher();
+        let mut follower = Follower::new(
+            ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            FinalityThreshold::new(1, 1).unwrap(),
+            fetcher
+                .get_block_with_proofs(BlockID::from(23))
+                .unwrap()
+                .unwrap(),
+            get_follower(23).era_consensus_info().to_owned(),
+        )
+        .unwrap();
+        for _ in 1..MAX_POLLS_PENDING_FINALITY {
+            assert!(matches!(follower.poll(&fetcher), Ok(None)));
+        }
+
+        assert!(matches!(
+            follower.poll(&fetcher),
+            Err(FollowerError::Verification(
+                VerificationError::InsufficientSignatureWeight
+            ))
+        ));
+        assert_eq!(follower.tip().block().height().inner(), 23);
+    }
+
+    #[test]
+    fn test_error_if_next_block_cannot_be_fetched() {
+        let fetcher =
+            FileSystemFetcher::new(Utf8PathBuf::from_path_buf(env::temp_dir()).unwrap().join(
+                format!("lkernel-follower-{}", rand::thread_rng().gen::<u64>()),
+            ));
+        let mut follower = get_follower(50);
+
+        assert!(matches!(
+            follower.poll(&fetcher),
+            Err(FollowerError::Fetch(_))
+        ));
+        assert_eq!(follower.tip().block().height().inner(), 50);
+    }
+
+    #[test]
+    fn test_that_tip_is_not_advanced_upon_verification_failure() {
+        let fetcher = get_fetcher();
+        let mut follower = get_follower(23);
+
+        assert_eq!(
+            follower.advance(
+                fetcher
+                    .get_block_with_proofs(BlockID::from(25))
+                    .unwrap()
+                    .unwrap()
+            ),
+            Err(VerificationError::ParentHashMismatch)
+        );
+        assert_eq!(follower.tip().block().height().inner(), 23);
+    }
+
+    #[test]
+    fn test_that_records_are_appended_to_file_sink() {
+        let fetcher = get_fetcher();
+        let path_to_file = Utf8PathBuf::from_path_buf(env::temp_dir())
+            .unwrap()
+            .join(format!(
+                "lkernel-follower-{}",
+                rand::thread_rng().gen::<u64>()
+            ))
+            .join("tip.ndjson");
+        let mut sink = FollowerSink::new_file(&path_to_file).unwrap();
+        let mut follower = get_follower(10);
+        for _ in 0..2 {
+            sink.emit(follower.poll(&fetcher).unwrap().unwrap())
+                .unwrap();
+        }
+
+        let records: Vec<serde_json::Value> = fs::read_to_string(&path_to_file)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[1]["block_hash"],
+            serde_json::to_value(follower.tip().block().hash()).unwrap()
+        );
+    }
+}
@@ -9,11 +9,18 @@ use std::fs;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Config {
+    pub cache: CacheConfig,
     pub fetcher: FetcherConfig,
     pub name_of_chain: String,
+    pub prover: ProverConfig,
     pub trusted_block_hash: BlockHash,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CacheConfig {
+    pub path_to_root: String,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "kind", content = "args")]
 pub enum FetcherConfig {
@@ -21,13 +28,29 @@ pub enum FetcherConfig {
     FileSystem { path_to_root: String },
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProverConfig {
+    pub mode: ProverMode,
+    pub path_to_elf: String,
+    pub path_to_output: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ProverMode {
+    /// Programs are executed but proofs are not generated - intended for tests.
+    Mock,
+
+    /// Proofs are generated by local hardware.
+    Local,
+}
+
 // ------------------------------------------------------------------------
 // Constructors.
 // ------------------------------------------------------------------------
 
 impl Config {
-    pub fn new(path_to_toml: &Utf8Path) -> Self {
-        let path_to_toml = fs::read_to_string(path_to_toml).unwrap();
+    pub fn new(path_to_toml: impl AsRef<Utf8Path>) -> Self {
+        let path_to_toml = fs::read_to_string(path_to_toml.as_ref()).unwrap();
 
         toml::from_str(&path_to_toml).unwrap()
     }
@@ -38,6 +61,10 @@ impl Config {
 // ------------------------------------------------------------------------
 
 impl Config {
+    pub fn cache(&self) -> &CacheConfig {
+        &self.cache
+    }
+
     pub fn fetcher(&self) -> &FetcherConfig {
         &self.fetcher
     }
@@ -46,11 +73,35 @@ impl Config {
         &self.name_of_chain
     }
 
+    pub fn prover(&self) -> &ProverConfig {
+        &self.prover
+    }
+
     pub fn trusted_block_hash(&self) -> &BlockHash {
         &self.trusted_block_hash
     }
 }
 
+impl CacheConfig {
+    pub fn path_to_root(&self) -> &str {
+        &self.path_to_root
+    }
+}
+
+impl ProverConfig {
+    pub fn mode(&self) -> ProverMode {
+        self.mode
+    }
+
+    pub fn path_to_elf(&self) -> &str {
+        &self.path_to_elf
+    }
+
+    pub fn path_to_output(&self) -> &str {
+        &self.path_to_output
+    }
+}
+
 // ------------------------------------------------------------------------
 // Methods.
 // ------------------------------------------------------------------------
@@ -67,7 +118,7 @@ impl Config {
 
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use super::{Config, ProverMode};
     use std::{env, path::Path};
 
     fn get_path_to_toml_file() -> String {
@@ -88,4 +139,12 @@ mod tests {
         let path_to_toml_file = get_path_to_toml_file();
         Config::new(path_to_toml_file);
     }
+
+    #[test]
+    fn test_that_prover_config_is_parsed_from_toml_file() {
+        let path_to_toml_file = get_path_to_toml_file();
+        let config = Config::new(path_to_toml_file);
+        assert_eq!(config.prover().mode(), ProverMode::Mock);
+        assert_eq!(config.prover().path_to_output(), "fixtures/proofs");
+    }
 }
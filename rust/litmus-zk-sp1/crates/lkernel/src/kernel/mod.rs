@@ -1,10 +1,26 @@
 pub(super) mod config;
 
 use super::fetcher::FetcherBackend;
-pub use super::{cache::Cache, fetcher::Fetcher, prover::Prover};
+pub use super::{
+    cache::{Cache, CachedFetcher},
+    fetcher::Fetcher,
+    follower::{Follower, FollowerError, FollowerSink},
+    prover::Prover,
+};
 use camino::Utf8Path;
-use ltypeset::chain::{BlockHash, BlockID, BlockWithProofs, ChainNameDigest};
-pub use {config::Config, config::FetcherConfig};
+use ltypeset::{
+    chain::{
+        Block, BlockHash, BlockHeight, BlockID, BlockWithProofs, ChainNameDigest, EraConsensusInfo,
+        EraId,
+    },
+    verification::VerificationError,
+};
+use lverifiers::{BlockReport, FinalityThreshold};
+use std::{fmt, io::Error, thread, time::Duration};
+pub use {
+    config::CacheConfig, config::Config, config::FetcherConfig, config::ProverConfig,
+    config::ProverMode,
+};
 
 // ------------------------------------------------------------------------
 // Declarations.
@@ -17,13 +33,23 @@ pub struct Kernel {
     prover: Prover,
 }
 
+/// Error raised whilst fetching & verifying blocks.
+#[derive(Debug)]
+pub enum KernelError {
+    /// A block could not be fetched.
+    Fetch(Error),
+
+    /// A block failed verification.
+    Verification(VerificationError),
+}
+
 // ------------------------------------------------------------------------
 // Constructors.
 // ------------------------------------------------------------------------
 
 impl Kernel {
     pub fn new(path_to_config_toml: &Utf8Path) -> Self {
-        let config = Config::new(&path_to_config_toml);
+        let config = Config::new(path_to_config_toml);
         let cache = Cache::new(config.clone());
         let fetcher = Fetcher::new(config.clone());
         let prover = Prover::new(config.clone());
@@ -65,24 +91,348 @@ impl Kernel {
 
 impl Kernel {
     /// Initialises kernel components.
-    pub fn init(&self) {
-        self.fetcher.init().unwrap();
+    pub fn init(&self) -> Result<(), Error> {
+        self.fetcher.init()
     }
 
     /// Returns block with associated proofs.
-    pub fn get_block_with_proofs(&self, block_hash: Option<BlockHash>) -> Option<BlockWithProofs> {
+    pub fn get_block_with_proofs(
+        &self,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Option<BlockWithProofs>, Error> {
         // If requested block hash is unspecified then set from config.
         let block_hash = match block_hash {
             Option::Some(inner) => inner,
             Option::None => self.config.trusted_block_hash,
         };
 
-        self.fetcher
+        self.get_cached_fetcher()
             .get_block_with_proofs(BlockID::from(block_hash))
     }
 
+    /// Returns validator set in force for a block, i.e. as declared by switch block of previous era.
+    ///
+    /// N.B. Switch block is authenticated by hash chaining from block only, hence block must itself
+    /// be verified against returned validator set. Returns `Ok(None)` if switch block is unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_with_proofs` - Block for which to return era validator set.
+    ///
+    pub fn get_era_consensus_info(
+        &self,
+        block_with_proofs: &BlockWithProofs,
+    ) -> Result<Option<EraConsensusInfo>, KernelError> {
+        let fetcher = self.get_cached_fetcher();
+        let get_block = |block_hash: BlockHash| -> Result<Option<Block>, Error> {
+            Ok(fetcher
+                .get_block_with_proofs(BlockID::from(block_hash))?
+                .map(|block_with_proofs| block_with_proofs.block().to_owned())
+                .filter(|block| &ltypeset_utils::crypto::get_hash_for_block(block) == block.hash())
+                .filter(|block| block.hash() == &block_hash))
+        };
+
+        // Set switch block - version two blocks point to it, version one blocks are walked back to it.
+        let switch_block = match block_with_proofs.block() {
+            Block::V1(_) => {
+                let mut block = match get_block(*block_with_proofs.block().parent_hash())? {
+                    Some(inner) => inner,
+                    None => return Ok(None),
+                };
+                while !block.is_switch() {
+                    block = match get_block(*block.parent_hash())? {
+                        Some(inner) => inner,
+                        None => return Ok(None),
+                    };
+                }
+                block
+            }
+            Block::V2(inner) => match inner.header().last_switch_block_hash() {
+                Some(block_hash) => match get_block(*block_hash)? {
+                    Some(inner) => inner,
+                    None => return Ok(None),
+                },
+                None => return Ok(None),
+            },
+        };
+
+        Ok(
+            get_next_era_consensus_info(&switch_block)?.filter(|era_consensus_info| {
+                era_consensus_info.era_id() == &block_with_proofs.block().era_id()
+            }),
+        )
+    }
+
+    /// Returns chain of blocks with associated proofs, walking forwards from trusted block to a target block.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_block_id` - Identifier of last block in chain.
+    ///
+    pub fn get_chain_of_blocks_with_proofs(
+        &self,
+        target_block_id: BlockID,
+    ) -> Result<Option<Vec<BlockWithProofs>>, Error> {
+        self.get_cached_fetcher()
+            .get_chain_of_blocks_with_proofs(self.config.trusted_block_hash, target_block_id)
+    }
+
+    /// Returns first switch block above a block height.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_height` - Height of block from which to start walking, e.g. height of previous switch block.
+    ///
+    pub fn get_next_switch_block_with_proofs(
+        &self,
+        block_height: BlockHeight,
+    ) -> Result<Option<BlockWithProofs>, Error> {
+        self.get_cached_fetcher()
+            .get_next_switch_block_with_proofs(block_height)
+    }
+
+    /// Follows chain tip from trusted block, emitting each verified block to a sink.
+    ///
+    /// N.B. Runs until a block fails verification, cannot be fetched or sink is unwritable.  A block that is not yet
+    /// final is evicted from cache & re-polled after waiting, i.e. so as to pick up finality
+    /// signatures gathered since, though at most `MAX_POLLS_PENDING_FINALITY` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - Destination of verified block records.
+    /// * `poll_interval` - Duration to wait before re-polling when next block is unavailable.
+    ///
+    pub fn follow(
+        &self,
+        sink: &mut FollowerSink,
+        poll_interval: Duration,
+    ) -> Result<(), FollowerError> {
+        let trusted_block_with_proofs = self
+            .get_block_with_proofs(None)
+            .map_err(FollowerError::Fetch)?
+            .ok_or(FollowerError::TrustedBlockUnavailable)?;
+        let era_consensus_info = self
+            .get_trusted_era_consensus_info(&trusted_block_with_proofs)?
+            .ok_or(FollowerError::TrustedBlockUnavailable)?;
+        let mut follower = Follower::new(
+            self.get_chain_name_digest(),
+            FinalityThreshold::default(),
+            trusted_block_with_proofs,
+            era_consensus_info,
+        )?;
+        sink.emit(follower.tip())?;
+
+        let fetcher = self.get_cached_fetcher();
+        loop {
+            match follower.poll(&fetcher)? {
+                Some(block_with_proofs) => sink.emit(block_with_proofs)?,
+                None => {
+                    let block_height =
+                        BlockHeight::new(follower.tip().block().height().inner() + 1);
+                    self.cache
+                        .evict_block_at_height(block_height)
+                        .map_err(FollowerError::Cache)?;
+                    thread::sleep(poll_interval);
+                }
+            }
+        }
+    }
+
+    /// Returns a per rule report of verifying a block natively, i.e. outside of the zk-vm.
+    ///
+    /// N.B. Validator set against which block is verified is derived by verifying chain from trusted block to block's parent.
+    /// Returns `Ok(None)` if any block between trusted block & target block is unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_block_id` - Identifier of block to be reported upon.
+    ///
+    pub fn get_block_report(
+        &self,
+        target_block_id: BlockID,
+    ) -> Result<Option<(BlockWithProofs, BlockReport)>, KernelError> {
+        let mut chain = match self.get_chain_of_blocks_with_proofs(target_block_id)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        let trusted_era_consensus_info = match self.get_trusted_era_consensus_info(&chain[0])? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        let block_with_proofs = chain.pop().unwrap();
+
+        // Set validator set in force for target block, i.e. unknown if target is a trusted switch block.
+        let era_consensus_info = match chain.first() {
+            Some(_) => {
+                let trusted_block_with_proofs = chain.remove(0);
+                let (parent_block_with_proofs, era_consensus_info) =
+                    lverifiers::verify_chain_v2_with_proofs(
+                        trusted_block_with_proofs,
+                        trusted_era_consensus_info,
+                        chain,
+                        self.get_chain_name_digest(),
+                        FinalityThreshold::default(),
+                    )?;
+                if block_with_proofs.block().parent_hash()
+                    != parent_block_with_proofs.block().hash()
+                {
+                    return Err(KernelError::Verification(
+                        VerificationError::ParentHashMismatch,
+                    ));
+                }
+                Some(era_consensus_info)
+            }
+            None => {
+                Some(trusted_era_consensus_info).filter(|_| !block_with_proofs.block().is_switch())
+            }
+        };
+
+        let report = lverifiers::get_block_v2_report(
+            &block_with_proofs,
+            self.get_chain_name_digest(),
+            era_consensus_info.as_ref(),
+            FinalityThreshold::default(),
+        )?;
+
+        Ok(Some((block_with_proofs, report)))
+    }
+
+    /// Returns a block verified by walking forwards from trusted block, i.e. as per a light client.
+    ///
+    /// N.B. Returns `Ok(None)` if any block between trusted block & target block is unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_block_id` - Identifier of block to be verified.
+    ///
+    pub fn get_verified_block_with_proofs(
+        &self,
+        target_block_id: BlockID,
+    ) -> Result<Option<BlockWithProofs>, KernelError> {
+        let mut chain = match self.get_chain_of_blocks_with_proofs(target_block_id)? {
+            Some(inner) => inner,
+            None => return Ok(None),
+        };
+        let trusted_block_with_proofs = chain.remove(0);
+        let era_consensus_info =
+            match self.get_trusted_era_consensus_info(&trusted_block_with_proofs)? {
+                Some(inner) => inner,
+                None => return Ok(None),
+            };
+        let (block_with_proofs, _) = lverifiers::verify_chain_v2_with_proofs(
+            trusted_block_with_proofs,
+            era_consensus_info,
+            chain,
+            self.get_chain_name_digest(),
+            FinalityThreshold::default(),
+        )?;
+
+        Ok(Some(block_with_proofs))
+    }
+
+    /// Returns validator set in force for blocks subsequent to trusted block.
+    ///
+    /// N.B. Validator set is declared by trusted block if it is a switch block, otherwise by switch block
+    /// referenced by trusted block. Returns `Ok(None)` if referenced switch block is unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// * `trusted_block_with_proofs` - Block whose hash is set by config.
+    ///
+    pub fn get_trusted_era_consensus_info(
+        &self,
+        trusted_block_with_proofs: &BlockWithProofs,
+    ) -> Result<Option<EraConsensusInfo>, KernelError> {
+        let switch_block_with_proofs = match trusted_block_with_proofs.block() {
+            Block::V2(inner) if !trusted_block_with_proofs.block().is_switch() => {
+                match inner.header().last_switch_block_hash() {
+                    Some(block_hash) => match self.get_block_with_proofs(Some(*block_hash))? {
+                        Some(inner) => inner,
+                        None => return Ok(None),
+                    },
+                    None => {
+                        return Err(KernelError::Verification(
+                            VerificationError::PreviousNotASwitchBlock,
+                        ))
+                    }
+                }
+            }
+            _ => trusted_block_with_proofs.to_owned(),
+        };
+
+        Ok(Some(lverifiers::get_trusted_era_consensus_info(
+            trusted_block_with_proofs,
+            &switch_block_with_proofs,
+        )?))
+    }
+
+    /// Returns fetcher fronted by cache, i.e. blocks are only fetched upon a cache miss.
+    pub fn get_cached_fetcher(&self) -> CachedFetcher<'_> {
+        CachedFetcher::new(&self.cache, &self.fetcher)
+    }
+
     /// Returns digest over associated chain name.
     pub fn get_chain_name_digest(&self) -> ChainNameDigest {
         self.config.get_chain_name_digest()
     }
 }
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Display for KernelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fetch(inner) => write!(f, "fetch failure: {inner}"),
+            Self::Verification(inner) => write!(f, "verification failure: {inner}"),
+        }
+    }
+}
+
+impl std::error::Error for KernelError {}
+
+impl From<Error> for KernelError {
+    fn from(value: Error) -> Self {
+        Self::Fetch(value)
+    }
+}
+
+impl From<VerificationError> for KernelError {
+    fn from(value: VerificationError) -> Self {
+        Self::Verification(value)
+    }
+}
+
+impl From<KernelError> for FollowerError {
+    fn from(value: KernelError) -> Self {
+        match value {
+            KernelError::Fetch(inner) => Self::Fetch(inner),
+            KernelError::Verification(inner) => Self::Verification(inner),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Returns consensus information for era subsequent to a switch block of either version.
+fn get_next_era_consensus_info(
+    block: &Block,
+) -> Result<Option<EraConsensusInfo>, VerificationError> {
+    match block {
+        Block::V1(inner) => inner
+            .header()
+            .era_end()
+            .as_ref()
+            .map(|era_end| {
+                EraConsensusInfo::new(
+                    EraId::new(inner.header().era_id().inner() + 1),
+                    era_end.next_era_validator_weights().to_owned(),
+                )
+            })
+            .transpose(),
+        Block::V2(inner) => lverifiers::get_next_era_consensus_info(inner),
+    }
+}
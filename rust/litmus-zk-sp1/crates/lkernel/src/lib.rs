@@ -1,6 +1,11 @@
 mod cache;
 mod fetcher;
+mod follower;
 mod kernel;
 mod prover;
 
-pub use {kernel::Config as KernelConfig, kernel::FetcherConfig, kernel::Kernel};
+pub use {
+    kernel::CacheConfig, kernel::Config as KernelConfig, kernel::FetcherConfig, kernel::Follower,
+    kernel::FollowerError, kernel::FollowerSink, kernel::Kernel, kernel::KernelError,
+    kernel::ProverConfig, kernel::ProverMode,
+};
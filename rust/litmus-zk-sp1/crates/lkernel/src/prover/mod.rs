@@ -1,9 +1,372 @@
-use super::kernel::Config;
+//! Wraps the SP1 zk-vm prover, i.e. program execution plus proof generation & verification.
+//!
+//! Proof artifacts are written to the configured output directory as follows:
+//!
+//! ```text
+//! <path_to_output>/
+//!     vkey.bin                    bincode encoded program `SP1VerifyingKey`.
+//!     vkey.hash                   hex encoded program verification key hash, i.e. `vk.bytes32()`.
+//!     <digest>/                   blake2b digest (hex) over committed public values.
+//!         proof.bin               bincode encoded `SP1ProofWithPublicValues`.
+//!         public-values.bin       committed public values, i.e. `lcodecs` binary encoded `PublicValues`.
+//! ```
 
-pub struct Prover {}
+use super::kernel::config::{Config, ProverMode};
+use camino::{Utf8Path, Utf8PathBuf};
+use sp1_sdk::{
+    ExecutionReport, HashableKey, ProverClient, SP1ProofWithPublicValues, SP1ProvingKey,
+    SP1PublicValues, SP1Stdin, SP1VerifyingKey,
+};
+use std::{fs, io::Error, sync::OnceLock};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const FNAME_OF_PROOF: &str = "proof.bin";
+const FNAME_OF_PUBLIC_VALUES: &str = "public-values.bin";
+const FNAME_OF_VKEY: &str = "vkey.bin";
+const FNAME_OF_VKEY_HASH: &str = "vkey.hash";
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+pub struct Prover {
+    client: ProverClient,
+    keys: OnceLock<ProverKeys>,
+    mode: ProverMode,
+    path_to_elf: Utf8PathBuf,
+    path_to_output: Utf8PathBuf,
+}
+
+struct ProverKeys {
+    elf: Vec<u8>,
+    pk: SP1ProvingKey,
+    vk: SP1VerifyingKey,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl Prover {
+    pub fn new(config: Config) -> Self {
+        let mode = config.prover().mode();
+
+        Self {
+            client: match mode {
+                ProverMode::Mock => ProverClient::mock(),
+                ProverMode::Local => ProverClient::new(),
+            },
+            keys: OnceLock::new(),
+            mode,
+            path_to_elf: Utf8PathBuf::from(config.prover().path_to_elf()),
+            path_to_output: Utf8PathBuf::from(config.prover().path_to_output()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
 
 impl Prover {
-    pub fn new(_: Config) -> Self {
-        Self {}
+    pub fn elf(&self) -> Result<&[u8], Error> {
+        Ok(&self.keys()?.elf)
+    }
+
+    pub fn mode(&self) -> ProverMode {
+        self.mode
+    }
+
+    pub fn path_to_output(&self) -> &Utf8Path {
+        &self.path_to_output
+    }
+
+    pub fn vk(&self) -> Result<&SP1VerifyingKey, Error> {
+        Ok(&self.keys()?.vk)
+    }
+
+    // N.B. ELF is loaded & keys are set up upon first use as setup is expensive.
+    fn keys(&self) -> Result<&ProverKeys, Error> {
+        if let Some(keys) = self.keys.get() {
+            return Ok(keys);
+        }
+        let elf = fs::read(&self.path_to_elf)?;
+        let (pk, vk) = self.client.setup(&elf);
+
+        Ok(self.keys.get_or_init(|| ProverKeys { elf, pk, vk }))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl Prover {
+    /// Executes program without generating a proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `stdin` - Program inputs.
+    ///
+    pub fn execute(&self, stdin: &SP1Stdin) -> Result<(SP1PublicValues, ExecutionReport), Error> {
+        self.client
+            .execute(self.elf()?, stdin.clone())
+            .run()
+            .map_err(Error::other)
+    }
+
+    /// Generates a core proof of program execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `stdin` - Program inputs.
+    ///
+    pub fn prove(&self, stdin: &SP1Stdin) -> Result<SP1ProofWithPublicValues, Error> {
+        self.client
+            .prove(&self.keys()?.pk, stdin.clone())
+            .run()
+            .map_err(Error::other)
+    }
+
+    /// Generates a compressed proof of program execution, i.e. one that may be verified by a program.
+    ///
+    /// # Arguments
+    ///
+    /// * `stdin` - Program inputs.
+    ///
+    pub fn prove_compressed(&self, stdin: &SP1Stdin) -> Result<SP1ProofWithPublicValues, Error> {
+        self.client
+            .prove(&self.keys()?.pk, stdin.clone())
+            .compressed()
+            .run()
+            .map_err(Error::other)
+    }
+
+    /// Verifies a proof against program verification key.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - Proof to be verified.
+    ///
+    pub fn verify(&self, proof: &SP1ProofWithPublicValues) -> Result<(), Error> {
+        self.client.verify(proof, self.vk()?).map_err(Error::other)
+    }
+
+    /// Writes proof artifacts to output directory & returns path to proof directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - Proof whose artifacts are to be written.
+    ///
+    pub fn write_artifacts(&self, proof: &SP1ProofWithPublicValues) -> Result<Utf8PathBuf, Error> {
+        // Program artifacts.
+        let vk = self.vk()?;
+        fs::create_dir_all(&self.path_to_output)?;
+        fs::write(
+            self.path_to_output.join(FNAME_OF_VKEY),
+            bincode::serialize(vk).map_err(Error::other)?,
+        )?;
+        fs::write(self.path_to_output.join(FNAME_OF_VKEY_HASH), vk.bytes32())?;
+
+        // Proof artifacts.
+        let path_to_proof = get_path_to_proof(&self.path_to_output, proof.public_values.as_slice());
+        fs::create_dir_all(&path_to_proof)?;
+        fs::write(
+            path_to_proof.join(FNAME_OF_PUBLIC_VALUES),
+            proof.public_values.as_slice(),
+        )?;
+        proof
+            .save(path_to_proof.join(FNAME_OF_PROOF))
+            .map_err(Error::other)?;
+
+        Ok(path_to_proof)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+/// Returns path to directory within which a proof's artifacts are written.
+fn get_path_to_proof(path_to_output: &Utf8Path, public_values: &[u8]) -> Utf8PathBuf {
+    path_to_output.join(hex::encode(lcrypto::get_hash_blake2b(
+        public_values.to_vec(),
+    )))
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lcodecs::binary::Decode;
+    use lrequests::VerificationRequest;
+    use lsimulator::{Simulator, SimulatorConfig};
+    use ltypeset::{
+        chain::{Block, BlockWithProofs},
+        crypto::Digest,
+        verification::{BlockPublicValues, PublicValues},
+    };
+    use rand::Rng;
+    use std::{env, io::ErrorKind};
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_path_to_temp_dir() -> Utf8PathBuf {
+        Utf8PathBuf::from_path_buf(env::temp_dir())
+            .unwrap()
+            .join(format!(
+                "lkernel-prover-{}",
+                rand::thread_rng().gen::<u64>()
+            ))
+    }
+
+    // N.B. Program ELF is emitted by this crate's build script, i.e. from current program sources.
+    fn get_config() -> Config {
+        let mut config = Config::new(format!(
+            "{}/fixtures/config.toml",
+            env::var("CARGO_MANIFEST_DIR").unwrap()
+        ));
+        config.prover.path_to_output = get_path_to_temp_dir().to_string();
+
+        config
+    }
+
+    fn get_stdin(request: &VerificationRequest) -> SP1Stdin {
+        let mut stdin = SP1Stdin::new();
+        stdin.write_vec(request.encode().unwrap());
+
+        stdin
+    }
+
+    fn get_digest_request() -> VerificationRequest {
+        VerificationRequest::Digest {
+            digest: Digest::from(DIGEST_HEX),
+            msg: b"casper".to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_that_path_to_proof_is_content_addressed() {
+        let path_to_output = Utf8Path::new("proofs");
+        let path_to_proof = get_path_to_proof(path_to_output, &[1, 2, 3]);
+
+        assert_eq!(path_to_proof.parent(), Some(path_to_output));
+        assert_eq!(path_to_proof.file_name().unwrap().len(), 64);
+        assert_eq!(path_to_proof, get_path_to_proof(path_to_output, &[1, 2, 3]));
+        assert_ne!(path_to_proof, get_path_to_proof(path_to_output, &[1, 2]));
+    }
+
+    #[test]
+    fn test_that_proof_artifacts_are_written_in_mock_mode() {
+        let prover = Prover::new(get_config());
+        let stdin = get_stdin(&get_digest_request());
+        assert_eq!(prover.mode(), ProverMode::Mock);
+
+        let (public_values, _) = prover.execute(&stdin).unwrap();
+        assert_eq!(
+            PublicValues::decode_exact(public_values.as_slice()).unwrap(),
+            PublicValues::Digest(Digest::from(DIGEST_HEX))
+        );
+        let proof = prover.prove(&stdin).unwrap();
+        assert_eq!(proof.public_values.as_slice(), public_values.as_slice());
+        prover.verify(&proof).unwrap();
+
+        let path_to_proof = prover.write_artifacts(&proof).unwrap();
+        assert_eq!(
+            path_to_proof,
+            get_path_to_proof(prover.path_to_output(), public_values.as_slice())
+        );
+        assert_eq!(
+            fs::read(path_to_proof.join(FNAME_OF_PUBLIC_VALUES)).unwrap(),
+            public_values.as_slice()
+        );
+        let saved_proof =
+            SP1ProofWithPublicValues::load(path_to_proof.join(FNAME_OF_PROOF)).unwrap();
+        assert_eq!(
+            saved_proof.public_values.as_slice(),
+            public_values.as_slice()
+        );
+        prover.verify(&saved_proof).unwrap();
+
+        let vk = prover.vk().unwrap();
+        let saved_vk: SP1VerifyingKey =
+            bincode::deserialize(&fs::read(prover.path_to_output().join(FNAME_OF_VKEY)).unwrap())
+                .unwrap();
+        assert_eq!(saved_vk.bytes32(), vk.bytes32());
+        assert_eq!(
+            fs::read_to_string(prover.path_to_output().join(FNAME_OF_VKEY_HASH)).unwrap(),
+            vk.bytes32()
+        );
+
+        fs::remove_dir_all(prover.path_to_output()).unwrap();
+    }
+
+    #[test]
+    fn test_error_if_elf_is_missing() {
+        let mut config = get_config();
+        config.prover.path_to_elf = get_path_to_temp_dir().join("elf").to_string();
+        let prover = Prover::new(config);
+
+        assert!(matches!(prover.vk(), Err(err) if err.kind() == ErrorKind::NotFound));
+        assert!(matches!(
+            prover.execute(&get_stdin(&get_digest_request())),
+            Err(err) if err.kind() == ErrorKind::NotFound
+        ));
+        assert!(!prover.path_to_output().exists());
+    }
+
+    #[test]
+    fn test_that_simulated_block_ranges_are_executed_in_mock_mode() {
+        let prover = Prover::new(get_config());
+        let mut simulator = Simulator::new(SimulatorConfig {
+            era_length: 100,
+            ..Default::default()
+        })
+        .unwrap();
+        let chain: Vec<BlockWithProofs> = simulator.by_ref().take(201).collect();
+        let set_of_era_consensus_info: Vec<_> = chain
+            .iter()
+            .enumerate()
+            .filter_map(
+                |(height, block_with_proofs)| match block_with_proofs.block() {
+                    Block::V2(inner) => lverifiers::get_next_era_consensus_info(inner)
+                        .unwrap()
+                        .map(|era_consensus_info| (height, era_consensus_info)),
+                    Block::V1(_) => unreachable!(),
+                },
+            )
+            .collect();
+        assert_eq!(set_of_era_consensus_info.len(), 3);
+
+        for pair in set_of_era_consensus_info.windows(2) {
+            let ((previous, era_consensus_info), (next, _)) = (&pair[0], &pair[1]);
+            let request = VerificationRequest::BlockRangeV2WithProofs {
+                chain_name_digest: simulator.chain_name_digest(),
+                era_consensus_info: era_consensus_info.to_owned(),
+                set_of_blocks_with_proofs: chain[*previous..=*next].to_vec(),
+            };
+            let (public_values, _) = prover.execute(&get_stdin(&request)).unwrap();
+            assert_eq!(
+                PublicValues::decode_exact(public_values.as_slice()).unwrap(),
+                PublicValues::BlockRangeV2(
+                    BlockPublicValues::new_from_block(
+                        chain[*previous].block(),
+                        Some(simulator.chain_name_digest()),
+                        None,
+                    ),
+                    BlockPublicValues::new_from_block(
+                        chain[*next].block(),
+                        Some(simulator.chain_name_digest()),
+                        Some(lverifiers::get_validator_set_digest(era_consensus_info)),
+                    ),
+                )
+            );
+        }
     }
 }
@@ -0,0 +1,16 @@
+//! Verification requests issued by host to zk-vm program, i.e. the program's stdin layout.
+//!
+//! A request is written to program stdin as a single buffer:
+//!
+//! ```text
+//! <verification type tag: u8><CBOR encoded request fields, in alphabetical order>
+//! ```
+//!
+//! N.B. An era transition request is followed by a deferred proof, i.e. the previous era proof,
+//! which is written to stdin separately & verified by the sp1 recursion circuit.
+
+mod request;
+mod tags;
+
+pub use request::{RequestError, VerificationRequest};
+pub use tags::*;
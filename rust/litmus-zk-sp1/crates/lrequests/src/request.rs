@@ -0,0 +1,423 @@
+use super::tags::*;
+use ltypeset::{
+    chain::{BlockWithProofs, ChainNameDigest, EraConsensusInfo, TransactionV2Hash},
+    crypto::{Digest, Signature, VerificationKey},
+    trie::TrieMerkleProof,
+};
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// A request to verify an entity within zk-vm program.
+///
+/// N.B. A single request is issued per program run, hence disparity in variant size is immaterial.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerificationRequest {
+    /// Verify a digest over a message.
+    Digest { digest: Digest, msg: Vec<u8> },
+
+    /// Verify a signature over a digest.
+    Signature {
+        digest: Digest,
+        sig: Signature,
+        vkey: VerificationKey,
+    },
+
+    /// Verify a version one block against validator set of its era.
+    BlockV1WithProofs {
+        block_with_proofs: BlockWithProofs,
+        era_consensus_info: EraConsensusInfo,
+    },
+
+    /// Verify a version two block against validator set of its era.
+    BlockV2WithProofs {
+        block_with_proofs: BlockWithProofs,
+        chain_name_digest: ChainNameDigest,
+        era_consensus_info: EraConsensusInfo,
+    },
+
+    /// Verify inclusion of a transaction within a version two block verified against validator set of its era.
+    TransactionV2Inclusion {
+        block_with_proofs: BlockWithProofs,
+        category: u8,
+        chain_name_digest: ChainNameDigest,
+        era_consensus_info: EraConsensusInfo,
+        transaction_hash: TransactionV2Hash,
+    },
+
+    /// Verify inclusion of a global state entry within state of a version two block verified against validator set of its era.
+    StateV2Inclusion {
+        block_with_proofs: BlockWithProofs,
+        chain_name_digest: ChainNameDigest,
+        era_consensus_info: EraConsensusInfo,
+        proof: TrieMerkleProof,
+    },
+
+    /// Verify a contiguous range of version two blocks against validator set of tip's era.
+    BlockRangeV2WithProofs {
+        chain_name_digest: ChainNameDigest,
+        era_consensus_info: EraConsensusInfo,
+        set_of_blocks_with_proofs: Vec<BlockWithProofs>,
+    },
+
+    /// Verify a trusted switch block, i.e. start a chain of era proofs.
+    SwitchBlockV2WithProofs {
+        block_with_proofs: BlockWithProofs,
+        chain_name_digest: ChainNameDigest,
+        vkey_hash: [u32; 8],
+    },
+
+    /// Verify a switch block against previous switch block, i.e. extend a chain of era proofs.
+    EraTransitionV2WithProofs {
+        block_with_proofs: BlockWithProofs,
+        chain_name_digest: ChainNameDigest,
+        previous_block_with_proofs: BlockWithProofs,
+        previous_public_values: Vec<u8>,
+        vkey_hash: [u32; 8],
+    },
+}
+
+/// Reasons for which a request may fail to be encoded or decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequestError {
+    /// Request buffer is empty.
+    EmptyBuffer,
+
+    /// Request fields cannot be encoded or decoded.
+    InvalidPayload(String),
+
+    /// Verification type tag is not supported by program.
+    UnsupportedVerificationType(u8),
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl VerificationRequest {
+    /// Returns tag identifying request's verification type.
+    pub fn verification_type(&self) -> u8 {
+        match self {
+            Self::Digest { .. } => VERIFICATION_TYPE_DIGEST,
+            Self::Signature { .. } => VERIFICATION_TYPE_SIGNATURE,
+            Self::BlockV1WithProofs { .. } => VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS,
+            Self::BlockV2WithProofs { .. } => VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS,
+            Self::TransactionV2Inclusion { .. } => VERIFICATION_TYPE_TRANSACTION_V2_INCLUSION,
+            Self::StateV2Inclusion { .. } => VERIFICATION_TYPE_STATE_V2_INCLUSION,
+            Self::BlockRangeV2WithProofs { .. } => VERIFICATION_TYPE_BLOCK_RANGE_V2_WITH_PROOFS,
+            Self::SwitchBlockV2WithProofs { .. } => VERIFICATION_TYPE_SWITCH_BLOCK_V2_WITH_PROOFS,
+            Self::EraTransitionV2WithProofs { .. } => {
+                VERIFICATION_TYPE_ERA_TRANSITION_V2_WITH_PROOFS
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl VerificationRequest {
+    /// Decodes a request from a program stdin buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Verification type tag followed by CBOR encoded request fields.
+    ///
+    pub fn decode(bytes: &[u8]) -> Result<Self, RequestError> {
+        let (tag, payload) = bytes.split_first().ok_or(RequestError::EmptyBuffer)?;
+
+        Ok(match *tag {
+            VERIFICATION_TYPE_DIGEST => {
+                let (digest, msg) = from_payload(payload)?;
+                Self::Digest { digest, msg }
+            }
+            VERIFICATION_TYPE_SIGNATURE => {
+                let (digest, sig, vkey) = from_payload(payload)?;
+                Self::Signature { digest, sig, vkey }
+            }
+            VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS => {
+                let (block_with_proofs, era_consensus_info) = from_payload(payload)?;
+                Self::BlockV1WithProofs {
+                    block_with_proofs,
+                    era_consensus_info,
+                }
+            }
+            VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS => {
+                let (block_with_proofs, chain_name_digest, era_consensus_info) =
+                    from_payload(payload)?;
+                Self::BlockV2WithProofs {
+                    block_with_proofs,
+                    chain_name_digest,
+                    era_consensus_info,
+                }
+            }
+            VERIFICATION_TYPE_TRANSACTION_V2_INCLUSION => {
+                let (
+                    block_with_proofs,
+                    category,
+                    chain_name_digest,
+                    era_consensus_info,
+                    transaction_hash,
+                ) = from_payload(payload)?;
+                Self::TransactionV2Inclusion {
+                    block_with_proofs,
+                    category,
+                    chain_name_digest,
+                    era_consensus_info,
+                    transaction_hash,
+                }
+            }
+            VERIFICATION_TYPE_STATE_V2_INCLUSION => {
+                let (block_with_proofs, chain_name_digest, era_consensus_info, proof) =
+                    from_payload(payload)?;
+                Self::StateV2Inclusion {
+                    block_with_proofs,
+                    chain_name_digest,
+                    era_consensus_info,
+                    proof,
+                }
+            }
+            VERIFICATION_TYPE_BLOCK_RANGE_V2_WITH_PROOFS => {
+                let (chain_name_digest, era_consensus_info, set_of_blocks_with_proofs) =
+                    from_payload(payload)?;
+                Self::BlockRangeV2WithProofs {
+                    chain_name_digest,
+                    era_consensus_info,
+                    set_of_blocks_with_proofs,
+                }
+            }
+            VERIFICATION_TYPE_SWITCH_BLOCK_V2_WITH_PROOFS => {
+                let (block_with_proofs, chain_name_digest, vkey_hash) = from_payload(payload)?;
+                Self::SwitchBlockV2WithProofs {
+                    block_with_proofs,
+                    chain_name_digest,
+                    vkey_hash,
+                }
+            }
+            VERIFICATION_TYPE_ERA_TRANSITION_V2_WITH_PROOFS => {
+                let (
+                    block_with_proofs,
+                    chain_name_digest,
+                    previous_block_with_proofs,
+                    previous_public_values,
+                    vkey_hash,
+                ) = from_payload(payload)?;
+                Self::EraTransitionV2WithProofs {
+                    block_with_proofs,
+                    chain_name_digest,
+                    previous_block_with_proofs,
+                    previous_public_values,
+                    vkey_hash,
+                }
+            }
+            tag => return Err(RequestError::UnsupportedVerificationType(tag)),
+        })
+    }
+
+    /// Encodes request as a program stdin buffer.
+    pub fn encode(&self) -> Result<Vec<u8>, RequestError> {
+        let payload = match self {
+            Self::Digest { digest, msg } => serde_cbor::to_vec(&(digest, msg)),
+            Self::Signature { digest, sig, vkey } => serde_cbor::to_vec(&(digest, sig, vkey)),
+            Self::BlockV1WithProofs {
+                block_with_proofs,
+                era_consensus_info,
+            } => serde_cbor::to_vec(&(block_with_proofs, era_consensus_info)),
+            Self::BlockV2WithProofs {
+                block_with_proofs,
+                chain_name_digest,
+                era_consensus_info,
+            } => serde_cbor::to_vec(&(block_with_proofs, chain_name_digest, era_consensus_info)),
+            Self::TransactionV2Inclusion {
+                block_with_proofs,
+                category,
+                chain_name_digest,
+                era_consensus_info,
+                transaction_hash,
+            } => serde_cbor::to_vec(&(
+                block_with_proofs,
+                category,
+                chain_name_digest,
+                era_consensus_info,
+                transaction_hash,
+            )),
+            Self::StateV2Inclusion {
+                block_with_proofs,
+                chain_name_digest,
+                era_consensus_info,
+                proof,
+            } => serde_cbor::to_vec(&(
+                block_with_proofs,
+                chain_name_digest,
+                era_consensus_info,
+                proof,
+            )),
+            Self::BlockRangeV2WithProofs {
+                chain_name_digest,
+                era_consensus_info,
+                set_of_blocks_with_proofs,
+            } => serde_cbor::to_vec(&(
+                chain_name_digest,
+                era_consensus_info,
+                set_of_blocks_with_proofs,
+            )),
+            Self::SwitchBlockV2WithProofs {
+                block_with_proofs,
+                chain_name_digest,
+                vkey_hash,
+            } => serde_cbor::to_vec(&(block_with_proofs, chain_name_digest, vkey_hash)),
+            Self::EraTransitionV2WithProofs {
+                block_with_proofs,
+                chain_name_digest,
+                previous_block_with_proofs,
+                previous_public_values,
+                vkey_hash,
+            } => serde_cbor::to_vec(&(
+                block_with_proofs,
+                chain_name_digest,
+                previous_block_with_proofs,
+                previous_public_values,
+                vkey_hash,
+            )),
+        }
+        .map_err(|err| RequestError::InvalidPayload(err.to_string()))?;
+
+        let mut result = Vec::with_capacity(1 + payload.len());
+        result.push(self.verification_type());
+        result.extend(payload);
+
+        Ok(result)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RequestError::EmptyBuffer => write!(f, "Request buffer is empty"),
+            RequestError::InvalidPayload(err) => write!(f, "Invalid request payload: {err}"),
+            RequestError::UnsupportedVerificationType(tag) => {
+                write!(f, "Unsupported verification type: {tag}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+fn from_payload<T: DeserializeOwned>(payload: &[u8]) -> Result<T, RequestError> {
+    serde_cbor::from_slice(payload).map_err(|err| RequestError::InvalidPayload(err.to_string()))
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset_utils::fixtures::{get_block_with_proofs, get_era_consensus_info};
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_chain_name_digest() -> ChainNameDigest {
+        ChainNameDigest::new_from_chain_name("cspr-dev-cctl")
+    }
+
+    fn get_requests() -> Vec<VerificationRequest> {
+        let block_with_proofs = get_block_with_proofs(11);
+        let proof = &block_with_proofs.proofs()[0];
+
+        vec![
+            VerificationRequest::Digest {
+                digest: Digest::from(DIGEST_HEX),
+                msg: b"casper".to_vec(),
+            },
+            VerificationRequest::Signature {
+                digest: Digest::from(DIGEST_HEX),
+                sig: proof.signature().to_owned(),
+                vkey: proof.verification_key().to_owned(),
+            },
+            VerificationRequest::BlockV2WithProofs {
+                block_with_proofs: get_block_with_proofs(12),
+                chain_name_digest: get_chain_name_digest(),
+                era_consensus_info: get_era_consensus_info(11),
+            },
+            VerificationRequest::TransactionV2Inclusion {
+                block_with_proofs: get_block_with_proofs(12),
+                category: 3,
+                chain_name_digest: get_chain_name_digest(),
+                era_consensus_info: get_era_consensus_info(11),
+                transaction_hash: TransactionV2Hash::new(Digest::from(DIGEST_HEX)),
+            },
+            VerificationRequest::BlockRangeV2WithProofs {
+                chain_name_digest: get_chain_name_digest(),
+                era_consensus_info: get_era_consensus_info(11),
+                set_of_blocks_with_proofs: (10..=12).map(get_block_with_proofs).collect(),
+            },
+            VerificationRequest::SwitchBlockV2WithProofs {
+                block_with_proofs: block_with_proofs.clone(),
+                chain_name_digest: get_chain_name_digest(),
+                vkey_hash: [1, 2, 3, 4, 5, 6, 7, 8],
+            },
+            VerificationRequest::EraTransitionV2WithProofs {
+                block_with_proofs: get_block_with_proofs(22),
+                chain_name_digest: get_chain_name_digest(),
+                previous_block_with_proofs: block_with_proofs,
+                previous_public_values: vec![15, 0, 1],
+                vkey_hash: [1, 2, 3, 4, 5, 6, 7, 8],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_that_requests_can_be_encoded_and_decoded() {
+        for request in get_requests() {
+            let encoded = request.encode().unwrap();
+            assert_eq!(encoded[0], request.verification_type());
+            assert_eq!(VerificationRequest::decode(&encoded).unwrap(), request);
+        }
+    }
+
+    #[test]
+    fn test_error_if_buffer_is_empty() {
+        assert_eq!(
+            VerificationRequest::decode(&[]),
+            Err(RequestError::EmptyBuffer)
+        );
+    }
+
+    #[test]
+    fn test_error_if_verification_type_is_unsupported() {
+        let mut encoded = get_requests()[0].encode().unwrap();
+        encoded[0] = 2;
+
+        assert_eq!(
+            VerificationRequest::decode(&encoded),
+            Err(RequestError::UnsupportedVerificationType(2))
+        );
+    }
+
+    #[test]
+    fn test_error_if_payload_does_not_match_verification_type() {
+        let mut encoded = get_requests()[0].encode().unwrap();
+        encoded[0] = VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS;
+
+        assert!(matches!(
+            VerificationRequest::decode(&encoded),
+            Err(RequestError::InvalidPayload(_))
+        ));
+    }
+}
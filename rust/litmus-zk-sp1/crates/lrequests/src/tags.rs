@@ -0,0 +1,14 @@
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Tags identifying each type of verification supported by zk-vm program.
+pub const VERIFICATION_TYPE_DIGEST: u8 = 0;
+pub const VERIFICATION_TYPE_SIGNATURE: u8 = 1;
+pub const VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS: u8 = 10;
+pub const VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS: u8 = 11;
+pub const VERIFICATION_TYPE_TRANSACTION_V2_INCLUSION: u8 = 12;
+pub const VERIFICATION_TYPE_STATE_V2_INCLUSION: u8 = 13;
+pub const VERIFICATION_TYPE_BLOCK_RANGE_V2_WITH_PROOFS: u8 = 14;
+pub const VERIFICATION_TYPE_SWITCH_BLOCK_V2_WITH_PROOFS: u8 = 15;
+pub const VERIFICATION_TYPE_ERA_TRANSITION_V2_WITH_PROOFS: u8 = 16;
@@ -0,0 +1,203 @@
+use camino::Utf8Path;
+use ltypeset::chain::ProtocolVersion;
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs};
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Parameters of a synthetic chain.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SimulatorConfig {
+    /// Validators deemed to have equivocated, each reported by switch block of an era.
+    #[serde(default)]
+    pub equivocations: Vec<EquivocationConfig>,
+
+    /// Number of blocks per era, i.e. distance between switch blocks.
+    pub era_length: u64,
+
+    /// Number of validators per era.
+    pub era_size: usize,
+
+    /// Name of synthetic chain.
+    pub name_of_chain: String,
+
+    /// Protocol upgrades, each activated at start of an era.
+    #[serde(default)]
+    pub protocol_upgrades: Vec<ProtocolUpgradeConfig>,
+
+    /// Number of validators within pool from which era validator sets are drawn.
+    pub validator_count: usize,
+
+    /// Weight of each pooled validator - defaults to equal weights if empty.
+    #[serde(default)]
+    pub validator_weights: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EquivocationConfig {
+    /// Era within which validator equivocated.
+    pub era_id: u64,
+
+    /// Index of equivocating validator within validator pool.
+    pub validator_index: usize,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProtocolUpgradeConfig {
+    /// Era from which upgrade is active.
+    pub era_id: u64,
+
+    /// Protocol version declared by blocks once upgrade is active.
+    pub protocol_version: ProtocolVersion,
+}
+
+/// Reasons for which a simulator configuration may be invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SimulatorError {
+    /// An equivocation references a validator outside of validator pool.
+    InvalidEquivocatorIndex(usize),
+
+    /// Era size is zero or exceeds number of validators that never equivocate.
+    InvalidEraSize(usize),
+
+    /// Number of validator weights does not match validator pool size.
+    InvalidValidatorWeights,
+
+    /// An equivocation references a validator that does not validate within its era.
+    EquivocatorIsNotEraValidator { era_id: u64, validator_index: usize },
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl SimulatorConfig {
+    pub fn new(path_to_toml: &Utf8Path) -> Self {
+        let path_to_toml = fs::read_to_string(path_to_toml).unwrap();
+
+        toml::from_str(&path_to_toml).unwrap()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl SimulatorConfig {
+    pub fn equivocations(&self) -> &[EquivocationConfig] {
+        &self.equivocations
+    }
+
+    pub fn era_length(&self) -> u64 {
+        self.era_length
+    }
+
+    pub fn era_size(&self) -> usize {
+        self.era_size
+    }
+
+    pub fn name_of_chain(&self) -> &str {
+        &self.name_of_chain
+    }
+
+    pub fn protocol_upgrades(&self) -> &[ProtocolUpgradeConfig] {
+        &self.protocol_upgrades
+    }
+
+    pub fn validator_count(&self) -> usize {
+        self.validator_count
+    }
+
+    pub fn validator_weights(&self) -> &[u64] {
+        &self.validator_weights
+    }
+}
+
+impl EquivocationConfig {
+    pub fn era_id(&self) -> u64 {
+        self.era_id
+    }
+
+    pub fn validator_index(&self) -> usize {
+        self.validator_index
+    }
+}
+
+impl ProtocolUpgradeConfig {
+    pub fn era_id(&self) -> u64 {
+        self.era_id
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Display for SimulatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimulatorError::InvalidEquivocatorIndex(idx) => {
+                write!(f, "Invalid equivocator index: {idx}")
+            }
+            SimulatorError::InvalidEraSize(era_size) => write!(f, "Invalid era size: {era_size}"),
+            SimulatorError::InvalidValidatorWeights => write!(f, "Invalid validator weights"),
+            SimulatorError::EquivocatorIsNotEraValidator {
+                era_id,
+                validator_index,
+            } => write!(
+                f,
+                "Equivocator {validator_index} is not an era {era_id} validator"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SimulatorError {}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            equivocations: vec![],
+            era_length: 10,
+            era_size: 4,
+            name_of_chain: String::from("cspr-simulated"),
+            protocol_upgrades: vec![],
+            validator_count: 6,
+            validator_weights: vec![],
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::primitives::SemanticVersion;
+    use std::env;
+
+    #[test]
+    fn test_that_config_can_be_loaded() {
+        let path_to_toml = format!(
+            "{}/fixtures/config.toml",
+            env::var("CARGO_MANIFEST_DIR").unwrap()
+        );
+        let config = SimulatorConfig::new(Utf8Path::new(&path_to_toml));
+
+        assert_eq!(config.name_of_chain(), "cspr-simulated");
+        assert_eq!(config.validator_weights().len(), config.validator_count());
+        assert_eq!(config.equivocations()[0].validator_index(), 3);
+        assert_eq!(
+            config.protocol_upgrades()[0].protocol_version(),
+            ProtocolVersion::new(SemanticVersion::new(2, 1, 0))
+        );
+    }
+}
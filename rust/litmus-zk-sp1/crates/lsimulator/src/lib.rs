@@ -0,0 +1,7 @@
+mod config;
+mod schedule;
+mod simulator;
+
+pub use config::{EquivocationConfig, ProtocolUpgradeConfig, SimulatorConfig, SimulatorError};
+pub use schedule::ConfiguredEraSchedule;
+pub use simulator::{get_fname_of_block, write_block_with_proofs, Simulator};
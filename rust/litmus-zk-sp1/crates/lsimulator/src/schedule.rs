@@ -0,0 +1,325 @@
+use crate::config::{SimulatorConfig, SimulatorError};
+use ltypeset::{
+    chain::{EraId, Motes, ProtocolVersion},
+    crypto::{SigningKey, VerificationKey},
+    primitives::SemanticVersion,
+};
+use ltypeset_utils::generator::{get_signing_keys, EraSchedule};
+use std::collections::BTreeSet;
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Weight of a pooled validator when weights are not configured.
+const DEFAULT_VALIDATOR_WEIGHT: u64 = 1_000_000_000;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Era schedule derived from a simulator configuration.
+///
+/// N.B. Era validator sets are rotated by one validator per era through those pooled validators
+/// that have not equivocated within a previous era, i.e. an equivocator signs until end of its
+/// era, is reported by that era's switch block & is excluded from subsequent eras.
+pub struct ConfiguredEraSchedule {
+    /// Simulator configuration.
+    config: SimulatorConfig,
+
+    /// Pool of signing keys from which era validator sets are drawn.
+    signing_keys: Vec<SigningKey>,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl ConfiguredEraSchedule {
+    /// Constructor: returns a schedule derived from a simulator configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Simulator configuration.
+    ///
+    pub fn new(config: SimulatorConfig) -> Result<Self, SimulatorError> {
+        let equivocators: BTreeSet<usize> = config
+            .equivocations()
+            .iter()
+            .map(|equivocation| equivocation.validator_index())
+            .collect();
+        if let Some(idx) = equivocators
+            .iter()
+            .find(|idx| **idx >= config.validator_count())
+        {
+            return Err(SimulatorError::InvalidEquivocatorIndex(*idx));
+        }
+        if config.era_size() == 0
+            || config.era_size() > config.validator_count() - equivocators.len()
+        {
+            return Err(SimulatorError::InvalidEraSize(config.era_size()));
+        }
+        if !config.validator_weights().is_empty()
+            && config.validator_weights().len() != config.validator_count()
+        {
+            return Err(SimulatorError::InvalidValidatorWeights);
+        }
+
+        let schedule = Self {
+            signing_keys: get_signing_keys(config.validator_count()),
+            config,
+        };
+        if let Some(equivocation) = schedule.config.equivocations().iter().find(|equivocation| {
+            !schedule
+                .get_era_validators(&EraId::new(equivocation.era_id()))
+                .iter()
+                .any(|(signing_key, _)| {
+                    signing_key == &schedule.signing_keys[equivocation.validator_index()]
+                })
+        }) {
+            return Err(SimulatorError::EquivocatorIsNotEraValidator {
+                era_id: equivocation.era_id(),
+                validator_index: equivocation.validator_index(),
+            });
+        }
+
+        Ok(schedule)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl ConfiguredEraSchedule {
+    pub fn config(&self) -> &SimulatorConfig {
+        &self.config
+    }
+
+    pub fn signing_keys(&self) -> &[SigningKey] {
+        &self.signing_keys
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl ConfiguredEraSchedule {
+    /// Returns indexes of pooled validators eligible to validate within an era.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era whose eligible validators are to be returned.
+    ///
+    pub fn get_eligible_validators(&self, era_id: &EraId) -> Vec<usize> {
+        (0..self.config.validator_count())
+            .filter(|idx| {
+                !self.config.equivocations().iter().any(|equivocation| {
+                    equivocation.validator_index() == *idx && equivocation.era_id() < era_id.inner()
+                })
+            })
+            .collect()
+    }
+
+    /// Returns weight of a pooled validator.
+    ///
+    /// # Arguments
+    ///
+    /// * `idx` - Index of validator within validator pool.
+    ///
+    pub fn get_validator_weight(&self, idx: usize) -> Motes {
+        Motes::new(
+            self.config
+                .validator_weights()
+                .get(idx)
+                .copied()
+                .unwrap_or(DEFAULT_VALIDATOR_WEIGHT),
+        )
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl EraSchedule for ConfiguredEraSchedule {
+    fn get_era_validators(&self, era_id: &EraId) -> Vec<(SigningKey, Motes)> {
+        let eligible = self.get_eligible_validators(era_id);
+
+        (0..self.config.era_size())
+            .map(|offset| eligible[(era_id.inner() as usize + offset) % eligible.len()])
+            .map(|idx| {
+                (
+                    self.signing_keys[idx].clone(),
+                    self.get_validator_weight(idx),
+                )
+            })
+            .collect()
+    }
+
+    fn get_era_equivocators(&self, era_id: &EraId) -> Vec<VerificationKey> {
+        self.config
+            .equivocations()
+            .iter()
+            .filter(|equivocation| equivocation.era_id() == era_id.inner())
+            .map(|equivocation| {
+                self.signing_keys[equivocation.validator_index()].verification_key()
+            })
+            .collect()
+    }
+
+    fn get_protocol_version(&self, era_id: &EraId) -> ProtocolVersion {
+        self.config
+            .protocol_upgrades()
+            .iter()
+            .filter(|upgrade| upgrade.era_id() <= era_id.inner())
+            .max_by_key(|upgrade| upgrade.era_id())
+            .map(|upgrade| upgrade.protocol_version())
+            .unwrap_or(ProtocolVersion::new(SemanticVersion::new(2, 0, 0)))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EquivocationConfig, ProtocolUpgradeConfig};
+
+    fn get_schedule() -> ConfiguredEraSchedule {
+        ConfiguredEraSchedule::new(SimulatorConfig {
+            equivocations: vec![EquivocationConfig {
+                era_id: 2,
+                validator_index: 3,
+            }],
+            protocol_upgrades: vec![
+                ProtocolUpgradeConfig {
+                    era_id: 5,
+                    protocol_version: ProtocolVersion::new(SemanticVersion::new(2, 2, 0)),
+                },
+                ProtocolUpgradeConfig {
+                    era_id: 3,
+                    protocol_version: ProtocolVersion::new(SemanticVersion::new(2, 1, 0)),
+                },
+            ],
+            validator_weights: vec![10, 20, 30, 40, 50, 60],
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_that_era_validators_are_weighted() {
+        let schedule = get_schedule();
+        for (signing_key, weight) in schedule.get_era_validators(&EraId::new(1)) {
+            let idx = schedule
+                .signing_keys()
+                .iter()
+                .position(|pooled| pooled == &signing_key)
+                .unwrap();
+            assert_eq!(weight, Motes::new(10 * (idx as u64 + 1)));
+        }
+        assert_eq!(
+            ConfiguredEraSchedule::new(SimulatorConfig::default())
+                .unwrap()
+                .get_validator_weight(0),
+            Motes::new(DEFAULT_VALIDATOR_WEIGHT)
+        );
+    }
+
+    #[test]
+    fn test_that_equivocator_is_reported_and_excluded_from_subsequent_eras() {
+        let schedule = get_schedule();
+        let equivocator = schedule.signing_keys()[3].verification_key();
+        let is_era_validator = |era_id: u64| {
+            schedule
+                .get_era_validators(&EraId::new(era_id))
+                .iter()
+                .any(|(signing_key, _)| signing_key.verification_key() == equivocator)
+        };
+
+        assert!(is_era_validator(2));
+        assert_eq!(
+            schedule.get_era_equivocators(&EraId::new(2)),
+            vec![equivocator]
+        );
+        for era_id in 3..12 {
+            assert!(!is_era_validator(era_id));
+            assert!(schedule
+                .get_era_equivocators(&EraId::new(era_id))
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn test_that_protocol_version_is_that_of_latest_upgrade() {
+        let schedule = get_schedule();
+        for (era_id, minor) in [(0, 0), (2, 0), (3, 1), (4, 1), (5, 2), (9, 2)] {
+            assert_eq!(
+                schedule.get_protocol_version(&EraId::new(era_id)),
+                ProtocolVersion::new(SemanticVersion::new(2, minor, 0))
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_if_equivocator_is_not_an_era_validator() {
+        let result = ConfiguredEraSchedule::new(SimulatorConfig {
+            equivocations: vec![EquivocationConfig {
+                era_id: 2,
+                validator_index: 1,
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            result.err(),
+            Some(SimulatorError::EquivocatorIsNotEraValidator {
+                era_id: 2,
+                validator_index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_if_too_few_validators_remain_after_equivocations() {
+        let result = ConfiguredEraSchedule::new(SimulatorConfig {
+            equivocations: vec![
+                EquivocationConfig {
+                    era_id: 1,
+                    validator_index: 0,
+                },
+                EquivocationConfig {
+                    era_id: 1,
+                    validator_index: 3,
+                },
+                EquivocationConfig {
+                    era_id: 2,
+                    validator_index: 5,
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(result.err(), Some(SimulatorError::InvalidEraSize(4)));
+    }
+
+    #[test]
+    fn test_error_if_equivocator_index_is_outside_validator_pool() {
+        let result = ConfiguredEraSchedule::new(SimulatorConfig {
+            equivocations: vec![EquivocationConfig {
+                era_id: 2,
+                validator_index: 6,
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            result.err(),
+            Some(SimulatorError::InvalidEquivocatorIndex(6))
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    config::{SimulatorConfig, SimulatorError},
+    schedule::ConfiguredEraSchedule,
+};
+use camino::{Utf8Path, Utf8PathBuf};
+use ltypeset::chain::{BlockWithProofs, ChainNameDigest};
+use ltypeset_utils::generator::ChainGenerator;
+use std::{fs, io::Error};
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Simulates a deterministic synthetic chain as parameterised by a simulator configuration.
+pub struct Simulator {
+    /// Simulator configuration.
+    config: SimulatorConfig,
+
+    /// Generator of synthetic blocks.
+    generator: ChainGenerator,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl Simulator {
+    /// Constructor: returns a simulator whose next block is a genesis block.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Simulator configuration.
+    ///
+    pub fn new(config: SimulatorConfig) -> Result<Self, SimulatorError> {
+        Ok(Self {
+            generator: ChainGenerator::new_with_schedule(
+                config.name_of_chain(),
+                config.era_length(),
+                Box::new(ConfiguredEraSchedule::new(config.clone())?),
+            ),
+            config,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl Simulator {
+    pub fn chain_name_digest(&self) -> ChainNameDigest {
+        self.generator.chain_name_digest()
+    }
+
+    pub fn config(&self) -> &SimulatorConfig {
+        &self.config
+    }
+
+    pub fn generator(&self) -> &ChainGenerator {
+        &self.generator
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl Simulator {
+    /// Generates next set of blocks & writes each to a directory in the layout expected by a
+    /// file system fetcher.
+    ///
+    /// # Arguments
+    ///
+    /// * `path_to_root` - Path to directory to which blocks are written.
+    /// * `count` - Number of blocks to generate.
+    ///
+    pub fn write_chain(
+        &mut self,
+        path_to_root: &Utf8Path,
+        count: usize,
+    ) -> Result<Vec<BlockWithProofs>, Error> {
+        fs::create_dir_all(path_to_root)?;
+
+        self.by_ref()
+            .take(count)
+            .map(|block_with_proofs| {
+                write_block_with_proofs(path_to_root, &block_with_proofs)?;
+                Ok(block_with_proofs)
+            })
+            .collect()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl Iterator for Simulator {
+    type Item = BlockWithProofs;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.generator.next_block_with_proofs())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns name of file to which a block is written, i.e. block-{height}-{hash}.json.
+///
+/// # Arguments
+///
+/// * `block_with_proofs` - A block plus associated finality signatures.
+///
+pub fn get_fname_of_block(block_with_proofs: &BlockWithProofs) -> String {
+    let block = block_with_proofs.block();
+
+    format!(
+        "block-{:02}-{}.json",
+        block.height().inner(),
+        hex::encode(block.hash().inner().as_slice())
+    )
+}
+
+/// Writes a block, encoded as JSON, to a directory & returns path to written file.
+///
+/// # Arguments
+///
+/// * `path_to_root` - Path to directory to which block is written.
+/// * `block_with_proofs` - A block plus associated finality signatures.
+///
+pub fn write_block_with_proofs(
+    path_to_root: &Utf8Path,
+    block_with_proofs: &BlockWithProofs,
+) -> Result<Utf8PathBuf, Error> {
+    let path_to_file = path_to_root.join(get_fname_of_block(block_with_proofs));
+    fs::write(
+        &path_to_file,
+        serde_json::to_string_pretty(block_with_proofs).unwrap(),
+    )?;
+
+    Ok(path_to_file)
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EquivocationConfig, ProtocolUpgradeConfig};
+    use ltypeset::{
+        chain::{Block, EraId, ProtocolVersion},
+        primitives::SemanticVersion,
+    };
+    use lverifiers::{
+        get_trusted_era_consensus_info, verify_chain_v2_with_proofs, FinalityThreshold,
+    };
+    use rand::Rng;
+    use std::env;
+
+    fn get_config() -> SimulatorConfig {
+        SimulatorConfig {
+            equivocations: vec![EquivocationConfig {
+                era_id: 2,
+                validator_index: 3,
+            }],
+            protocol_upgrades: vec![ProtocolUpgradeConfig {
+                era_id: 3,
+                protocol_version: ProtocolVersion::new(SemanticVersion::new(2, 1, 0)),
+            }],
+            validator_weights: vec![1, 2, 3, 4, 5, 6],
+            ..Default::default()
+        }
+    }
+
+    fn get_path_to_root() -> Utf8PathBuf {
+        Utf8PathBuf::from_path_buf(env::temp_dir())
+            .unwrap()
+            .join(format!("lsimulator-{}", rand::thread_rng().gen::<u64>()))
+    }
+
+    #[test]
+    fn test_that_chain_is_deterministic() {
+        let chain: Vec<BlockWithProofs> = Simulator::new(get_config()).unwrap().take(24).collect();
+
+        assert_eq!(
+            chain,
+            Simulator::new(get_config())
+                .unwrap()
+                .take(24)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_that_chain_of_any_length_is_verified() {
+        let mut simulator = Simulator::new(get_config()).unwrap();
+        let mut chain: Vec<BlockWithProofs> = simulator.by_ref().take(64).collect();
+        let trusted = chain.remove(0);
+        let (tip, era_consensus_info) = verify_chain_v2_with_proofs(
+            trusted.clone(),
+            get_trusted_era_consensus_info(&trusted, &trusted).unwrap(),
+            chain,
+            simulator.chain_name_digest(),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert_eq!(tip.block().height().inner(), 63);
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(7));
+        match tip.block() {
+            Block::V2(inner) => assert_eq!(
+                inner.header().protocol_version(),
+                &ProtocolVersion::new(SemanticVersion::new(2, 1, 0))
+            ),
+            Block::V1(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_that_chain_is_written_in_file_system_fetcher_layout() {
+        let path_to_root = get_path_to_root();
+        let chain = Simulator::new(get_config())
+            .unwrap()
+            .write_chain(&path_to_root, 12)
+            .unwrap();
+
+        assert_eq!(fs::read_dir(&path_to_root).unwrap().count(), 12);
+        for block_with_proofs in chain {
+            let path_to_file = path_to_root.join(get_fname_of_block(&block_with_proofs));
+            let decoded: BlockWithProofs =
+                serde_json::from_str(&fs::read_to_string(path_to_file).unwrap()).unwrap();
+            assert_eq!(decoded, block_with_proofs);
+        }
+        fs::remove_dir_all(path_to_root).unwrap();
+    }
+}
@@ -1,5 +1,8 @@
 use lcodecs::binary::Encode;
-use ltypeset::chain::{Block, ChainNameDigest};
+use ltypeset::{
+    chain::{Block, BlockHash, ChainNameDigest, EraConsensusInfo},
+    crypto::Digest,
+};
 
 /// Returns a set of bytes for computing a block digest.
 ///
@@ -10,8 +13,21 @@ use ltypeset::chain::{Block, ChainNameDigest};
 pub fn get_digest_bytes_for_block(block: &Block) -> Vec<u8> {
     let mut result: Vec<u8> = Vec::new();
     match block {
-        Block::V1(_) => {
-            unimplemented!()
+        Block::V1(inner) => {
+            for encoded in [
+                inner.header().parent_hash().encode(),
+                inner.header().state_root_hash().encode(),
+                inner.header().body_hash().encode(),
+                inner.header().random_bit().encode(),
+                inner.header().accumulated_seed().encode(),
+                inner.header().era_end().encode(),
+                inner.header().timestamp().encode(),
+                inner.header().era_id().encode(),
+                inner.header().height().encode(),
+                inner.header().protocol_version().encode(),
+            ] {
+                result.extend_from_slice(encoded.unwrap().as_slice());
+            }
         }
         Block::V2(inner) => {
             for encoded in [
@@ -20,14 +36,14 @@ pub fn get_digest_bytes_for_block(block: &Block) -> Vec<u8> {
                 inner.header().body_hash().encode(),
                 inner.header().random_bit().encode(),
                 inner.header().accumulated_seed().encode(),
-                // inner.header().era_end().encode(),
+                inner.header().era_end().encode(),
                 inner.header().timestamp().encode(),
                 inner.header().era_id().encode(),
                 inner.header().height().encode(),
-                // inner.header().protocol_version().encode(),
+                inner.header().protocol_version().encode(),
                 inner.header().proposer().encode(),
                 inner.header().current_gas_price().encode(),
-                // inner.header().last_switch_block_hash().encode(),
+                inner.header().last_switch_block_hash().encode(),
             ] {
                 result.extend_from_slice(encoded.unwrap().as_slice());
             }
@@ -37,6 +53,77 @@ pub fn get_digest_bytes_for_block(block: &Block) -> Vec<u8> {
     result
 }
 
+/// Returns a set of bytes for computing a block body digest.
+///
+/// # Arguments
+///
+/// * `block` - Block over whose body a message will be computed for subsequent mapping to a digest.
+///
+pub fn get_digest_bytes_for_block_body(block: &Block) -> Vec<u8> {
+    match block {
+        Block::V1(inner) => inner.body().encode().unwrap(),
+        Block::V2(inner) => inner.body().encode().unwrap(),
+    }
+}
+
+/// Returns recomputed hash of a block.
+///
+/// # Arguments
+///
+/// * `block` - Block whose hash will be recomputed.
+///
+pub fn get_hash_for_block(block: &Block) -> BlockHash {
+    BlockHash::new(Digest::from(
+        lcrypto::get_hash_blake2b(get_digest_bytes_for_block(block)).as_slice(),
+    ))
+}
+
+/// Returns recomputed hash of a block's body.
+///
+/// # Arguments
+///
+/// * `block` - Block whose body hash will be recomputed.
+///
+pub fn get_hash_for_block_body(block: &Block) -> Digest {
+    Digest::from(lcrypto::get_hash_blake2b(get_digest_bytes_for_block_body(block)).as_slice())
+}
+
+/// Returns a set of bytes for computing a digest over an era's validator set.
+///
+/// N.B. Validator weights are ordered by validator so that digest is independent of declaration order.
+///
+/// # Arguments
+///
+/// * `era_consensus_info` - Era validator set plus respective weights.
+///
+pub fn get_digest_bytes_for_era_consensus_info(era_consensus_info: &EraConsensusInfo) -> Vec<u8> {
+    let mut validator_weights = era_consensus_info.validator_weights().to_owned();
+    validator_weights.sort_by_key(|vw| vw.validator_id());
+
+    let mut result: Vec<u8> = Vec::new();
+    for encoded in [
+        era_consensus_info.era_id().encode(),
+        validator_weights.encode(),
+    ] {
+        result.extend_from_slice(encoded.unwrap().as_slice());
+    }
+
+    result
+}
+
+/// Returns digest over an era's validator set, i.e. that against which finality signatures were tallied.
+///
+/// # Arguments
+///
+/// * `era_consensus_info` - Era validator set plus respective weights.
+///
+pub fn get_hash_for_era_consensus_info(era_consensus_info: &EraConsensusInfo) -> Digest {
+    Digest::from(
+        lcrypto::get_hash_blake2b(get_digest_bytes_for_era_consensus_info(era_consensus_info))
+            .as_slice(),
+    )
+}
+
 /// Returns a set of bytes for computing a block finality signature.
 ///
 /// # Arguments
@@ -50,10 +137,9 @@ pub fn get_signature_bytes_for_block_finality(
 ) -> Vec<u8> {
     let mut result: Vec<u8> = Vec::new();
     for encoded in match block {
-        Block::V1(_) => {
-            unimplemented!()
-        }
-        Block::V2(inner) => [
+        // N.B. prior to protocol version 2.0 finality signatures were not scoped by chain name.
+        Block::V1(inner) => vec![inner.hash().encode(), inner.header().era_id().encode()],
+        Block::V2(inner) => vec![
             inner.hash().encode(),
             inner.header().height().encode(),
             inner.header().era_id().encode(),
@@ -66,13 +152,86 @@ pub fn get_signature_bytes_for_block_finality(
     result
 }
 
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::get_set_of_blocks_with_proofs;
+    use ltypeset::chain::EraId;
+
+    fn get_blocks() -> Vec<Block> {
+        get_set_of_blocks_with_proofs()
+            .iter()
+            .map(|block_with_proofs| block_with_proofs.block().to_owned())
+            .collect()
+    }
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn test_block_hash_can_be_recomputed() {
+        for block in get_blocks() {
+            assert_eq!(&get_hash_for_block(&block), block.hash());
+        }
+    }
+
+    #[test]
+    fn test_block_hash_can_be_recomputed_for_switch_block() {
+        let blocks = get_blocks();
+        let switch_blocks: Vec<&Block> = blocks
+            .iter()
+            .filter(|b| matches!(b, Block::V2(inner) if inner.header().era_end().is_some()))
+            .collect();
+
+        assert!(!switch_blocks.is_empty());
+        for block in switch_blocks {
+            assert_eq!(&get_hash_for_block(block), block.hash());
+        }
+    }
+
+    #[test]
+    fn test_block_body_hash_can_be_recomputed() {
+        for block in get_blocks() {
+            match &block {
+                Block::V2(inner) => {
+                    assert_eq!(&get_hash_for_block_body(&block), inner.header().body_hash());
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_era_consensus_info_hash_is_independent_of_validator_order() {
+        let blocks = get_blocks();
+        let era_end = blocks
+            .iter()
+            .find_map(|b| match b {
+                Block::V2(inner) => inner.header().era_end().to_owned(),
+                _ => None,
+            })
+            .unwrap();
+        let validator_weights = era_end.next_era_validator_weights().to_owned();
+        let mut reversed = validator_weights.clone();
+        reversed.reverse();
+        let era_consensus_info = EraConsensusInfo::new(EraId::new(2), validator_weights).unwrap();
+
+        assert_eq!(
+            get_hash_for_era_consensus_info(&era_consensus_info),
+            get_hash_for_era_consensus_info(
+                &EraConsensusInfo::new(EraId::new(2), reversed).unwrap()
+            )
+        );
+        assert_ne!(
+            get_hash_for_era_consensus_info(&era_consensus_info),
+            get_hash_for_era_consensus_info(
+                &EraConsensusInfo::new(
+                    EraId::new(3),
+                    era_consensus_info.validator_weights().to_owned()
+                )
+                .unwrap()
+            )
+        );
     }
 }
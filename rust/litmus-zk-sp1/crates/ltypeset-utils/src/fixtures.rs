@@ -0,0 +1,90 @@
+//! Chain fixtures, i.e. blocks issued by a Casper node, as found beneath `resources/chain/blocks`.
+
+use ltypeset::chain::{Block, BlockWithProofs, EraConsensusInfo, EraId};
+use std::{fs, path::PathBuf};
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns path to directory of chain fixtures, i.e. one block-{height}-{hash}.json file per block.
+pub fn get_path_to_chain_fixtures() -> &'static str {
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../resources/chain/blocks")
+}
+
+/// Returns a block with proofs loaded from chain fixtures.
+///
+/// # Arguments
+///
+/// * `height` - Height of a block within chain fixtures.
+///
+pub fn get_block_with_proofs(height: u64) -> BlockWithProofs {
+    let path_to_file = get_paths_to_block_files()
+        .into_iter()
+        .find(|(h, _)| *h == height)
+        .map(|(_, path_to_file)| path_to_file)
+        .unwrap_or_else(|| panic!("no chain fixture at height {height}"));
+
+    read_block_file(path_to_file)
+}
+
+/// Returns set of blocks with proofs loaded from chain fixtures, ordered by height.
+pub fn get_set_of_blocks_with_proofs() -> Vec<BlockWithProofs> {
+    get_paths_to_block_files()
+        .into_iter()
+        .map(|(_, path_to_file)| read_block_file(path_to_file))
+        .collect()
+}
+
+/// Returns validator set declared by a switch block loaded from chain fixtures, i.e. that in force
+/// for the era following that of switch block.
+///
+/// # Arguments
+///
+/// * `height` - Height of a switch block within chain fixtures.
+///
+pub fn get_era_consensus_info(height: u64) -> EraConsensusInfo {
+    match get_block_with_proofs(height).block() {
+        Block::V2(inner) => EraConsensusInfo::new(
+            EraId::new(inner.header().era_id().inner() + 1),
+            inner
+                .header()
+                .era_end()
+                .as_ref()
+                .unwrap_or_else(|| panic!("chain fixture at height {height} is not a switch block"))
+                .next_era_validator_weights()
+                .to_owned(),
+        )
+        .unwrap(),
+        Block::V1(_) => unreachable!("chain fixtures are version two blocks"),
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Returns paths to block files keyed by block height, ordered by height.
+fn get_paths_to_block_files() -> Vec<(u64, PathBuf)> {
+    let mut result: Vec<(u64, PathBuf)> = fs::read_dir(get_path_to_chain_fixtures())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter_map(|path_to_file| {
+            let fname = path_to_file.file_name()?.to_str()?;
+            let height = fname
+                .strip_prefix("block-")?
+                .split('-')
+                .next()?
+                .parse()
+                .ok()?;
+            Some((height, path_to_file))
+        })
+        .collect();
+    result.sort_by_key(|(height, _)| *height);
+
+    result
+}
+
+fn read_block_file(path_to_file: PathBuf) -> BlockWithProofs {
+    serde_json::from_str(&fs::read_to_string(path_to_file).unwrap()).unwrap()
+}
@@ -0,0 +1,530 @@
+use super::crypto::{get_hash_for_block, get_hash_for_block_body};
+use ltypeset::{
+    chain::{
+        Block, BlockHash, BlockHeight, BlockSignature, BlockV2, BlockV2Body, BlockV2Header,
+        BlockWithProofs, ChainNameDigest, EraEndV2, EraId, Motes, ProtocolVersion, ValidatorWeight,
+    },
+    crypto::{Digest, SigningKey, VerificationKey},
+    primitives::{bites::Bytes32, SemanticVersion, Timestamp},
+};
+use std::collections::BTreeMap;
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Gas price declared by each block.
+const GAS_PRICE: u8 = 1;
+
+// Milliseconds between successive blocks.
+const BLOCK_INTERVAL_MS: u128 = 8_192;
+
+// Timestamp of genesis block, i.e. 2024-01-01T00:00:00Z.
+const GENESIS_TIMESTAMP_MS: u128 = 1_704_067_200_000;
+
+// Weight of validator at first position within an era validator set.
+const VALIDATOR_WEIGHT_UNIT: u64 = 1_000_000_000;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Generates a synthetic chain of version two blocks, each signed by validator set of its era.
+///
+/// N.B. Genesis block, plus every `era_length`th block thereafter, is a switch block whose
+/// `EraEndV2` declares next era's validator set. Era validator sets, equivocators & protocol
+/// versions are determined by an era schedule.
+pub struct ChainGenerator {
+    /// Digest over name of synthetic chain.
+    chain_name_digest: ChainNameDigest,
+
+    /// Number of blocks per era, i.e. distance between switch blocks.
+    era_length: u64,
+
+    /// Hash of most recently generated switch block.
+    last_switch_block_hash: Option<BlockHash>,
+
+    /// Schedule determining each era's validator set, equivocators & protocol version.
+    schedule: Box<dyn EraSchedule>,
+
+    /// Most recently generated block.
+    tip: Option<BlockWithProofs>,
+}
+
+/// Determines validator set, equivocators & protocol version of each era of a synthetic chain.
+pub trait EraSchedule {
+    /// Returns signing keys of an era's validator set plus respective weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era whose validator set is to be returned.
+    ///
+    fn get_era_validators(&self, era_id: &EraId) -> Vec<(SigningKey, Motes)>;
+
+    /// Returns validators reported as equivocators by an era's switch block.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era whose equivocators are to be returned.
+    ///
+    fn get_era_equivocators(&self, _era_id: &EraId) -> Vec<VerificationKey> {
+        vec![]
+    }
+
+    /// Returns protocol version declared by an era's blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era whose protocol version is to be returned.
+    ///
+    fn get_protocol_version(&self, _era_id: &EraId) -> ProtocolVersion {
+        ProtocolVersion::new(SemanticVersion::new(2, 0, 0))
+    }
+}
+
+/// Era schedule whose validator sets are rotated by one signing key per era through a pool of
+/// signing keys. Weights increase with position within an era validator set.
+pub struct RotatingEraSchedule {
+    /// Number of validators per era.
+    era_size: usize,
+
+    /// Pool of signing keys from which era validator sets are drawn.
+    signing_keys: Vec<SigningKey>,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl ChainGenerator {
+    /// Constructor: returns a generator, rotating validators through a pool of signing keys,
+    /// whose next block is a genesis block.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_name` - Name of synthetic chain.
+    /// * `era_length` - Number of blocks per era, i.e. distance between switch blocks.
+    /// * `era_size` - Number of validators per era.
+    /// * `signing_keys` - Pool of signing keys from which era validator sets are drawn.
+    ///
+    pub fn new(
+        chain_name: &str,
+        era_length: u64,
+        era_size: usize,
+        signing_keys: Vec<SigningKey>,
+    ) -> Self {
+        Self::new_with_schedule(
+            chain_name,
+            era_length,
+            Box::new(RotatingEraSchedule::new(era_size, signing_keys)),
+        )
+    }
+
+    /// Constructor: returns a generator whose next block is a genesis block.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_name` - Name of synthetic chain.
+    /// * `era_length` - Number of blocks per era, i.e. distance between switch blocks.
+    /// * `schedule` - Schedule determining each era's validator set, equivocators & protocol version.
+    ///
+    pub fn new_with_schedule(
+        chain_name: &str,
+        era_length: u64,
+        schedule: Box<dyn EraSchedule>,
+    ) -> Self {
+        assert!(era_length > 0, "Invalid era length");
+
+        Self {
+            chain_name_digest: ChainNameDigest::new_from_chain_name(chain_name),
+            era_length,
+            last_switch_block_hash: None,
+            schedule,
+            tip: None,
+        }
+    }
+}
+
+impl RotatingEraSchedule {
+    /// Constructor: returns a schedule rotating validators through a pool of signing keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_size` - Number of validators per era.
+    /// * `signing_keys` - Pool of signing keys from which era validator sets are drawn.
+    ///
+    pub fn new(era_size: usize, signing_keys: Vec<SigningKey>) -> Self {
+        assert!(
+            era_size > 0 && era_size <= signing_keys.len(),
+            "Invalid era size"
+        );
+
+        Self {
+            era_size,
+            signing_keys,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl ChainGenerator {
+    pub fn chain_name_digest(&self) -> ChainNameDigest {
+        self.chain_name_digest
+    }
+
+    pub fn era_length(&self) -> u64 {
+        self.era_length
+    }
+
+    pub fn tip(&self) -> Option<&BlockWithProofs> {
+        self.tip.as_ref()
+    }
+}
+
+impl RotatingEraSchedule {
+    pub fn signing_keys(&self) -> &[SigningKey] {
+        &self.signing_keys
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl ChainGenerator {
+    /// Returns id of era within which a block at a particular height is produced.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of a block.
+    ///
+    pub fn get_era_id(&self, height: u64) -> EraId {
+        match height {
+            0 => EraId::new(0),
+            _ => EraId::new((height - 1) / self.era_length + 1),
+        }
+    }
+
+    /// Returns signing keys of an era's validator set.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era whose validator set is to be returned.
+    ///
+    pub fn get_era_signing_keys(&self, era_id: &EraId) -> Vec<SigningKey> {
+        self.schedule
+            .get_era_validators(era_id)
+            .into_iter()
+            .map(|(signing_key, _)| signing_key)
+            .collect()
+    }
+
+    /// Returns an era's validator set plus respective weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era whose validator set is to be returned.
+    ///
+    pub fn get_era_validator_weights(&self, era_id: &EraId) -> Vec<ValidatorWeight> {
+        self.schedule
+            .get_era_validators(era_id)
+            .iter()
+            .map(|(signing_key, weight)| {
+                ValidatorWeight::new(signing_key.verification_key(), *weight)
+            })
+            .collect()
+    }
+
+    /// Predicate: is a block at a particular height a switch block ?
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of a block.
+    ///
+    pub fn is_switch_block(&self, height: u64) -> bool {
+        height % self.era_length == 0
+    }
+
+    /// Generates next block, signed by each validator of its era, & moves tip to it.
+    pub fn next_block_with_proofs(&mut self) -> BlockWithProofs {
+        let height = match &self.tip {
+            Some(tip) => tip.block().height().inner() + 1,
+            None => 0,
+        };
+        let era_id = self.get_era_id(height);
+
+        // Set block.
+        let block = self.get_block(height, era_id);
+        let msg = match &block {
+            Block::V2(inner) => inner.get_bytes_for_finality_signature(&self.chain_name_digest),
+            Block::V1(_) => unreachable!(),
+        };
+
+        // Set finality signatures.
+        let proofs = self
+            .get_era_signing_keys(&era_id)
+            .iter()
+            .map(|signing_key| {
+                BlockSignature::new(signing_key.sign(&msg), signing_key.verification_key())
+            })
+            .collect();
+
+        // Move tip.
+        if self.is_switch_block(height) {
+            self.last_switch_block_hash = Some(*block.hash());
+        }
+        self.tip = Some(BlockWithProofs::new(block, proofs));
+
+        self.tip.clone().unwrap()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl EraSchedule for RotatingEraSchedule {
+    fn get_era_validators(&self, era_id: &EraId) -> Vec<(SigningKey, Motes)> {
+        (0..self.era_size)
+            .map(|idx| {
+                (
+                    self.signing_keys[(era_id.inner() as usize + idx) % self.signing_keys.len()]
+                        .clone(),
+                    Motes::new(VALIDATOR_WEIGHT_UNIT * (idx as u64 + 1)),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Iterator for ChainGenerator {
+    type Item = BlockWithProofs;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_block_with_proofs())
+    }
+}
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns a pool of signing keys, alternating between ed25519 & secp256k1 keys.
+///
+/// # Arguments
+///
+/// * `count` - Number of signing keys to return.
+///
+pub fn get_signing_keys(count: usize) -> Vec<SigningKey> {
+    (0..count)
+        .map(|idx| {
+            let seed = (idx as u64).to_le_bytes();
+            match idx % 2 {
+                0 => SigningKey::new_ed25519_from_seed(&seed),
+                _ => SigningKey::new_secp256k1_from_seed(&seed),
+            }
+        })
+        .collect()
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+impl ChainGenerator {
+    // Returns a hashed block at a particular height.
+    fn get_block(&self, height: u64, era_id: EraId) -> Block {
+        let era_end = match self.is_switch_block(height) {
+            true => Some(EraEndV2::new(
+                self.schedule.get_era_equivocators(&era_id),
+                vec![],
+                self.get_era_validator_weights(&EraId::new(era_id.inner() + 1)),
+                BTreeMap::new(),
+                GAS_PRICE,
+            )),
+            false => None,
+        };
+        let parent_hash = match &self.tip {
+            Some(tip) => *tip.block().hash(),
+            None => BlockHash::new(Digest::new(Bytes32::new([0_u8; 32]))),
+        };
+        let proposer = {
+            let signing_keys = self.get_era_signing_keys(&era_id);
+            signing_keys[height as usize % signing_keys.len()].verification_key()
+        };
+        let seed = height.to_le_bytes().to_vec();
+        let zero_digest = Digest::new(Bytes32::new([0_u8; 32]));
+
+        // N.B. body & block hashes are computed over a block whose hashes are yet to be set.
+        let body = BlockV2Body::new(vec![], BTreeMap::new());
+        let get_header = |body_hash: Digest| {
+            BlockV2Header::new(
+                Digest::get_blake2b([b"seed".to_vec(), seed.clone()].concat()),
+                body_hash,
+                GAS_PRICE,
+                era_end.clone(),
+                era_id,
+                BlockHeight::new(height),
+                self.last_switch_block_hash,
+                parent_hash,
+                proposer,
+                self.schedule.get_protocol_version(&era_id),
+                height % 2 == 0,
+                Digest::get_blake2b([b"state".to_vec(), seed.clone()].concat()),
+                Timestamp::new(GENESIS_TIMESTAMP_MS + height as u128 * BLOCK_INTERVAL_MS),
+            )
+        };
+        let get_unhashed_block = |header: BlockV2Header| {
+            Block::new_v2(BlockV2::new(
+                body.clone(),
+                BlockHash::new(zero_digest),
+                header,
+            ))
+        };
+        let header = get_header(get_hash_for_block_body(&get_unhashed_block(get_header(
+            zero_digest,
+        ))));
+        let hash = get_hash_for_block(&get_unhashed_block(header.clone()));
+
+        Block::new_v2(BlockV2::new(body, hash, header))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAIN_NAME: &str = "cspr-synthetic";
+
+    fn get_generator() -> ChainGenerator {
+        ChainGenerator::new(CHAIN_NAME, 5, 3, get_signing_keys(4))
+    }
+
+    struct UpgradingEraSchedule(RotatingEraSchedule);
+
+    impl EraSchedule for UpgradingEraSchedule {
+        fn get_era_validators(&self, era_id: &EraId) -> Vec<(SigningKey, Motes)> {
+            self.0.get_era_validators(era_id)
+        }
+
+        fn get_era_equivocators(&self, era_id: &EraId) -> Vec<VerificationKey> {
+            match era_id.inner() {
+                1 => vec![self.0.signing_keys()[1].verification_key()],
+                _ => vec![],
+            }
+        }
+
+        fn get_protocol_version(&self, era_id: &EraId) -> ProtocolVersion {
+            ProtocolVersion::new(SemanticVersion::new(2, era_id.inner() as u32, 0))
+        }
+    }
+
+    fn get_header(block_with_proofs: &BlockWithProofs) -> &BlockV2Header {
+        match block_with_proofs.block() {
+            Block::V2(inner) => inner.header(),
+            Block::V1(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_block_hashes_can_be_recomputed() {
+        for block_with_proofs in get_generator().take(12) {
+            let block = block_with_proofs.block();
+            assert_eq!(&get_hash_for_block(block), block.hash());
+            assert_eq!(
+                &get_hash_for_block_body(block),
+                get_header(&block_with_proofs).body_hash()
+            );
+        }
+    }
+
+    #[test]
+    fn test_blocks_are_chained() {
+        let blocks: Vec<BlockWithProofs> = get_generator().take(12).collect();
+        for (parent, child) in blocks.iter().zip(blocks.iter().skip(1)) {
+            assert_eq!(get_header(child).parent_hash(), parent.block().hash());
+            assert_eq!(
+                get_header(child).height().inner(),
+                get_header(parent).height().inner() + 1
+            );
+        }
+        assert_eq!(
+            get_header(&blocks[7]).last_switch_block_hash(),
+            &Some(*blocks[5].block().hash())
+        );
+    }
+
+    #[test]
+    fn test_switch_blocks_rotate_validator_set() {
+        let generator = get_generator();
+        let blocks: Vec<BlockWithProofs> = get_generator().take(12).collect();
+        for (height, block_with_proofs) in blocks.iter().enumerate() {
+            let header = get_header(block_with_proofs);
+            assert_eq!(header.era_end().is_some(), [0, 5, 10].contains(&height));
+            if let Some(era_end) = header.era_end() {
+                assert_eq!(
+                    era_end.next_era_validator_weights(),
+                    &generator.get_era_validator_weights(&EraId::new(header.era_id().inner() + 1))
+                );
+            }
+        }
+        assert_ne!(
+            generator.get_era_validator_weights(&EraId::new(1)),
+            generator.get_era_validator_weights(&EraId::new(2))
+        );
+    }
+
+    #[test]
+    fn test_finality_signatures_are_issued_by_era_validators() {
+        let generator = get_generator();
+        let chain_name_digest = ChainNameDigest::new_from_chain_name(CHAIN_NAME);
+        for block_with_proofs in get_generator().take(12) {
+            let msg = match block_with_proofs.block() {
+                Block::V2(inner) => inner.get_bytes_for_finality_signature(&chain_name_digest),
+                Block::V1(_) => unreachable!(),
+            };
+            let era_validator_weights =
+                generator.get_era_validator_weights(get_header(&block_with_proofs).era_id());
+            assert_eq!(
+                block_with_proofs.proofs().len(),
+                era_validator_weights.len()
+            );
+            for (proof, validator_weight) in
+                block_with_proofs.proofs().iter().zip(era_validator_weights)
+            {
+                assert_eq!(proof.verification_key(), &validator_weight.validator_id());
+                assert_eq!(
+                    proof.signature().verify(proof.verification_key(), &msg),
+                    Ok(())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_schedule_determines_equivocators_and_protocol_version() {
+        let schedule = UpgradingEraSchedule(RotatingEraSchedule::new(3, get_signing_keys(4)));
+        let equivocator = schedule.0.signing_keys()[1].verification_key();
+        let generator = ChainGenerator::new_with_schedule(CHAIN_NAME, 5, Box::new(schedule));
+        for block_with_proofs in generator.take(12) {
+            let header = get_header(&block_with_proofs);
+            assert_eq!(
+                header.protocol_version().inner(),
+                &SemanticVersion::new(2, header.era_id().inner() as u32, 0)
+            );
+            if let Some(era_end) = header.era_end() {
+                assert_eq!(
+                    era_end.equivocators().contains(&equivocator),
+                    header.era_id().inner() == 1
+                );
+            }
+        }
+    }
+}
@@ -1 +1,6 @@
 pub mod crypto;
+#[cfg(any(feature = "testing", test))]
+pub mod fixtures;
+#[cfg(any(feature = "testing", test))]
+pub mod generator;
+pub mod trie;
@@ -0,0 +1,138 @@
+use lcodecs::binary::Encode;
+use ltypeset::{
+    crypto::Digest,
+    trie::{Pointer, Trie, TrieMerkleProof},
+    verification::VerificationError,
+};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Size above which Casper digests a trie node as a merkle tree of chunks.
+const CHUNK_SIZE_BYTES: usize = 8 * 1024 * 1024;
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns digest over a global state trie node.
+///
+/// N.B. Chunked hashing of nodes larger than 8 MiB is unsupported, i.e. such nodes are rejected.
+///
+/// # Arguments
+///
+/// * `trie` - A global state trie node.
+///
+pub fn get_hash_for_trie(trie: &Trie) -> Result<Digest, VerificationError> {
+    let encoded = trie
+        .encode()
+        .map_err(|_| VerificationError::InvalidTrieNode)?;
+    if encoded.len() > CHUNK_SIZE_BYTES {
+        return Err(VerificationError::InvalidTrieNode);
+    }
+
+    Ok(Digest::from(lcrypto::get_hash_blake2b(encoded).as_slice()))
+}
+
+/// Returns state root hash recomputed by walking a merkle proof from leaf up to trie root.
+///
+/// # Arguments
+///
+/// * `proof` - Proof that a key/value pair is stored within global state.
+///
+pub fn get_state_root_hash_for_proof(proof: &TrieMerkleProof) -> Result<Digest, VerificationError> {
+    let mut digest = get_hash_for_trie(&proof.get_leaf())?;
+    for (idx, proof_step) in proof.proof_steps().iter().enumerate() {
+        let pointer = match idx {
+            0 => Pointer::Leaf(digest),
+            _ => Pointer::Node(digest),
+        };
+        digest = get_hash_for_trie(&proof_step.get_parent(pointer)?)?;
+    }
+
+    Ok(digest)
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::trie::{TrieMerkleProofStep, TRIE_RADIX};
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_proof(value: Vec<u8>) -> TrieMerkleProof {
+        TrieMerkleProof::new(
+            vec![1, 2, 3],
+            vec![
+                TrieMerkleProofStep::Node {
+                    hole_index: 1,
+                    indexed_pointers_with_hole: vec![(0, Pointer::Node(Digest::from(DIGEST_HEX)))],
+                },
+                TrieMerkleProofStep::Extension { affix: vec![4, 5] },
+            ],
+            value,
+        )
+    }
+
+    #[test]
+    fn test_state_root_hash_can_be_recomputed() {
+        // Leaf: tag || key || value.
+        let leaf_hash = lcrypto::get_hash_blake2b(vec![0, 1, 2, 3, 6]);
+
+        // Node: tag || 256 optional pointers with leaf pointer at hole index.
+        let mut node = vec![1, 1, 1];
+        node.extend_from_slice(Digest::from(DIGEST_HEX).as_slice());
+        node.extend_from_slice(&[1, 0]);
+        node.extend_from_slice(&leaf_hash);
+        node.extend_from_slice(&vec![0; TRIE_RADIX - 2]);
+        let node_hash = lcrypto::get_hash_blake2b(node);
+
+        // Extension: tag || affix || node pointer.
+        let mut extension = vec![2, 2, 0, 0, 0, 4, 5, 1];
+        extension.extend_from_slice(&node_hash);
+        let root_hash = lcrypto::get_hash_blake2b(extension);
+
+        assert_eq!(
+            get_state_root_hash_for_proof(&get_proof(vec![6])),
+            Ok(Digest::from(root_hash.as_slice()))
+        );
+    }
+
+    #[test]
+    fn test_state_root_hash_changes_with_value() {
+        assert_ne!(
+            get_state_root_hash_for_proof(&get_proof(vec![6])),
+            get_state_root_hash_for_proof(&get_proof(vec![7]))
+        );
+    }
+
+    #[test]
+    fn test_error_if_proof_step_hole_is_occupied() {
+        let proof = TrieMerkleProof::new(
+            vec![1, 2, 3],
+            vec![TrieMerkleProofStep::Node {
+                hole_index: 0,
+                indexed_pointers_with_hole: vec![(0, Pointer::Node(Digest::from(DIGEST_HEX)))],
+            }],
+            vec![6],
+        );
+
+        assert_eq!(
+            get_state_root_hash_for_proof(&proof),
+            Err(VerificationError::InvalidTrieNode)
+        );
+    }
+
+    #[test]
+    fn test_error_if_trie_node_exceeds_chunk_size() {
+        assert_eq!(
+            get_hash_for_trie(&Trie::new_leaf(vec![1], vec![0; CHUNK_SIZE_BYTES])),
+            Err(VerificationError::InvalidTrieNode)
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use super::{BlockHash, BlockV1, BlockV2};
+use super::{BlockHash, BlockHeight, BlockV1, BlockV2, EraId};
+use crate::crypto::Digest;
 use serde::{Deserialize, Serialize};
 
 // ------------------------------------------------------------------------
@@ -32,12 +33,40 @@ impl Block {
 // ------------------------------------------------------------------------
 
 impl Block {
+    pub fn era_id(&self) -> EraId {
+        match self {
+            Block::V1(inner) => inner.header().era_id().to_owned(),
+            Block::V2(inner) => inner.header().era_id().to_owned(),
+        }
+    }
+
     pub fn hash(&self) -> &BlockHash {
         match self {
             Block::V1(inner) => inner.hash(),
             Block::V2(inner) => inner.hash(),
         }
     }
+
+    pub fn height(&self) -> BlockHeight {
+        match self {
+            Block::V1(inner) => inner.header().height().to_owned(),
+            Block::V2(inner) => inner.header().height().to_owned(),
+        }
+    }
+
+    pub fn parent_hash(&self) -> &BlockHash {
+        match self {
+            Block::V1(inner) => inner.header().parent_hash(),
+            Block::V2(inner) => inner.header().parent_hash(),
+        }
+    }
+
+    pub fn state_root_hash(&self) -> &Digest {
+        match self {
+            Block::V1(inner) => inner.header().state_root_hash(),
+            Block::V2(inner) => inner.header().state_root_hash(),
+        }
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -47,8 +76,32 @@ impl Block {
 impl Block {
     pub fn is_genesis(&self) -> bool {
         match self {
-            Block::V1(_) => unimplemented!(),
+            Block::V1(inner) => inner.header().parent_hash().is_genesis(),
             Block::V2(inner) => inner.header().parent_hash().is_genesis(),
         }
     }
+
+    pub fn is_switch(&self) -> bool {
+        match self {
+            Block::V1(inner) => inner.header().era_end().is_some(),
+            Block::V2(inner) => inner.header().era_end().is_some(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl Block {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        prop_oneof![
+            BlockV1::new_from_arb().prop_map(Self::new_v1),
+            BlockV2::new_from_arb().prop_map(Self::new_v2),
+        ]
+    }
 }
@@ -162,3 +162,24 @@ impl From<u64> for BlockID {
         Self::from(BlockHeight::from(value))
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl BlockHash {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        Digest::new_from_arb().prop_map(Self::new)
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl BlockHeight {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        any::<u64>().prop_map(Self::new)
+    }
+}
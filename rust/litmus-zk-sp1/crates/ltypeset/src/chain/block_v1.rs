@@ -1,4 +1,8 @@
-use super::BlockHash;
+use crate::{
+    chain::{BlockHash, BlockHeight, EraEndV1, EraId, ProtocolVersion, TransactionV1Hash},
+    crypto::{Digest, PublicKey},
+    primitives::time::Timestamp,
+};
 use serde::{Deserialize, Serialize};
 
 // ------------------------------------------------------------------------
@@ -6,7 +10,7 @@ use serde::{Deserialize, Serialize};
 // ------------------------------------------------------------------------
 
 // Block (v1).
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Block {
     /// Information pertaining to vm + consensus.
     body: BlockBody,
@@ -19,14 +23,65 @@ pub struct Block {
 }
 
 // Block (v1) body.
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
-pub struct BlockBody {}
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BlockBody {
+    /// Set of deploys executed within block.
+    deploy_hashes: Vec<TransactionV1Hash>,
 
-// Block (v1) body header.
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+    /// Public key of validator granted protocol permission to propose block.
+    proposer: PublicKey,
+
+    /// Set of native transfers executed within block.
+    transfer_hashes: Vec<TransactionV1Hash>,
+}
+
+// Block (v1) header.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub struct BlockHeader {
-    /// The parent block's hash.
+    /// A seed needed for initializing a future era.
+    accumulated_seed: Digest,
+
+    /// Digest over block's body.
+    body_hash: Digest,
+
+    /// `EraEnd` report (if it is a switch block).
+    era_end: Option<EraEndV1>,
+
+    /// ID of era at point in chain time when block was created.
+    era_id: EraId,
+
+    /// Height of this block, i.e. the number of ancestors.
+    height: BlockHeight,
+
+    /// Parent block hash.
     parent_hash: BlockHash,
+
+    /// Protocol version of network at point of block creation.
+    protocol_version: ProtocolVersion,
+
+    /// A random bit needed for initializing a future era.
+    random_bit: bool,
+
+    /// Root hash of global state after deploys in this block have been executed.
+    state_root_hash: Digest,
+
+    /// Timestamp at which block was created.
+    timestamp: Timestamp,
+}
+
+/// Fields from which a block (v1) header is constructed, i.e. named rather than positional.
+#[derive(Clone, Debug)]
+pub struct BlockHeaderFields {
+    pub accumulated_seed: Digest,
+    pub body_hash: Digest,
+    pub era_end: Option<EraEndV1>,
+    pub era_id: EraId,
+    pub height: BlockHeight,
+    pub parent_hash: BlockHash,
+    pub protocol_version: ProtocolVersion,
+    pub random_bit: bool,
+    pub state_root_hash: Digest,
+    pub timestamp: Timestamp,
 }
 
 // ------------------------------------------------------------------------
@@ -40,19 +95,122 @@ impl Block {
     }
 }
 
+impl BlockBody {
+    pub fn new(
+        deploy_hashes: Vec<TransactionV1Hash>,
+        proposer: PublicKey,
+        transfer_hashes: Vec<TransactionV1Hash>,
+    ) -> Self {
+        // TODO: validate inputs.
+        Self {
+            deploy_hashes,
+            proposer,
+            transfer_hashes,
+        }
+    }
+}
+
+impl BlockHeader {
+    pub fn new(fields: BlockHeaderFields) -> Self {
+        let BlockHeaderFields {
+            accumulated_seed,
+            body_hash,
+            era_end,
+            era_id,
+            height,
+            parent_hash,
+            protocol_version,
+            random_bit,
+            state_root_hash,
+            timestamp,
+        } = fields;
+
+        Self {
+            accumulated_seed,
+            body_hash,
+            era_end,
+            era_id,
+            height,
+            parent_hash,
+            protocol_version,
+            random_bit,
+            state_root_hash,
+            timestamp,
+        }
+    }
+}
+
 // ------------------------------------------------------------------------
 // Accessors.
 // ------------------------------------------------------------------------
 
 impl Block {
     pub fn body(&self) -> &BlockBody {
-        unimplemented!()
+        &self.body
     }
+
     pub fn hash(&self) -> &BlockHash {
         &self.hash
     }
+
     pub fn header(&self) -> &BlockHeader {
-        unimplemented!()
+        &self.header
+    }
+}
+
+impl BlockBody {
+    pub fn deploy_hashes(&self) -> &Vec<TransactionV1Hash> {
+        &self.deploy_hashes
+    }
+
+    pub fn proposer(&self) -> &PublicKey {
+        &self.proposer
+    }
+
+    pub fn transfer_hashes(&self) -> &Vec<TransactionV1Hash> {
+        &self.transfer_hashes
+    }
+}
+
+impl BlockHeader {
+    pub fn accumulated_seed(&self) -> &Digest {
+        &self.accumulated_seed
+    }
+
+    pub fn body_hash(&self) -> &Digest {
+        &self.body_hash
+    }
+
+    pub fn era_end(&self) -> &Option<EraEndV1> {
+        &self.era_end
+    }
+
+    pub fn era_id(&self) -> &EraId {
+        &self.era_id
+    }
+
+    pub fn height(&self) -> &BlockHeight {
+        &self.height
+    }
+
+    pub fn parent_hash(&self) -> &BlockHash {
+        &self.parent_hash
+    }
+
+    pub fn protocol_version(&self) -> &ProtocolVersion {
+        &self.protocol_version
+    }
+
+    pub fn random_bit(&self) -> &bool {
+        &self.random_bit
+    }
+
+    pub fn state_root_hash(&self) -> &Digest {
+        &self.state_root_hash
+    }
+
+    pub fn timestamp(&self) -> &Timestamp {
+        &self.timestamp
     }
 }
 
@@ -61,13 +219,92 @@ impl Block {
 // ------------------------------------------------------------------------
 
 impl Block {
-    /// Returns a sequence of bytes for mapping to a block digest.
-    pub fn get_bytes_for_hash(&self) -> Vec<u8> {
-        unimplemented!("v1 block get_bytes_for_hash");
-    }
-
     /// Returns a sequence of bytes to be signed over when commiting to block finality.
+    ///
+    /// N.B. Prior to protocol version 2.0 finality signatures were not scoped by chain name.
     pub fn get_bytes_for_finality_signature(&self) -> Vec<u8> {
-        unimplemented!("v1 block get_bytes_for_finality_signature");
+        let mut result: Vec<u8> = Vec::new();
+        result.extend_from_slice(self.hash().inner().as_slice());
+        result.extend_from_slice(self.header().era_id().inner().to_le_bytes().as_slice());
+
+        result
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl Block {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            BlockBody::new_from_arb(),
+            BlockHash::new_from_arb(),
+            BlockHeader::new_from_arb(),
+        )
+            .prop_map(|(body, hash, header)| Self::new(body, hash, header))
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl BlockBody {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            prop::collection::vec(TransactionV1Hash::new_from_arb(), 0..4),
+            PublicKey::new_from_arb(),
+            prop::collection::vec(TransactionV1Hash::new_from_arb(), 0..4),
+        )
+            .prop_map(|(deploy_hashes, proposer, transfer_hashes)| {
+                Self::new(deploy_hashes, proposer, transfer_hashes)
+            })
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl BlockHeader {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            Digest::new_from_arb(),
+            Digest::new_from_arb(),
+            prop::option::of(EraEndV1::new_from_arb()),
+            EraId::new_from_arb(),
+            BlockHeight::new_from_arb(),
+            BlockHash::new_from_arb(),
+            ProtocolVersion::new_from_arb(),
+            any::<bool>(),
+            Digest::new_from_arb(),
+            Timestamp::new_from_arb(),
+        )
+            .prop_map(
+                |(
+                    accumulated_seed,
+                    body_hash,
+                    era_end,
+                    era_id,
+                    height,
+                    parent_hash,
+                    protocol_version,
+                    random_bit,
+                    state_root_hash,
+                    timestamp,
+                )| {
+                    Self::new(BlockHeaderFields {
+                        accumulated_seed,
+                        body_hash,
+                        era_end,
+                        era_id,
+                        height,
+                        parent_hash,
+                        protocol_version,
+                        random_bit,
+                        state_root_hash,
+                        timestamp,
+                    })
+                },
+            )
     }
 }
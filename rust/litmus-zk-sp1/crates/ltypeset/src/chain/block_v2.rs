@@ -2,8 +2,7 @@ extern crate alloc;
 
 use crate::{
     chain::{
-        BlockHash, BlockHeight, ChainNameDigest, EraEndV2, EraId, ProtocolVersion,
-        TransactionV2Hash,
+        BlockHash, BlockHeight, ChainNameDigest, EraEndV2, EraId, ProtocolVersion, TransactionHash,
     },
     crypto::{Digest, PublicKey},
     primitives::time::Timestamp,
@@ -36,7 +35,7 @@ pub struct BlockBody {
     rewarded_signatures: Vec<Vec<u8>>,
 
     /// Map of transactions mapping categories to a list of transaction hashes.
-    transactions: BTreeMap<u8, Vec<TransactionV2Hash>>,
+    transactions: BTreeMap<u8, Vec<TransactionHash>>,
 }
 
 // Block (v2) header.
@@ -96,7 +95,7 @@ impl Block {
 impl BlockBody {
     pub fn new(
         rewarded_signatures: Vec<Vec<u8>>,
-        transactions: BTreeMap<u8, Vec<TransactionV2Hash>>,
+        transactions: BTreeMap<u8, Vec<TransactionHash>>,
     ) -> Self {
         // TODO: validate inputs.
         Self {
@@ -146,7 +145,7 @@ impl BlockHeader {
 
 impl Block {
     pub fn body(&self) -> &BlockBody {
-        unimplemented!()
+        &self.body
     }
 
     pub fn hash(&self) -> &BlockHash {
@@ -163,7 +162,7 @@ impl BlockBody {
         &self.rewarded_signatures
     }
 
-    pub fn transactions(&self) -> &BTreeMap<u8, Vec<TransactionV2Hash>> {
+    pub fn transactions(&self) -> &BTreeMap<u8, Vec<TransactionHash>> {
         &self.transactions
     }
 }
@@ -227,11 +226,6 @@ impl BlockHeader {
 // ------------------------------------------------------------------------
 
 impl Block {
-    /// Returns a sequence of bytes for mapping to a block digest.
-    pub fn get_bytes_for_digest(&self) -> Vec<u8> {
-        self.header().get_bytes_for_digest()
-    }
-
     /// Returns a sequence of bytes to be signed over when commiting to block finality.
     pub fn get_bytes_for_finality_signature(&self, chain_name_digest: &ChainNameDigest) -> Vec<u8> {
         let mut result: Vec<u8> = Vec::new();
@@ -244,35 +238,101 @@ impl Block {
     }
 }
 
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl Block {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            BlockBody::new_from_arb(),
+            BlockHash::new_from_arb(),
+            BlockHeader::new_from_arb(),
+        )
+            .prop_map(|(body, hash, header)| Self::new(body, hash, header))
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
 impl BlockBody {
-    /// Returns a sequence of bytes for mapping to a block digest.
-    pub fn get_bytes_for_digest(&self) -> Vec<u8> {
-        unimplemented!()
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            prop::collection::vec(prop::collection::vec(any::<u8>(), 0..4), 0..4),
+            prop::collection::btree_map(
+                any::<u8>(),
+                prop::collection::vec(TransactionHash::new_from_arb(), 0..4),
+                0..4,
+            ),
+        )
+            .prop_map(|(rewarded_signatures, transactions)| {
+                Self::new(rewarded_signatures, transactions)
+            })
     }
 }
 
+#[cfg(any(feature = "testing", test))]
 impl BlockHeader {
-    /// Returns a sequence of bytes for mapping to a block digest.
-    pub fn get_bytes_for_digest(&self) -> Vec<u8> {
-        let mut result: Vec<u8> = Vec::new();
-        result.extend_from_slice(self.parent_hash().inner().as_slice());
-        result.extend_from_slice(self.state_root_hash().as_slice());
-        result.extend_from_slice(self.body_hash().as_slice());
-        result.extend_from_slice(
-            u8::from(self.random_bit().to_owned())
-                .to_le_bytes()
-                .as_slice(),
-        );
-        result.extend_from_slice(self.accumulated_seed().as_slice());
-        // result.extend_from_slice(self.era_end().hash(state););
-        result.extend_from_slice(self.timestamp().inner().to_le_bytes().as_slice());
-        result.extend_from_slice(self.era_id().inner().to_le_bytes().as_slice());
-        result.extend_from_slice(self.height().inner().to_le_bytes().as_slice());
-        // result.extend_from_slice(self.protocol_version());
-        result.extend_from_slice(self.proposer().as_slice());
-        result.extend_from_slice(self.current_gas_price().to_le_bytes().as_slice());
-        // result.extend_from_slice(self.last_switch_block_hash().unwrap().inner().as_slice());
-
-        result
+    // N.B. Fields are drawn as two tuples as proptest tuple strategies are limited to 12 elements.
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            (
+                Digest::new_from_arb(),
+                Digest::new_from_arb(),
+                any::<u8>(),
+                prop::option::of(EraEndV2::new_from_arb()),
+                EraId::new_from_arb(),
+                BlockHeight::new_from_arb(),
+                prop::option::of(BlockHash::new_from_arb()),
+            ),
+            (
+                BlockHash::new_from_arb(),
+                PublicKey::new_from_arb(),
+                ProtocolVersion::new_from_arb(),
+                any::<bool>(),
+                Digest::new_from_arb(),
+                Timestamp::new_from_arb(),
+            ),
+        )
+            .prop_map(
+                |(
+                    (
+                        accumulated_seed,
+                        body_hash,
+                        current_gas_price,
+                        era_end,
+                        era_id,
+                        height,
+                        last_switch_block_hash,
+                    ),
+                    (
+                        parent_hash,
+                        proposer,
+                        protocol_version,
+                        random_bit,
+                        state_root_hash,
+                        timestamp,
+                    ),
+                )| {
+                    Self::new(
+                        accumulated_seed,
+                        body_hash,
+                        current_gas_price,
+                        era_end,
+                        era_id,
+                        height,
+                        last_switch_block_hash,
+                        parent_hash,
+                        proposer,
+                        protocol_version,
+                        random_bit,
+                        state_root_hash,
+                        timestamp,
+                    )
+                },
+            )
     }
 }
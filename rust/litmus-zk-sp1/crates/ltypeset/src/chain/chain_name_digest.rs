@@ -45,3 +45,17 @@ impl From<&str> for ChainNameDigest {
         Self::new_from_chain_name(value)
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl ChainNameDigest {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        Digest::new_from_arb().prop_map(Self::new)
+    }
+}
@@ -1,14 +1,16 @@
 use super::EraId;
 use super::Motes;
+use super::ValidatorID;
 use super::ValidatorWeight;
-use serde::{Deserialize, Serialize};
+use crate::verification::VerificationError;
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 // ------------------------------------------------------------------------
 // Declarations.
 // ------------------------------------------------------------------------
 
 /// Information scoped by era pertinent to consensus.
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize)]
 pub struct EraConsensusInfo {
     /// Era identifier.
     era_id: EraId,
@@ -20,21 +22,39 @@ pub struct EraConsensusInfo {
     total_weight: Motes,
 }
 
+// N.B. total voting weight is recomputed upon deserialization rather than trusted.
+#[derive(Deserialize)]
+struct EraConsensusInfoFields {
+    era_id: EraId,
+    validator_weights: Vec<ValidatorWeight>,
+}
+
 // ------------------------------------------------------------------------
 // Constructors.
 // ------------------------------------------------------------------------
 
 impl EraConsensusInfo {
-    pub fn new(era_id: EraId, validator_weights: Vec<ValidatorWeight>) -> Self {
-        let total_weight: &Motes = &validator_weights
+    /// Constructor: returns era consensus information, erroring if total weight overflows.
+    ///
+    /// # Arguments
+    ///
+    /// * `era_id` - Era identifier.
+    /// * `validator_weights` - Era scoped validator voting weights.
+    ///
+    pub fn new(
+        era_id: EraId,
+        validator_weights: Vec<ValidatorWeight>,
+    ) -> Result<Self, VerificationError> {
+        let total_weight = validator_weights
             .iter()
-            .fold(Motes::MIN, |acc, x| acc + x.weight());
+            .try_fold(Motes::MIN, |acc, x| acc.checked_add(x.weight()))
+            .ok_or(VerificationError::WeightOverflow)?;
 
-        Self {
+        Ok(Self {
             era_id,
             validator_weights,
-            total_weight: total_weight.clone(),
-        }
+            total_weight,
+        })
     }
 }
 
@@ -53,3 +73,98 @@ impl EraConsensusInfo {
         self.total_weight
     }
 }
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl EraConsensusInfo {
+    /// Returns weight of a validator within era, if validator is a member of era validator set.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_id` - Identifier of a validator.
+    ///
+    pub fn get_validator_weight(&self, validator_id: &ValidatorID) -> Option<Motes> {
+        self.validator_weights
+            .iter()
+            .find(|vw| &vw.validator_id() == validator_id)
+            .map(|vw| vw.weight())
+    }
+
+    /// Predicate: is validator a member of era validator set ?
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_id` - Identifier of a validator.
+    ///
+    pub fn is_validator(&self, validator_id: &ValidatorID) -> bool {
+        self.get_validator_weight(validator_id).is_some()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits -> serde.
+// ------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for EraConsensusInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields = EraConsensusInfoFields::deserialize(deserializer)?;
+
+        Self::new(fields.era_id, fields.validator_weights).map_err(de::Error::custom)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::VerificationKey;
+
+    const VKEY_HEX: &str = "01764f83295812c03354e0cd64718a7e50b452696799dc9d6e446338d668f3b2d9";
+
+    #[test]
+    fn test_that_total_weight_is_recomputed_upon_deserialization() {
+        let era_consensus_info = EraConsensusInfo::new(
+            EraId::new(2),
+            vec![ValidatorWeight::new(
+                VerificationKey::from(VKEY_HEX),
+                Motes::new(100),
+            )],
+        )
+        .unwrap();
+        let mut encoded = serde_json::to_value(&era_consensus_info).unwrap();
+        encoded["total_weight"] = serde_json::json!(1);
+
+        assert_eq!(
+            serde_json::from_value::<EraConsensusInfo>(encoded).unwrap(),
+            era_consensus_info
+        );
+    }
+
+    #[test]
+    fn test_error_if_total_weight_overflows() {
+        let validator_weights = vec![
+            ValidatorWeight::new(VerificationKey::from(VKEY_HEX), Motes::new(u64::MAX)),
+            ValidatorWeight::new(VerificationKey::from(VKEY_HEX), Motes::new(1)),
+        ];
+
+        assert_eq!(
+            EraConsensusInfo::new(EraId::new(2), validator_weights.clone()),
+            Err(VerificationError::WeightOverflow)
+        );
+        let mut encoded = serde_json::to_value(
+            EraConsensusInfo::new(EraId::new(2), validator_weights[..1].to_vec()).unwrap(),
+        )
+        .unwrap();
+        encoded["validator_weights"] = serde_json::to_value(&validator_weights).unwrap();
+
+        assert!(serde_json::from_value::<EraConsensusInfo>(encoded).is_err());
+    }
+}
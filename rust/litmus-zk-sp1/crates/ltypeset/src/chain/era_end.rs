@@ -9,8 +9,14 @@ use serde::{Deserialize, Serialize};
 // Declarations.
 // ------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
-pub struct EraEndV1 {}
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EraEndV1 {
+    /// Report over equivocations, rewards & inactivity within era.
+    era_report: EraReport,
+
+    /// Validator set for upcoming era plus respective weights.
+    next_era_validator_weights: Vec<ValidatorWeight>,
+}
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub struct EraEndV2 {
@@ -30,10 +36,62 @@ pub struct EraEndV2 {
     next_era_gas_price: u8,
 }
 
+/// Era report issued within a (v1) switch block.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EraReport {
+    /// Set of validators deemed to have equivocated.
+    equivocators: Vec<PublicKey>,
+
+    /// Validators that did not produce units during era.
+    inactive_validators: Vec<PublicKey>,
+
+    /// Rewards to be distributed to validators within era.
+    rewards: Vec<EraReward>,
+}
+
+/// Reward issued to a validator within an era report.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EraReward {
+    /// Rewarded validator.
+    validator: PublicKey,
+
+    /// Reward amount.
+    amount: u64,
+}
+
 // ------------------------------------------------------------------------
 // Constructors.
 // ------------------------------------------------------------------------
 
+impl EraEndV1 {
+    pub fn new(era_report: EraReport, next_era_validator_weights: Vec<ValidatorWeight>) -> Self {
+        Self {
+            era_report,
+            next_era_validator_weights,
+        }
+    }
+}
+
+impl EraReport {
+    pub fn new(
+        equivocators: Vec<PublicKey>,
+        inactive_validators: Vec<PublicKey>,
+        rewards: Vec<EraReward>,
+    ) -> Self {
+        Self {
+            equivocators,
+            inactive_validators,
+            rewards,
+        }
+    }
+}
+
+impl EraReward {
+    pub fn new(validator: PublicKey, amount: u64) -> Self {
+        Self { validator, amount }
+    }
+}
+
 impl EraEndV2 {
     pub fn new(
         equivocators: Vec<PublicKey>,
@@ -56,6 +114,40 @@ impl EraEndV2 {
 // Accessors.
 // ------------------------------------------------------------------------
 
+impl EraEndV1 {
+    pub fn era_report(&self) -> &EraReport {
+        &self.era_report
+    }
+
+    pub fn next_era_validator_weights(&self) -> &Vec<ValidatorWeight> {
+        &self.next_era_validator_weights
+    }
+}
+
+impl EraReport {
+    pub fn equivocators(&self) -> &Vec<PublicKey> {
+        &self.equivocators
+    }
+
+    pub fn inactive_validators(&self) -> &Vec<PublicKey> {
+        &self.inactive_validators
+    }
+
+    pub fn rewards(&self) -> &Vec<EraReward> {
+        &self.rewards
+    }
+}
+
+impl EraReward {
+    pub fn validator(&self) -> &PublicKey {
+        &self.validator
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
 impl EraEndV2 {
     pub fn equivocators(&self) -> &Vec<PublicKey> {
         &self.equivocators
@@ -77,3 +169,91 @@ impl EraEndV2 {
         self.next_era_gas_price
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl EraEndV1 {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            EraReport::new_from_arb(),
+            ValidatorWeight::new_set_from_arb(),
+        )
+            .prop_map(|(era_report, next_era_validator_weights)| {
+                Self::new(era_report, next_era_validator_weights)
+            })
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl EraEndV2 {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            prop::collection::vec(PublicKey::new_from_arb(), 0..4),
+            prop::collection::vec(PublicKey::new_from_arb(), 0..4),
+            ValidatorWeight::new_set_from_arb(),
+            prop::collection::btree_map(
+                PublicKey::new_from_arb(),
+                prop::collection::vec(Motes::new_from_arb(), 0..4),
+                0..8,
+            ),
+            any::<u8>(),
+        )
+            .prop_map(
+                |(
+                    equivocators,
+                    inactive_validators,
+                    next_era_validator_weights,
+                    rewards,
+                    next_era_gas_price,
+                )| {
+                    Self::new(
+                        equivocators,
+                        inactive_validators,
+                        next_era_validator_weights,
+                        rewards,
+                        next_era_gas_price,
+                    )
+                },
+            )
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl EraReport {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (
+            prop::collection::vec(PublicKey::new_from_arb(), 0..4),
+            prop::collection::vec(PublicKey::new_from_arb(), 0..4),
+            EraReward::new_set_from_arb(),
+        )
+            .prop_map(|(equivocators, inactive_validators, rewards)| {
+                Self::new(equivocators, inactive_validators, rewards)
+            })
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl EraReward {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (PublicKey::new_from_arb(), any::<u64>())
+            .prop_map(|(validator, amount)| Self::new(validator, amount))
+    }
+
+    /// Returns a strategy over a set of rewards ordered by validator, i.e. as per an encoded map.
+    pub fn new_set_from_arb() -> impl Strategy<Value = Vec<Self>> {
+        prop::collection::btree_map(PublicKey::new_from_arb(), any::<u64>(), 0..8).prop_map(
+            |rewards| {
+                rewards
+                    .into_iter()
+                    .map(|(validator, amount)| Self::new(validator, amount))
+                    .collect()
+            },
+        )
+    }
+}
@@ -56,18 +56,21 @@ impl fmt::Display for EraId {
 // Tests.
 // ------------------------------------------------------------------------
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use proptest::prelude::*;
 
 #[cfg(test)]
 use rand::Rng;
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 impl EraId {
     pub fn new_from_arb() -> impl Strategy<Value = Self> {
         any::<u64>().prop_map(Self::new)
     }
+}
 
+#[cfg(test)]
+impl EraId {
     pub fn new_from_random() -> Self {
         Self::new(rand::thread_rng().gen())
     }
@@ -19,16 +19,20 @@ pub use block_id::BlockHash;
 pub use block_id::BlockHeight;
 pub use block_id::BlockID;
 pub use block_signature::BlockSignature;
-pub use block_v1::{Block as BlockV1, BlockBody as BlockV1Body, BlockHeader as BlockV1Header};
+pub use block_v1::{
+    Block as BlockV1, BlockBody as BlockV1Body, BlockHeader as BlockV1Header,
+    BlockHeaderFields as BlockV1HeaderFields,
+};
 pub use block_v2::{Block as BlockV2, BlockBody as BlockV2Body, BlockHeader as BlockV2Header};
 pub use block_with_proofs::BlockWithProofs;
 pub use chain_name_digest::ChainNameDigest;
 pub use era_consensus_info::EraConsensusInfo;
 pub use era_end::EraEndV1;
 pub use era_end::EraEndV2;
+pub use era_end::{EraReport, EraReward};
 pub use era_id::EraId;
 pub use motes::Motes;
 pub use protocol_version::ProtocolVersion;
-pub use transaction_hash::{TransactionV1Hash, TransactionV2Hash};
+pub use transaction_hash::{TransactionHash, TransactionV1Hash, TransactionV2Hash};
 pub use validator_id::ValidatorID;
 pub use validator_weight::ValidatorWeight;
@@ -37,6 +37,22 @@ impl Motes {
     }
 }
 
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl Motes {
+    /// Returns sum of two amounts, or `None` upon overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - Amount to be added.
+    ///
+    pub fn checked_add(&self, rhs: Motes) -> Option<Motes> {
+        self.inner().checked_add(rhs.inner()).map(Motes::new)
+    }
+}
+
 // ------------------------------------------------------------------------
 // Traits.
 // ------------------------------------------------------------------------
@@ -105,18 +121,21 @@ impl Serialize for Motes {
 // Tests.
 // ------------------------------------------------------------------------
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use proptest::prelude::*;
 
 #[cfg(test)]
 use rand::Rng;
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 impl Motes {
     pub fn new_from_arb() -> impl Strategy<Value = Self> {
         any::<u64>().prop_map(Self::new)
     }
+}
 
+#[cfg(test)]
+impl Motes {
     pub fn new_from_random() -> Self {
         Self::new(rand::thread_rng().gen())
     }
@@ -133,6 +152,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_that_checked_add_is_none_upon_overflow() {
+        assert_eq!(
+            Motes::new(1).checked_add(Motes::new(2)),
+            Some(Motes::new(3))
+        );
+        assert_eq!(Motes::new(u64::MAX).checked_add(Motes::new(1)), None);
+    }
+
     proptest! {
         #[test]
         fn new_from_arb(_ in Motes::new_from_arb()) {
@@ -29,3 +29,17 @@ impl ProtocolVersion {
         &self.0
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl ProtocolVersion {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        SemanticVersion::new_from_arb().prop_map(Self::new)
+    }
+}
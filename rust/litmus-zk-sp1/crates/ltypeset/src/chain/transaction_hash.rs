@@ -8,10 +8,12 @@ use serde::{Deserialize, Serialize};
 /// Digest over a transaction's data structure.
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub enum TransactionHash {
-    /// A version 1 transaction hash.
+    /// A version 1 transaction hash, i.e. a legacy deploy hash.
+    #[serde(rename = "Deploy")]
     V1(TransactionV1Hash),
 
     /// A version 2 transaction hash.
+    #[serde(rename = "Version1")]
     V2(TransactionV2Hash),
 }
 
@@ -54,3 +56,34 @@ impl TransactionV2Hash {
         &self.0
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl TransactionHash {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        prop_oneof![
+            TransactionV1Hash::new_from_arb().prop_map(Self::V1),
+            TransactionV2Hash::new_from_arb().prop_map(Self::V2),
+        ]
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl TransactionV1Hash {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        Digest::new_from_arb().prop_map(Self::new)
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl TransactionV2Hash {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        Digest::new_from_arb().prop_map(Self::new)
+    }
+}
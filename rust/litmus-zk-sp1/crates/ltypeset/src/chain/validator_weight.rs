@@ -41,3 +41,29 @@ impl ValidatorWeight {
         self.weight
     }
 }
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl ValidatorWeight {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (ValidatorID::new_from_arb(), Motes::new_from_arb())
+            .prop_map(|(validator_id, weight)| Self::new(validator_id, weight))
+    }
+
+    /// Returns a strategy over a set of weights ordered by validator, i.e. as per an encoded map.
+    pub fn new_set_from_arb() -> impl Strategy<Value = Vec<Self>> {
+        prop::collection::btree_map(ValidatorID::new_from_arb(), Motes::new_from_arb(), 0..8)
+            .prop_map(|weights| {
+                weights
+                    .into_iter()
+                    .map(|(validator_id, weight)| Self::new(validator_id, weight))
+                    .collect()
+            })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::primitives::bites::Bytes32;
+use crate::{primitives::bites::Bytes32, verification::VerificationError};
 use lcrypto;
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
@@ -83,12 +83,15 @@ impl Digest {
     ///
     /// * `data` - Data against which to verify digest.
     ///
-    pub fn verify(&self, data: Vec<u8>) {
-        match self {
-            Digest::BLAKE2B(_) => {
-                assert_eq!(self, &Digest::get_blake2b(data));
-            }
+    pub fn verify(&self, data: Vec<u8>) -> Result<(), VerificationError> {
+        let recomputed = match self {
+            Digest::BLAKE2B(_) => Digest::get_blake2b(data),
+        };
+        if self != &recomputed {
+            return Err(VerificationError::DigestMismatch);
         }
+
+        Ok(())
     }
 }
 
@@ -183,6 +186,16 @@ impl Serialize for Digest {
 // Tests.
 // ------------------------------------------------------------------------
 
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl Digest {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        Bytes32::new_from_arb().prop_map(Self::new)
+    }
+}
+
 #[cfg(test)]
 use rand::Rng;
 
@@ -226,14 +239,16 @@ mod tests {
     fn test_verify() {
         let digest = Digest::from(MSG_DIGEST_BLAKE2B_HEX);
 
-        assert_eq!(digest.verify(MSG.to_vec()), ());
+        assert_eq!(digest.verify(MSG.to_vec()), Ok(()));
     }
 
     #[test]
-    #[should_panic]
-    fn test_panic_on_verification_failure() {
+    fn test_error_on_verification_failure() {
         let digest = Digest::random();
 
-        digest.verify(MSG.to_vec());
+        assert_eq!(
+            digest.verify(MSG.to_vec()),
+            Err(VerificationError::DigestMismatch)
+        );
     }
 }
@@ -1,7 +1,9 @@
 mod digest;
 mod signature;
+mod signing_key;
 mod verification_key;
 
 pub use digest::Digest;
-pub use signature::Signature;
+pub use signature::{verify_signatures, Signature};
+pub use signing_key::SigningKey;
 pub use verification_key::{VerificationKey, VerificationKey as PublicKey};
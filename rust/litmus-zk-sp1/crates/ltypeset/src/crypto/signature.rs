@@ -1,6 +1,6 @@
 use super::digest::Digest;
 use super::verification_key::VerificationKey;
-use crate::primitives::bites::Bytes64;
+use crate::{primitives::bites::Bytes64, verification::VerificationError};
 use lcrypto;
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
@@ -113,26 +113,25 @@ impl Signature {
 impl Signature {
     /// Verifies signature against arbitrary data.
     ///
+    /// N.B. secp256k1 signatures are issued over a SHA-256 digest of the data, i.e. as per Casper.
+    ///
     /// # Arguments
     ///
     /// * `vkey` - Verification key counterpart to signing key.
     /// * `msg` - Data over which signature was issued.
     ///
-    pub fn verify(&self, vkey: &VerificationKey, msg: &[u8]) {
-        match self {
-            Signature::ED25519(sig) => match vkey {
-                VerificationKey::ED25519(vk) => {
-                    lcrypto::verify_signature_ed25519(&sig.inner(), &vk.inner(), msg)
-                }
-                _ => panic!("Invalid verification key type"),
-            },
-            Signature::SECP256K1(sig) => match vkey {
-                VerificationKey::SECP256K1(vk) => {
-                    lcrypto::verify_signature_secp256k1(&sig.inner(), &vk.inner(), msg)
-                }
-                _ => panic!("Invalid verification key type"),
-            },
-        }
+    pub fn verify(&self, vkey: &VerificationKey, msg: &[u8]) -> Result<(), VerificationError> {
+        match (self, vkey) {
+            (Signature::ED25519(sig), VerificationKey::ED25519(vk)) => {
+                lcrypto::verify_signature_ed25519(&sig.inner(), &vk.inner(), msg)?
+            }
+            (Signature::SECP256K1(sig), VerificationKey::SECP256K1(vk)) => {
+                lcrypto::verify_signature_secp256k1_prehashed(&sig.inner(), &vk.inner(), msg)?
+            }
+            _ => return Err(VerificationError::KeySignatureTypeMismatch),
+        };
+
+        Ok(())
     }
 
     /// Verifies signature against a digest.
@@ -142,11 +141,57 @@ impl Signature {
     /// * `vkey` - Verification key counterpart to signing key.
     /// * `digest` - Digest over which signature was issued.
     ///
-    pub fn verify_digest(&self, vkey: &VerificationKey, digest: &Digest) {
-        self.verify(&vkey, &digest.as_slice());
+    pub fn verify_digest(
+        &self,
+        vkey: &VerificationKey,
+        digest: &Digest,
+    ) -> Result<(), VerificationError> {
+        match (self, vkey) {
+            (Signature::SECP256K1(sig), VerificationKey::SECP256K1(vk)) => {
+                lcrypto::verify_signature_secp256k1(&sig.inner(), &vk.inner(), digest.as_slice())?
+            }
+            _ => self.verify(vkey, digest.as_slice())?,
+        };
+
+        Ok(())
     }
 }
 
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Verifies a set of signatures issued over the same data, batching ed25519 signatures.
+///
+/// N.B. A batch failure does not identify which signature is invalid.
+///
+/// # Arguments
+///
+/// * `proofs` - Set of signatures plus verification key counterparts to signing keys.
+/// * `msg` - Data over which signatures were issued.
+///
+pub fn verify_signatures(
+    proofs: &[(Signature, &VerificationKey)],
+    msg: &[u8],
+) -> Result<(), VerificationError> {
+    let mut batch = Vec::new();
+    for (sig, vkey) in proofs {
+        match (sig, vkey) {
+            (Signature::ED25519(sig), VerificationKey::ED25519(vk)) => {
+                batch.push((sig.inner(), vk.inner()))
+            }
+            _ => sig.verify(vkey, msg)?,
+        }
+    }
+
+    Ok(lcrypto::verify_signatures_ed25519(
+        &batch
+            .iter()
+            .map(|(sig, vkey)| (sig, vkey, msg))
+            .collect::<Vec<_>>(),
+    )?)
+}
+
 // ------------------------------------------------------------------------
 // Traits.
 // ------------------------------------------------------------------------
@@ -236,6 +281,19 @@ impl Serialize for Signature {
 // Tests.
 // ------------------------------------------------------------------------
 
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl Signature {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        prop_oneof![
+            Bytes64::new_from_arb().prop_map(Self::new_ed25519),
+            Bytes64::new_from_arb().prop_map(Self::new_secp256k1),
+        ]
+    }
+}
+
 #[cfg(test)]
 use rand::{rngs::OsRng, RngCore};
 
@@ -262,6 +320,8 @@ mod tests {
 
     const SIG_ED25519_TAGGED_HEX: &str = "012fa8e929a7514496545d098e86841463ef66358ff0930073fde3b138f66a2cef5304d884baa693a971d002d7e071f658fb16de8c1e5c80ba5ecea8b3866f8106";
     const SIG_SECP256K1_TAGGED_HEX: &str = "025ed6e5b71fa8f87dfb197a3d85c926d075f0b15651b59224a9a41a9fa1deb8cc2b2de5a8312a310af9b5321f67b744e1b3814994b13ec6db2769e9e6a9cc9364";
+    // Issued over MSG1 by k256 `SigningKey::sign`, i.e. over a SHA-256 digest as per casper-types.
+    const SIG_SECP256K1_OVER_MSG1_TAGGED_HEX: &str = "02afff3803b1fc41eb4d66c3bbe373729f4fa288aff7d640b8357adc18396188a6501a813c45cc28245e082133894eab4e2dab0c7b5a6bb252388f026292ea2393";
     const SIG_SET: [&str; 2] = [SIG_ED25519_TAGGED_HEX, SIG_SECP256K1_TAGGED_HEX];
     const VKEY_ED25519_TAGGED_HEX: &str =
         "01764f83295812c03354e0cd64718a7e50b452696799dc9d6e446338d668f3b2d9";
@@ -298,14 +358,87 @@ mod tests {
     #[test]
     fn test_verification() {
         let digest = Digest::from(MSG_DIGEST_BLAKE2B_HEX);
-        println!("{}", digest);
         for (sig, vkey) in SIG_SET.iter().zip(VKEY_SET.iter()) {
             let sig = Signature::from(*sig);
             let vkey = VerificationKey::from(*vkey);
-            sig.verify_digest(&vkey, &digest);
+            assert_eq!(sig.verify_digest(&vkey, &digest), Ok(()));
         }
     }
 
+    #[test]
+    fn test_error_if_signature_is_invalid() {
+        let sig = Signature::from(SIG_ED25519_TAGGED_HEX);
+        let vkey = VerificationKey::from(VKEY_ED25519_TAGGED_HEX);
+
+        assert_eq!(
+            sig.verify(&vkey, MSG1),
+            Err(VerificationError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_error_if_key_type_is_mismatched() {
+        let digest = Digest::from(MSG_DIGEST_BLAKE2B_HEX);
+        let sig = Signature::from(SIG_ED25519_TAGGED_HEX);
+        let vkey = VerificationKey::from(VKEY_SECP256K1_TAGGED_HEX);
+
+        assert_eq!(
+            sig.verify_digest(&vkey, &digest),
+            Err(VerificationError::KeySignatureTypeMismatch)
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_verification_prehashes_data() {
+        let sig = Signature::from(SIG_SECP256K1_OVER_MSG1_TAGGED_HEX);
+        let vkey = VerificationKey::from(VKEY_SECP256K1_TAGGED_HEX);
+
+        assert_eq!(sig.verify(&vkey, MSG1), Ok(()));
+        assert_eq!(
+            sig.verify(&vkey, &MSG1[1..]),
+            Err(VerificationError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_verification_of_signature_set() {
+        let digest = Digest::from(MSG_DIGEST_BLAKE2B_HEX);
+        let sig_ed25519 = Signature::from(SIG_ED25519_TAGGED_HEX);
+        let sig_secp256k1 = Signature::from(SIG_SECP256K1_OVER_MSG1_TAGGED_HEX);
+        let vkey_ed25519 = VerificationKey::from(VKEY_ED25519_TAGGED_HEX);
+        let vkey_secp256k1 = VerificationKey::from(VKEY_SECP256K1_TAGGED_HEX);
+
+        assert_eq!(
+            verify_signatures(&[(sig_ed25519, &vkey_ed25519)], digest.as_slice()),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signatures(&[(sig_secp256k1, &vkey_secp256k1)], MSG1),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signatures(
+                &[
+                    (sig_secp256k1, &vkey_secp256k1),
+                    (sig_ed25519, &vkey_ed25519)
+                ],
+                MSG1
+            ),
+            Err(VerificationError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_error_if_signature_set_has_mismatched_key_type() {
+        let sig = Signature::from(SIG_ED25519_TAGGED_HEX);
+        let vkey = VerificationKey::from(VKEY_SECP256K1_TAGGED_HEX);
+
+        assert_eq!(
+            verify_signatures(&[(sig, &vkey)], MSG1),
+            Err(VerificationError::KeySignatureTypeMismatch)
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_panic_if_tag_is_invalid() {
@@ -0,0 +1,212 @@
+use super::{digest::Digest, signature::Signature, verification_key::VerificationKey};
+use crate::primitives::bites::{Bytes32, Bytes33, Bytes64};
+use lcrypto;
+use std::fmt;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// A wrapped assymetric signing key, i.e. secret counterpart of a verification key.
+///
+/// N.B. Intended for issuing signatures over synthetic chains, e.g. whilst testing.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub enum SigningKey {
+    ED25519(Bytes32),
+    SECP256K1(Bytes32),
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl SigningKey {
+    /// Constructor: returns a new ed25519 signing key.
+    ///
+    /// # Arguments
+    ///
+    /// * `sk` - Signing key issued by an ed25519 algorithm.
+    ///
+    pub fn new_ed25519(sk: Bytes32) -> Self {
+        SigningKey::ED25519(sk)
+    }
+
+    /// Constructor: returns a new secp256k1 signing key.
+    ///
+    /// # Arguments
+    ///
+    /// * `sk` - Signing key issued by a secp256k1 algorithm.
+    ///
+    pub fn new_secp256k1(sk: Bytes32) -> Self {
+        assert!(
+            lcrypto::get_verification_key_secp256k1(&sk.inner()).is_ok(),
+            "Invalid secp256k1 signing key"
+        );
+        SigningKey::SECP256K1(sk)
+    }
+
+    /// Constructor: returns an ed25519 signing key deterministically derived from a seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Arbitrary data from which to derive a signing key.
+    ///
+    pub fn new_ed25519_from_seed(seed: &[u8]) -> Self {
+        Self::new_ed25519(Bytes32::new(lcrypto::get_signing_key_ed25519(seed)))
+    }
+
+    /// Constructor: returns a secp256k1 signing key deterministically derived from a seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Arbitrary data from which to derive a signing key.
+    ///
+    pub fn new_secp256k1_from_seed(seed: &[u8]) -> Self {
+        Self::new_secp256k1(Bytes32::new(lcrypto::get_signing_key_secp256k1(seed)))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl SigningKey {
+    /// Returns verification key counterpart.
+    pub fn verification_key(&self) -> VerificationKey {
+        match self {
+            SigningKey::ED25519(sk) => VerificationKey::new_ed25519(Bytes32::new(
+                lcrypto::get_verification_key_ed25519(&sk.inner()),
+            )),
+            SigningKey::SECP256K1(sk) => VerificationKey::new_secp256k1(Bytes33::new(
+                lcrypto::get_verification_key_secp256k1(&sk.inner()).unwrap(),
+            )),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl SigningKey {
+    /// Returns signature over arbitrary data.
+    ///
+    /// N.B. secp256k1 signatures are issued over a SHA-256 digest of the data, i.e. as per Casper.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - Data to be signed over.
+    ///
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        match self {
+            SigningKey::ED25519(sk) => {
+                Signature::new_ed25519(Bytes64::new(lcrypto::sign_ed25519(&sk.inner(), msg)))
+            }
+            SigningKey::SECP256K1(sk) => Signature::new_secp256k1(Bytes64::new(
+                lcrypto::sign_secp256k1_prehashed(&sk.inner(), msg).unwrap(),
+            )),
+        }
+    }
+
+    /// Returns signature over a digest.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Digest to be signed over.
+    ///
+    pub fn sign_digest(&self, digest: &Digest) -> Signature {
+        match self {
+            SigningKey::ED25519(_) => self.sign(digest.as_slice()),
+            SigningKey::SECP256K1(sk) => Signature::new_secp256k1(Bytes64::new(
+                lcrypto::sign_secp256k1(&sk.inner(), digest.as_slice()).unwrap(),
+            )),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Debug for SigningKey {
+    // N.B. key material is withheld.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl fmt::Display for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SigningKey::ED25519(_) => write!(f, "SKEY:ED25519:{}", self.verification_key()),
+            SigningKey::SECP256K1(_) => write!(f, "SKEY:SECP256K1:{}", self.verification_key()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MSG: &[u8] = "أبو يوسف يعقوب بن إسحاق الصبّاح الكندي‎".as_bytes();
+
+    fn get_signing_keys() -> [SigningKey; 2] {
+        [
+            SigningKey::new_ed25519_from_seed(MSG),
+            SigningKey::new_secp256k1_from_seed(MSG),
+        ]
+    }
+
+    #[test]
+    fn test_signature_is_verified() {
+        for skey in get_signing_keys() {
+            assert_eq!(skey.sign(MSG).verify(&skey.verification_key(), MSG), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_digest_signature_is_verified() {
+        let digest = Digest::get_blake2b(MSG.to_vec());
+        for skey in get_signing_keys() {
+            assert_eq!(
+                skey.sign_digest(&digest)
+                    .verify_digest(&skey.verification_key(), &digest),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_keys_are_derived_deterministically() {
+        for (skey, other) in get_signing_keys().iter().zip(get_signing_keys().iter()) {
+            assert_eq!(skey, other);
+            assert_eq!(skey.verification_key(), other.verification_key());
+        }
+        assert_ne!(
+            SigningKey::new_ed25519_from_seed(MSG).verification_key(),
+            SigningKey::new_ed25519_from_seed(&MSG[1..]).verification_key()
+        );
+    }
+
+    #[test]
+    fn test_key_material_is_not_rendered() {
+        for skey in get_signing_keys() {
+            let rendered = format!("{:?}", skey);
+            let inner = match &skey {
+                SigningKey::ED25519(inner) | SigningKey::SECP256K1(inner) => inner,
+            };
+            assert!(!rendered.contains(&hex::encode(inner.as_slice())));
+            assert!(rendered.ends_with(&skey.verification_key().to_string()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_panic_if_secp256k1_key_is_invalid() {
+        let _ = SigningKey::new_secp256k1(Bytes32::new([0_u8; 32]));
+    }
+}
@@ -6,6 +6,7 @@ use std::fmt;
 // Constants.
 // ------------------------------------------------------------------------
 
+const TAG_SYSTEM: u8 = 0;
 const TAG_ED25519: u8 = 1;
 const TAG_SECP256K1: u8 = 2;
 const VKEY_SIZE_ED25519: usize = 32;
@@ -19,6 +20,8 @@ const VKEY_SIZE_SECP256K1: usize = 33;
 // A wrapped verification key counterpart of an assymetric signing key.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum VerificationKey {
+    /// System key, e.g. proposer of a genesis block, which has no key material.
+    SYSTEM,
     ED25519(Bytes32),
     SECP256K1(Bytes33),
 }
@@ -35,6 +38,9 @@ impl VerificationKey {
     /// * `raw_bytes` - A sequence of bytes.
     ///
     pub fn new(raw_bytes: &[u8]) -> Self {
+        if raw_bytes == [TAG_SYSTEM] {
+            return VerificationKey::SYSTEM;
+        }
         assert!(
             VKEY_SIZE_RANGE.contains(&raw_bytes.len()),
             "Invalid verification key length"
@@ -87,6 +93,7 @@ impl VerificationKey {
     // Returns underlying byte array.
     pub fn as_slice(&self) -> &[u8] {
         match self {
+            VerificationKey::SYSTEM => &[],
             VerificationKey::ED25519(inner) => inner.as_slice(),
             VerificationKey::SECP256K1(inner) => inner.as_slice(),
         }
@@ -103,6 +110,7 @@ impl VerificationKey {
     // Returns algorithm type tag.
     pub fn get_tag(&self) -> u8 {
         match self {
+            VerificationKey::SYSTEM => TAG_SYSTEM,
             VerificationKey::ED25519(_) => TAG_ED25519,
             VerificationKey::SECP256K1(_) => TAG_SECP256K1,
         }
@@ -116,6 +124,7 @@ impl VerificationKey {
 impl fmt::Display for VerificationKey {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            VerificationKey::SYSTEM => write!(f, "VKEY:SYSTEM"),
             VerificationKey::ED25519(inner) => write!(f, "VKEY:ED25519:{}", inner),
             VerificationKey::SECP256K1(inner) => write!(f, "VKEY:SECP256K1:{}", inner),
         }
@@ -198,6 +207,20 @@ impl Serialize for VerificationKey {
 // Tests.
 // ------------------------------------------------------------------------
 
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl VerificationKey {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        prop_oneof![
+            Just(Self::SYSTEM),
+            Bytes32::new_from_arb().prop_map(Self::new_ed25519),
+            Bytes33::new_from_arb().prop_map(Self::new_secp256k1),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,6 +230,7 @@ mod tests {
     const VKEY_SECP256K1_TAGGED_HEX: &str =
         "0203eed4eb0b40b3131679c365e3a23780eabfeaeb01776b0f908223ad1d4bd06f0d";
     const VKEY_SET: [&str; 2] = [VKEY_ED25519_TAGGED_HEX, VKEY_SECP256K1_TAGGED_HEX];
+    const VKEY_SYSTEM_TAGGED_HEX: &str = "00";
 
     #[test]
     fn test_new_from_str() {
@@ -243,6 +267,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_system_from_str() {
+        let vkey = VerificationKey::from(VKEY_SYSTEM_TAGGED_HEX);
+        assert_eq!(vkey, VerificationKey::SYSTEM);
+        assert_eq!(vkey.as_slice_with_tag(), vec![TAG_SYSTEM]);
+    }
+
     #[test]
     #[should_panic]
     fn test_panic_if_tag_is_invalid() {
@@ -1,4 +1,6 @@
 pub mod chain;
 pub mod crypto;
 pub mod primitives;
+pub mod trie;
 mod utils;
+pub mod verification;
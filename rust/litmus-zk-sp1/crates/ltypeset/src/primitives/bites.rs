@@ -158,7 +158,7 @@ impl<const N: usize> From<&String> for Bytes<N> {
 // Tests.
 // ------------------------------------------------------------------------
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use proptest::prelude::*;
 
 #[cfg(test)]
@@ -173,45 +173,57 @@ pub fn new_from_random<const N: usize>() -> Bytes<N> {
     Bytes::<N>::from(buffer)
 }
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 impl Bytes32 {
     pub fn new_from_arb() -> impl Strategy<Value = Self> {
         any::<[u8; SIZE_32]>().prop_map(Self::new)
     }
+}
 
+#[cfg(test)]
+impl Bytes32 {
     fn new_from_random() -> Bytes32 {
         new_from_random::<SIZE_32>()
     }
 }
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 impl Bytes33 {
     pub fn new_from_arb() -> impl Strategy<Value = Self> {
         any::<[u8; SIZE_33]>().prop_map(Self::new)
     }
+}
 
+#[cfg(test)]
+impl Bytes33 {
     fn new_from_random() -> Bytes33 {
         new_from_random::<SIZE_33>()
     }
 }
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 impl Bytes64 {
     pub fn new_from_arb() -> impl Strategy<Value = Self> {
         any::<[u8; SIZE_64]>().prop_map(Self::new)
     }
+}
 
+#[cfg(test)]
+impl Bytes64 {
     fn new_from_random() -> Bytes64 {
         new_from_random::<SIZE_64>()
     }
 }
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 impl Bytes65 {
     pub fn new_from_arb() -> impl Strategy<Value = Self> {
         any::<[u8; SIZE_65]>().prop_map(Self::new)
     }
+}
 
+#[cfg(test)]
+impl Bytes65 {
     fn new_from_random() -> Bytes65 {
         new_from_random::<SIZE_65>()
     }
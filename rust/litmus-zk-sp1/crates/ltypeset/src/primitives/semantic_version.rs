@@ -96,8 +96,16 @@ impl Serialize for SemanticVersion {
 // Tests.
 // ------------------------------------------------------------------------
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use proptest::prelude::*;
 
 #[cfg(test)]
 use rand::Rng;
+
+#[cfg(any(feature = "testing", test))]
+impl SemanticVersion {
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        (any::<u32>(), any::<u32>(), any::<u32>())
+            .prop_map(|(major, minor, patch)| Self::new(major, minor, patch))
+    }
+}
@@ -1,11 +1,18 @@
 use crate::utils::deconstruct_bytes;
 use humantime;
-use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::Visitor, ser, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{self, Display, Formatter},
     time::{Duration, SystemTime},
 };
 
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+// Milliseconds since Unix epoch at 9999-12-31T23:59:59.999Z, i.e. latest moment representable in RFC 3339.
+const MAX_MS_SINCE_EPOCH_RFC3339: u128 = 253_402_300_799_999;
+
 // ------------------------------------------------------------------------
 // Declarations.
 // ------------------------------------------------------------------------
@@ -152,7 +159,23 @@ impl Serialize for Timestamp {
     where
         S: Serializer,
     {
-        serializer.serialize_bytes(&self.inner().to_le_bytes())
+        // N.B. human readable formats, e.g. JSON, mirror node's RFC 3339 representation.
+        match serializer.is_human_readable() {
+            true => {
+                if self.inner() > MAX_MS_SINCE_EPOCH_RFC3339 {
+                    return Err(ser::Error::custom(
+                        "timestamp exceeds RFC 3339 range, i.e. year 9999",
+                    ));
+                }
+                serializer.serialize_str(
+                    &humantime::format_rfc3339_millis(
+                        SystemTime::UNIX_EPOCH + Duration::from_millis(self.inner() as u64),
+                    )
+                    .to_string(),
+                )
+            }
+            false => serializer.serialize_bytes(&self.inner().to_le_bytes()),
+        }
     }
 }
 
@@ -160,6 +183,17 @@ impl Serialize for Timestamp {
 // Tests.
 // ------------------------------------------------------------------------
 
+#[cfg(any(feature = "testing", test))]
+use proptest::prelude::*;
+
+#[cfg(any(feature = "testing", test))]
+impl Timestamp {
+    // N.B. Binary encoding is a u64 millisecond count.
+    pub fn new_from_arb() -> impl Strategy<Value = Self> {
+        any::<u64>().prop_map(|ms_since_epoch| Self::new(ms_since_epoch as u128))
+    }
+}
+
 #[cfg(test)]
 use rand::Rng;
 
@@ -181,3 +215,43 @@ impl Timestamp {
         Self::new(1_596_763_000_000 + rand::thread_rng().gen_range(200_000..1_000_000))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_that_json_encoding_is_rfc3339() {
+        let timestamp = Timestamp::from("2024-12-02T11:42:48.304Z");
+        let encoded = serde_json::to_string(&timestamp).unwrap();
+
+        assert_eq!(encoded, "\"2024-12-02T11:42:48.304Z\"");
+        assert_eq!(
+            serde_json::from_str::<Timestamp>(&encoded).unwrap(),
+            timestamp
+        );
+    }
+
+    #[test]
+    fn test_that_json_encoding_of_max_rfc3339_timestamp_is_supported() {
+        let timestamp = Timestamp::new(MAX_MS_SINCE_EPOCH_RFC3339);
+        let encoded = serde_json::to_string(&timestamp).unwrap();
+
+        assert_eq!(encoded, "\"9999-12-31T23:59:59.999Z\"");
+        assert_eq!(
+            serde_json::from_str::<Timestamp>(&encoded).unwrap(),
+            timestamp
+        );
+    }
+
+    #[test]
+    fn test_error_if_json_encoded_timestamp_exceeds_rfc3339_range() {
+        for ms_since_epoch in [
+            MAX_MS_SINCE_EPOCH_RFC3339 + 1,
+            u64::MAX as u128 + 1,
+            u128::MAX,
+        ] {
+            assert!(serde_json::to_string(&Timestamp::new(ms_since_epoch)).is_err());
+        }
+    }
+}
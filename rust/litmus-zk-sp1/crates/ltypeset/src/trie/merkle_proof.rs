@@ -0,0 +1,107 @@
+use super::{Pointer, Trie};
+use crate::verification::VerificationError;
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Proof that a key/value pair is stored within global state under a state root hash.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct TrieMerkleProof {
+    /// Binary encoded Casper `Key`.
+    #[serde(with = "hex::serde")]
+    key: Vec<u8>,
+
+    /// Sequence of steps from leaf up to trie root.
+    proof_steps: Vec<TrieMerkleProofStep>,
+
+    /// Binary encoded Casper `StoredValue`.
+    #[serde(with = "hex::serde")]
+    value: Vec<u8>,
+}
+
+/// A step within a trie merkle proof, i.e. a parent minus pointer to child being proven.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub enum TrieMerkleProofStep {
+    /// A node with a hole at index of child being proven.
+    Node {
+        hole_index: u8,
+        indexed_pointers_with_hole: Vec<(u8, Pointer)>,
+    },
+
+    /// An extension whose pointer is to child being proven.
+    Extension {
+        #[serde(with = "hex::serde")]
+        affix: Vec<u8>,
+    },
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl TrieMerkleProof {
+    pub fn new(key: Vec<u8>, proof_steps: Vec<TrieMerkleProofStep>, value: Vec<u8>) -> Self {
+        Self {
+            key,
+            proof_steps,
+            value,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl TrieMerkleProof {
+    pub fn key(&self) -> &Vec<u8> {
+        &self.key
+    }
+
+    pub fn proof_steps(&self) -> &Vec<TrieMerkleProofStep> {
+        &self.proof_steps
+    }
+
+    pub fn value(&self) -> &Vec<u8> {
+        &self.value
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl TrieMerkleProof {
+    /// Returns leaf whose inclusion is being proven.
+    pub fn get_leaf(&self) -> Trie {
+        Trie::new_leaf(self.key.to_owned(), self.value.to_owned())
+    }
+}
+
+impl TrieMerkleProofStep {
+    /// Returns parent trie node obtained by filling step's hole with pointer to child being proven.
+    ///
+    /// N.B. Errors if step's hole index is also that of one of its pointers.
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer` - Pointer to child being proven.
+    ///
+    pub fn get_parent(&self, pointer: Pointer) -> Result<Trie, VerificationError> {
+        match self {
+            TrieMerkleProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                let mut indexed_pointers = indexed_pointers_with_hole.to_owned();
+                indexed_pointers.push((*hole_index, pointer));
+                Trie::new_node(indexed_pointers)
+            }
+            TrieMerkleProofStep::Extension { affix } => {
+                Ok(Trie::new_extension(affix.to_owned(), pointer))
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod merkle_proof;
+mod node;
+mod pointer;
+
+pub use merkle_proof::{TrieMerkleProof, TrieMerkleProofStep};
+pub use node::{Trie, TRIE_RADIX};
+pub use pointer::Pointer;
@@ -0,0 +1,67 @@
+use super::Pointer;
+use crate::verification::VerificationError;
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+/// Number of children of a global state trie node.
+pub const TRIE_RADIX: usize = 256;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// A node within Casper's global state trie.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Trie {
+    /// A key/value pair, i.e. binary encoded Casper `Key` + `StoredValue`.
+    Leaf {
+        #[serde(with = "hex::serde")]
+        key: Vec<u8>,
+        #[serde(with = "hex::serde")]
+        value: Vec<u8>,
+    },
+
+    /// A branch whose children are indexed by next byte of a key.
+    Node {
+        indexed_pointers: Vec<(u8, Pointer)>,
+    },
+
+    /// A shared key affix followed by a pointer to a single child.
+    Extension {
+        #[serde(with = "hex::serde")]
+        affix: Vec<u8>,
+        pointer: Pointer,
+    },
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl Trie {
+    pub fn new_leaf(key: Vec<u8>, value: Vec<u8>) -> Self {
+        Self::Leaf { key, value }
+    }
+
+    /// Constructor: returns a node, erroring if a child index is pointed to more than once.
+    ///
+    /// # Arguments
+    ///
+    /// * `indexed_pointers` - Set of pointers to children, each keyed by child index.
+    ///
+    pub fn new_node(mut indexed_pointers: Vec<(u8, Pointer)>) -> Result<Self, VerificationError> {
+        indexed_pointers.sort_by_key(|(idx, _)| *idx);
+        if indexed_pointers.windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(VerificationError::InvalidTrieNode);
+        }
+
+        Ok(Self::Node { indexed_pointers })
+    }
+
+    pub fn new_extension(affix: Vec<u8>, pointer: Pointer) -> Self {
+        Self::Extension { affix, pointer }
+    }
+}
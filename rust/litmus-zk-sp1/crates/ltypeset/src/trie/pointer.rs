@@ -0,0 +1,31 @@
+use crate::crypto::Digest;
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Pointer from a global state trie node to one of its children.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Pointer {
+    /// Digest over a child leaf.
+    #[serde(rename = "LeafPointer")]
+    Leaf(Digest),
+
+    /// Digest over a child node or extension.
+    #[serde(rename = "NodePointer")]
+    Node(Digest),
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl Pointer {
+    pub fn digest(&self) -> &Digest {
+        match self {
+            Pointer::Leaf(inner) => inner,
+            Pointer::Node(inner) => inner,
+        }
+    }
+}
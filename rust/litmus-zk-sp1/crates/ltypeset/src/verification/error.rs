@@ -0,0 +1,291 @@
+use crate::chain::ValidatorID;
+use lcrypto::CryptoError;
+use std::fmt;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Reasons for which verification of a digest, signature, block or chain may fail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VerificationError {
+    /// BL-001: Recomputed block hash is not equal to actual block hash.
+    BlockHashMismatch,
+
+    /// BL-002: Block is not from era declared by previous switch block.
+    EraMismatch,
+
+    /// BL-003: Block was signed more than once by a validator.
+    DuplicateSignatory(ValidatorID),
+
+    /// BL-003: Block was signed by a validator outside of era validator set.
+    NonEraSignatory(ValidatorID),
+
+    /// BL-004: A finality signature issued by a validator is invalid.
+    InvalidFinalitySignature(ValidatorID, Box<VerificationError>),
+
+    /// BL-005: Weight of finality signatures does not exceed finality threshold.
+    InsufficientSignatureWeight,
+
+    /// BL-005: Finality threshold is not a fraction within range [0, 1].
+    InvalidFinalityThreshold,
+
+    /// BL-005: Sum of validator weights exceeds maximum possible weight.
+    WeightOverflow,
+
+    /// BL-006: Recomputed block body hash is not equal to header body hash.
+    BlockBodyHashMismatch,
+
+    /// GS-001: Recomputed state root hash is not equal to block state root hash.
+    StateRootHashMismatch,
+
+    /// GS-001: A trie node within a merkle proof is malformed or cannot be hashed.
+    InvalidTrieNode,
+
+    /// Block is not of version expected by verifier.
+    BlockVersionMismatch,
+
+    /// Block range to be verified is empty.
+    EmptyBlockRange,
+
+    /// Block's parent hash is not hash of previous block.
+    ParentHashMismatch,
+
+    /// Block's height is not one greater than that of previous block.
+    NonContiguousHeight,
+
+    /// Block is not a switch block.
+    NotASwitchBlock,
+
+    /// Previous block is not a switch block.
+    PreviousNotASwitchBlock,
+
+    /// Switch block is not that referenced by a trusted block.
+    SwitchBlockHashMismatch,
+
+    /// Switch block does not reference previous switch block as its last switch block.
+    LastSwitchBlockHashMismatch,
+
+    /// Switch block's era is not that following previous switch block's era.
+    NonContiguousEra,
+
+    /// Transaction is not listed within block body.
+    TransactionNotInBlock,
+
+    /// Recomputed digest is not equal to actual digest.
+    DigestMismatch,
+
+    /// Signature type does not correspond to verification key type.
+    KeySignatureTypeMismatch,
+
+    /// Signed over message cannot be decoded.
+    InvalidMessageEncoding,
+
+    /// Signature cannot be decoded.
+    InvalidSignatureEncoding,
+
+    /// Verification key cannot be decoded.
+    InvalidVerificationKeyEncoding,
+
+    /// Signing key cannot be decoded.
+    InvalidSigningKeyEncoding,
+
+    /// Signature was not issued over message by verification key's signing counterpart.
+    InvalidSignature,
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl VerificationError {
+    /// Returns code of verification rule that was violated, e.g. BL-001.
+    pub fn rule(&self) -> &'static str {
+        match self {
+            VerificationError::BlockHashMismatch => "BL-001",
+            VerificationError::EraMismatch => "BL-002",
+            VerificationError::DuplicateSignatory(_) | VerificationError::NonEraSignatory(_) => {
+                "BL-003"
+            }
+            VerificationError::InvalidFinalitySignature(_, _) => "BL-004",
+            VerificationError::InsufficientSignatureWeight
+            | VerificationError::InvalidFinalityThreshold
+            | VerificationError::WeightOverflow => "BL-005",
+            VerificationError::BlockBodyHashMismatch => "BL-006",
+            VerificationError::StateRootHashMismatch | VerificationError::InvalidTrieNode => {
+                "GS-001"
+            }
+            VerificationError::BlockVersionMismatch
+            | VerificationError::EmptyBlockRange
+            | VerificationError::ParentHashMismatch
+            | VerificationError::NonContiguousHeight => "Chain",
+            VerificationError::NotASwitchBlock
+            | VerificationError::PreviousNotASwitchBlock
+            | VerificationError::SwitchBlockHashMismatch
+            | VerificationError::LastSwitchBlockHashMismatch
+            | VerificationError::NonContiguousEra => "Era",
+            VerificationError::TransactionNotInBlock => "Inclusion",
+            VerificationError::DigestMismatch => "Digest",
+            VerificationError::KeySignatureTypeMismatch
+            | VerificationError::InvalidMessageEncoding
+            | VerificationError::InvalidSignatureEncoding
+            | VerificationError::InvalidVerificationKeyEncoding
+            | VerificationError::InvalidSigningKeyEncoding
+            | VerificationError::InvalidSignature => "Signature",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rule = self.rule();
+        match self {
+            VerificationError::BlockHashMismatch => write!(
+                f,
+                "{rule}: Recomputed block hash is not equal to actual block hash"
+            ),
+            VerificationError::EraMismatch => write!(
+                f,
+                "{rule}: Block is not from era declared by previous switch block"
+            ),
+            VerificationError::DuplicateSignatory(validator_id) => {
+                write!(f, "{rule}: Duplicate finality signature: {validator_id}")
+            }
+            VerificationError::NonEraSignatory(validator_id) => write!(
+                f,
+                "{rule}: Finality signatory is not an era validator: {validator_id}"
+            ),
+            VerificationError::InvalidFinalitySignature(validator_id, err) => write!(
+                f,
+                "{rule}: Invalid finality signature: {validator_id}: {err}"
+            ),
+            VerificationError::InsufficientSignatureWeight => {
+                write!(f, "{rule}: Insufficient finality signature weight")
+            }
+            VerificationError::InvalidFinalityThreshold => {
+                write!(f, "{rule}: Invalid finality threshold")
+            }
+            VerificationError::WeightOverflow => write!(f, "{rule}: Validator weight overflow"),
+            VerificationError::BlockBodyHashMismatch => write!(
+                f,
+                "{rule}: Recomputed block body hash is not equal to header body hash"
+            ),
+            VerificationError::StateRootHashMismatch => write!(
+                f,
+                "{rule}: Recomputed state root hash is not equal to block state root hash"
+            ),
+            VerificationError::InvalidTrieNode => write!(f, "{rule}: Invalid trie node"),
+            VerificationError::BlockVersionMismatch => write!(f, "{rule}: Invalid block version"),
+            VerificationError::EmptyBlockRange => write!(f, "{rule}: Block range is empty"),
+            VerificationError::ParentHashMismatch => {
+                write!(f, "{rule}: Block is not a child of previous block")
+            }
+            VerificationError::NonContiguousHeight => {
+                write!(f, "{rule}: Block height is not contiguous")
+            }
+            VerificationError::NotASwitchBlock => write!(f, "{rule}: Block is not a switch block"),
+            VerificationError::PreviousNotASwitchBlock => {
+                write!(f, "{rule}: Previous block is not a switch block")
+            }
+            VerificationError::SwitchBlockHashMismatch => {
+                write!(
+                    f,
+                    "{rule}: Switch block is not that referenced by trusted block"
+                )
+            }
+            VerificationError::LastSwitchBlockHashMismatch => write!(
+                f,
+                "{rule}: Switch block does not reference previous switch block"
+            ),
+            VerificationError::NonContiguousEra => write!(
+                f,
+                "{rule}: Switch block era does not follow previous switch block era"
+            ),
+            VerificationError::TransactionNotInBlock => {
+                write!(f, "{rule}: Transaction is not in block")
+            }
+            VerificationError::DigestMismatch => {
+                write!(f, "{rule}: Recomputed digest is not equal to actual digest")
+            }
+            VerificationError::KeySignatureTypeMismatch => {
+                write!(f, "{rule}: Invalid verification key type")
+            }
+            VerificationError::InvalidMessageEncoding => {
+                write!(f, "{rule}: Invalid message encoding")
+            }
+            VerificationError::InvalidSignatureEncoding => {
+                write!(f, "{rule}: Invalid signature encoding")
+            }
+            VerificationError::InvalidVerificationKeyEncoding => {
+                write!(f, "{rule}: Invalid verification key encoding")
+            }
+            VerificationError::InvalidSigningKeyEncoding => {
+                write!(f, "{rule}: Invalid signing key encoding")
+            }
+            VerificationError::InvalidSignature => {
+                write!(f, "{rule}: Signature verification failure")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+impl From<CryptoError> for VerificationError {
+    fn from(value: CryptoError) -> Self {
+        match value {
+            CryptoError::InvalidMessage => VerificationError::InvalidMessageEncoding,
+            CryptoError::InvalidSignature => VerificationError::InvalidSignatureEncoding,
+            CryptoError::InvalidSigningKey => VerificationError::InvalidSigningKeyEncoding,
+            CryptoError::InvalidVerificationKey => {
+                VerificationError::InvalidVerificationKeyEncoding
+            }
+            CryptoError::VerificationFailure => VerificationError::InvalidSignature,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_is_prefixed_by_rule() {
+        for err in [
+            VerificationError::BlockHashMismatch,
+            VerificationError::EraMismatch,
+            VerificationError::InsufficientSignatureWeight,
+            VerificationError::BlockBodyHashMismatch,
+            VerificationError::StateRootHashMismatch,
+            VerificationError::EmptyBlockRange,
+            VerificationError::NotASwitchBlock,
+            VerificationError::LastSwitchBlockHashMismatch,
+            VerificationError::NonContiguousEra,
+            VerificationError::TransactionNotInBlock,
+            VerificationError::InvalidSignature,
+        ] {
+            assert!(err.to_string().starts_with(&format!("{}: ", err.rule())));
+        }
+    }
+
+    #[test]
+    fn test_crypto_errors_are_mapped() {
+        assert_eq!(
+            VerificationError::from(CryptoError::VerificationFailure),
+            VerificationError::InvalidSignature
+        );
+        assert_eq!(
+            VerificationError::from(CryptoError::InvalidVerificationKey),
+            VerificationError::InvalidVerificationKeyEncoding
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod error;
+mod public_values;
+
+pub use error::VerificationError;
+pub use public_values::{
+    BlockPublicValues, EraPublicValues, PublicValues, StatePublicValues, TransactionPublicValues,
+};
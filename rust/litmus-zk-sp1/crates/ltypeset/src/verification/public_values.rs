@@ -0,0 +1,247 @@
+use crate::{
+    chain::{Block, BlockHash, BlockHeight, ChainNameDigest, EraId, TransactionV2Hash},
+    crypto::{Digest, VerificationKey},
+};
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Values committed by zk-vm program, i.e. what a proof attests to having been verified.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub enum PublicValues {
+    /// A verified digest.
+    Digest(Digest),
+
+    /// A verified signature over a digest issued by a verification key.
+    Signature(Digest, VerificationKey),
+
+    /// A verified version one block.
+    BlockV1(BlockPublicValues),
+
+    /// A verified version two block.
+    BlockV2(BlockPublicValues),
+
+    /// A verified contiguous range of version two blocks, i.e. first + last block.
+    BlockRangeV2(BlockPublicValues, BlockPublicValues),
+
+    /// A transaction included within a verified version two block.
+    TransactionV2Inclusion(TransactionPublicValues),
+
+    /// A key/value pair stored within global state as at a verified version two block.
+    StateV2Inclusion(StatePublicValues),
+
+    /// A verified version two switch block reached from a trusted switch block one era at a time.
+    EraV2(EraPublicValues),
+}
+
+/// Values committed upon verification of a block.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BlockPublicValues {
+    /// Hash of verified block.
+    block_hash: BlockHash,
+
+    /// Digest over name of chain to which block belongs (if scoped by chain name).
+    chain_name_digest: Option<ChainNameDigest>,
+
+    /// ID of era in which block was created.
+    era_id: EraId,
+
+    /// Height of verified block.
+    height: BlockHeight,
+
+    /// Root hash of global state after block execution.
+    state_root_hash: Digest,
+
+    /// Digest over era validator set against which finality signatures were tallied (if not a trusted block).
+    validator_set_digest: Option<Digest>,
+}
+
+/// Values committed upon verification of a switch block, either trusted or reached by extending a previous proof by one era.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EraPublicValues {
+    /// Values pertaining to most recently verified switch block.
+    block: BlockPublicValues,
+
+    /// Hash of trusted switch block from which chain of proofs was started.
+    trusted_block_hash: BlockHash,
+
+    /// Hash of verification key of zk-vm program that issued chain of proofs.
+    vkey_hash: Digest,
+}
+
+/// Values committed upon verification of a transaction's inclusion within a block.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct TransactionPublicValues {
+    /// Values pertaining to block within which transaction is included.
+    block: BlockPublicValues,
+
+    /// Category under which transaction was executed.
+    category: u8,
+
+    /// Hash of included transaction.
+    transaction_hash: TransactionV2Hash,
+}
+
+/// Values committed upon verification of a key/value pair stored within global state.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StatePublicValues {
+    /// Values pertaining to block whose state root hash anchors global state.
+    block: BlockPublicValues,
+
+    /// Binary encoded Casper `Key`.
+    key: Vec<u8>,
+
+    /// Binary encoded Casper `StoredValue`.
+    value: Vec<u8>,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl BlockPublicValues {
+    pub fn new(
+        block_hash: BlockHash,
+        chain_name_digest: Option<ChainNameDigest>,
+        era_id: EraId,
+        height: BlockHeight,
+        state_root_hash: Digest,
+        validator_set_digest: Option<Digest>,
+    ) -> Self {
+        Self {
+            block_hash,
+            chain_name_digest,
+            era_id,
+            height,
+            state_root_hash,
+            validator_set_digest,
+        }
+    }
+
+    /// Constructor: returns values to be committed upon verification of a block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - A verified block.
+    /// * `chain_name_digest` - Digest over name of chain to which block belongs (if scoped by chain name).
+    /// * `validator_set_digest` - Digest over era validator set against which finality was verified, `None` if block was trusted.
+    ///
+    pub fn new_from_block(
+        block: &Block,
+        chain_name_digest: Option<ChainNameDigest>,
+        validator_set_digest: Option<Digest>,
+    ) -> Self {
+        Self::new(
+            block.hash().to_owned(),
+            chain_name_digest,
+            block.era_id(),
+            block.height(),
+            block.state_root_hash().to_owned(),
+            validator_set_digest,
+        )
+    }
+}
+
+impl EraPublicValues {
+    pub fn new(block: BlockPublicValues, trusted_block_hash: BlockHash, vkey_hash: Digest) -> Self {
+        Self {
+            block,
+            trusted_block_hash,
+            vkey_hash,
+        }
+    }
+}
+
+impl StatePublicValues {
+    pub fn new(block: BlockPublicValues, key: Vec<u8>, value: Vec<u8>) -> Self {
+        Self { block, key, value }
+    }
+}
+
+impl TransactionPublicValues {
+    pub fn new(
+        block: BlockPublicValues,
+        category: u8,
+        transaction_hash: TransactionV2Hash,
+    ) -> Self {
+        Self {
+            block,
+            category,
+            transaction_hash,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl BlockPublicValues {
+    pub fn block_hash(&self) -> &BlockHash {
+        &self.block_hash
+    }
+
+    pub fn chain_name_digest(&self) -> &Option<ChainNameDigest> {
+        &self.chain_name_digest
+    }
+
+    pub fn era_id(&self) -> &EraId {
+        &self.era_id
+    }
+
+    pub fn height(&self) -> &BlockHeight {
+        &self.height
+    }
+
+    pub fn state_root_hash(&self) -> &Digest {
+        &self.state_root_hash
+    }
+
+    pub fn validator_set_digest(&self) -> &Option<Digest> {
+        &self.validator_set_digest
+    }
+}
+
+impl EraPublicValues {
+    pub fn block(&self) -> &BlockPublicValues {
+        &self.block
+    }
+
+    pub fn trusted_block_hash(&self) -> &BlockHash {
+        &self.trusted_block_hash
+    }
+
+    pub fn vkey_hash(&self) -> &Digest {
+        &self.vkey_hash
+    }
+}
+
+impl StatePublicValues {
+    pub fn block(&self) -> &BlockPublicValues {
+        &self.block
+    }
+
+    pub fn key(&self) -> &Vec<u8> {
+        &self.key
+    }
+
+    pub fn value(&self) -> &Vec<u8> {
+        &self.value
+    }
+}
+
+impl TransactionPublicValues {
+    pub fn block(&self) -> &BlockPublicValues {
+        &self.block
+    }
+
+    pub fn category(&self) -> u8 {
+        self.category
+    }
+
+    pub fn transaction_hash(&self) -> &TransactionV2Hash {
+        &self.transaction_hash
+    }
+}
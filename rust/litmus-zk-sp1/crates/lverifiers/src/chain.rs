@@ -0,0 +1,897 @@
+use super::{
+    verify_block_body_hash, verify_block_hash, verify_block_v2_with_proofs,
+    verify_trusted_block_v2_with_proofs_unchecked, FinalityThreshold,
+};
+use ltypeset::{
+    chain::{Block, BlockV2, BlockWithProofs, ChainNameDigest, EraConsensusInfo, EraId},
+    verification::VerificationError,
+};
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns consensus information for era subsequent to a switch block, otherwise `None`.
+///
+/// N.B. Errors if total weight of declared validator set overflows.
+///
+/// # Arguments
+///
+/// * `block` - A block, which if it is a switch block, declares next era's validator set.
+///
+pub fn get_next_era_consensus_info(
+    block: &BlockV2,
+) -> Result<Option<EraConsensusInfo>, VerificationError> {
+    block
+        .header()
+        .era_end()
+        .as_ref()
+        .map(|era_end| {
+            EraConsensusInfo::new(
+                EraId::new(block.header().era_id().inner() + 1),
+                era_end.next_era_validator_weights().to_owned(),
+            )
+        })
+        .transpose()
+}
+
+/// Returns validator set in force for blocks subsequent to a trusted block.
+///
+/// N.B. Validator set is declared by trusted block if it is a switch block, otherwise by switch block
+/// referenced by trusted block's header, i.e. it is authenticated by hash chaining from trusted block.
+///
+/// # Arguments
+///
+/// * `trusted_block_with_proofs` - Block whose hash is trusted by caller.
+/// * `switch_block_with_proofs` - Trusted block if it is a switch block, otherwise last switch block that it references.
+///
+pub fn get_trusted_era_consensus_info(
+    trusted_block_with_proofs: &BlockWithProofs,
+    switch_block_with_proofs: &BlockWithProofs,
+) -> Result<EraConsensusInfo, VerificationError> {
+    // BL-001: Verify block hashes, thereby binding switch block to trusted block.
+    verify_block_hash(trusted_block_with_proofs.block())?;
+    verify_block_hash(switch_block_with_proofs.block())?;
+    let trusted_block = get_block_v2(trusted_block_with_proofs)?;
+    let switch_block = get_block_v2(switch_block_with_proofs)?;
+    let switch_block_hash = match trusted_block_with_proofs.block().is_switch() {
+        true => Some(*trusted_block.hash()),
+        false => *trusted_block.header().last_switch_block_hash(),
+    };
+    if switch_block_hash != Some(*switch_block.hash()) {
+        return Err(VerificationError::SwitchBlockHashMismatch);
+    }
+
+    let era_consensus_info = match get_next_era_consensus_info(switch_block)? {
+        Some(inner) => inner,
+        None => return Err(VerificationError::PreviousNotASwitchBlock),
+    };
+
+    // BL-002: Verify that validator set is that of era of blocks subsequent to trusted block.
+    let era_id = match trusted_block_with_proofs.block().is_switch() {
+        true => EraId::new(trusted_block.header().era_id().inner() + 1),
+        false => trusted_block.header().era_id().to_owned(),
+    };
+    if era_consensus_info.era_id() != &era_id {
+        return Err(VerificationError::EraMismatch);
+    }
+
+    Ok(era_consensus_info)
+}
+
+/// Verifies a chain of version two blocks by walking forwards from a trusted block across era transitions.
+///
+/// Returns final verified block plus validator set in force for blocks subsequent to it.
+///
+/// # Arguments
+///
+/// * `trusted_block_with_proofs` - Block from which to start walking.
+/// * `era_consensus_info` - Validator set in force for blocks subsequent to trusted block.
+/// * `descendants` - Contiguous blocks following trusted block ordered by height.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+///
+pub fn verify_chain_v2_with_proofs(
+    trusted_block_with_proofs: BlockWithProofs,
+    era_consensus_info: EraConsensusInfo,
+    descendants: Vec<BlockWithProofs>,
+    chain_name_digest: ChainNameDigest,
+    finality_threshold: FinalityThreshold,
+) -> Result<(BlockWithProofs, EraConsensusInfo), VerificationError> {
+    // Verify trusted block, i.e. without finality as its hash is trusted.
+    verify_trusted_block_v2_with_proofs_unchecked(
+        trusted_block_with_proofs.clone(),
+        chain_name_digest,
+    )?;
+    let mut era_consensus_info = era_consensus_info;
+    let mut tip = trusted_block_with_proofs;
+
+    // Verify descendants against validator set declared by most recent switch block.
+    for block_with_proofs in descendants {
+        era_consensus_info = verify_child_block_v2_with_proofs(
+            &tip,
+            block_with_proofs.clone(),
+            chain_name_digest,
+            era_consensus_info,
+            finality_threshold,
+        )?;
+        tip = block_with_proofs;
+    }
+
+    Ok((tip, era_consensus_info))
+}
+
+/// Verifies a version two block as a child of a verified block, i.e. extends a verified chain by one block.
+///
+/// Returns validator set in force for blocks subsequent to verified block, i.e. as declared by it if it is a switch block.
+///
+/// # Arguments
+///
+/// * `parent_block_with_proofs` - Previously verified block.
+/// * `block_with_proofs` - Child of previously verified block plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `era_consensus_info` - Validator set in force for child block.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+///
+pub fn verify_child_block_v2_with_proofs(
+    parent_block_with_proofs: &BlockWithProofs,
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+) -> Result<EraConsensusInfo, VerificationError> {
+    // Verify that block is a child of its parent.
+    let block = get_block_v2(&block_with_proofs)?;
+    verify_linkage(&[get_block_v2(parent_block_with_proofs)?, block])?;
+
+    // Verify that block is from era of validator set.
+    if block.header().era_id() != era_consensus_info.era_id() {
+        return Err(VerificationError::EraMismatch);
+    }
+
+    // Verify block against validator set.
+    let next_era_consensus_info = get_next_era_consensus_info(block)?;
+    verify_block_v2_with_proofs(
+        block_with_proofs,
+        chain_name_digest,
+        &era_consensus_info,
+        finality_threshold,
+    )?;
+
+    Ok(next_era_consensus_info.unwrap_or(era_consensus_info))
+}
+
+/// Verifies a contiguous range of version two blocks by hash chaining back from a finalized tip.
+///
+/// N.B. Finality signatures are verified for tip only, ancestors are authenticated by hash chaining
+/// plus their block & body hashes.
+///
+/// # Arguments
+///
+/// * `set_of_blocks_with_proofs` - Contiguous blocks ordered by height.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `era_consensus_info` - Validator set in force for tip, i.e. as declared by previous era's switch block.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+///
+pub fn verify_block_range_v2_with_proofs(
+    set_of_blocks_with_proofs: Vec<BlockWithProofs>,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: &EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+) -> Result<(), VerificationError> {
+    let (tip, ancestors) = match set_of_blocks_with_proofs.split_last() {
+        Some(inner) => inner,
+        None => return Err(VerificationError::EmptyBlockRange),
+    };
+
+    // Verify that each block is a child of its predecessor.
+    verify_linkage(
+        &set_of_blocks_with_proofs
+            .iter()
+            .map(get_block_v2)
+            .collect::<Result<Vec<&BlockV2>, VerificationError>>()?,
+    )?;
+
+    // BL-001: Verify ancestor hashes, thereby binding each ancestor to its child's parent hash.
+    // BL-006: Verify ancestor body hashes, thereby binding each ancestor's body to its header.
+    for block_with_proofs in ancestors {
+        verify_block_hash(block_with_proofs.block())?;
+        verify_block_body_hash(block_with_proofs.block())?;
+    }
+
+    // Verify tip.
+    verify_block_v2_with_proofs(
+        tip.clone(),
+        chain_name_digest,
+        era_consensus_info,
+        finality_threshold,
+    )
+}
+
+/// Verifies a trusted switch block from which a chain of era transitions may be extended.
+///
+/// N.B. Finality is not verified, i.e. switch block must be trusted by caller.
+///
+/// # Arguments
+///
+/// * `block_with_proofs` - Trusted switch block plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+///
+pub fn verify_switch_block_v2_with_proofs(
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+) -> Result<(), VerificationError> {
+    if !block_with_proofs.block().is_switch() {
+        return Err(VerificationError::NotASwitchBlock);
+    }
+
+    verify_trusted_block_v2_with_proofs_unchecked(block_with_proofs, chain_name_digest)
+}
+
+/// Verifies a switch block against validator set declared by previous switch block, i.e. extends a verified chain by one era.
+///
+/// N.B. Previous switch block is assumed to have been verified, e.g. by a previous proof committing to its hash.
+///
+/// # Arguments
+///
+/// * `previous_block_with_proofs` - Previous switch block, which declares validator set of next era.
+/// * `block_with_proofs` - Switch block of next era plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+///
+pub fn verify_era_transition_v2_with_proofs(
+    previous_block_with_proofs: &BlockWithProofs,
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    finality_threshold: FinalityThreshold,
+) -> Result<(), VerificationError> {
+    // BL-001: Verify previous switch block hash, thereby binding declared validator set to it.
+    verify_block_hash(previous_block_with_proofs.block())?;
+    let previous_block = get_block_v2(previous_block_with_proofs)?;
+    let era_consensus_info = match get_next_era_consensus_info(previous_block)? {
+        Some(inner) => inner,
+        None => return Err(VerificationError::PreviousNotASwitchBlock),
+    };
+
+    // Verify that switch block directly succeeds previous switch block, i.e. no era is skipped.
+    let block = get_block_v2(&block_with_proofs)?;
+    if !block_with_proofs.block().is_switch() {
+        return Err(VerificationError::NotASwitchBlock);
+    }
+    if block.header().last_switch_block_hash().as_ref() != Some(previous_block.hash()) {
+        return Err(VerificationError::LastSwitchBlockHashMismatch);
+    }
+    if block.header().era_id().inner() != previous_block.header().era_id().inner() + 1 {
+        return Err(VerificationError::NonContiguousEra);
+    }
+
+    // BL-002: Verify that switch block is from era declared by previous switch block.
+    if block.header().era_id() != era_consensus_info.era_id() {
+        return Err(VerificationError::EraMismatch);
+    }
+
+    verify_block_v2_with_proofs(
+        block_with_proofs,
+        chain_name_digest,
+        &era_consensus_info,
+        finality_threshold,
+    )
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Verifies that each block is a child of its predecessor.
+fn verify_linkage(blocks: &[&BlockV2]) -> Result<(), VerificationError> {
+    for pair in blocks.windows(2) {
+        let (parent, block) = (pair[0], pair[1]);
+        if block.header().parent_hash() != parent.hash() {
+            return Err(VerificationError::ParentHashMismatch);
+        }
+        if block.header().height().inner() != parent.header().height().inner() + 1 {
+            return Err(VerificationError::NonContiguousHeight);
+        }
+    }
+
+    Ok(())
+}
+
+// Destructures inner version two block.
+fn get_block_v2(block_with_proofs: &BlockWithProofs) -> Result<&BlockV2, VerificationError> {
+    match block_with_proofs.block() {
+        Block::V2(inner) => Ok(inner),
+        _ => Err(VerificationError::BlockVersionMismatch),
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_signed_weight, verify_signatories};
+    use lsimulator::{EquivocationConfig, ProtocolUpgradeConfig, Simulator, SimulatorConfig};
+    use ltypeset::{
+        chain::{BlockV2Body, BlockV2Header, ProtocolVersion, TransactionHash, TransactionV2Hash},
+        crypto::Digest,
+        primitives::SemanticVersion,
+    };
+    use ltypeset_utils::fixtures::get_block_with_proofs;
+    use std::collections::BTreeMap;
+
+    // Returns a simulated chain spanning many eras, equivocations & protocol upgrades.
+    fn get_simulated_chain(count: usize) -> (Vec<BlockWithProofs>, ChainNameDigest) {
+        let mut simulator = Simulator::new(SimulatorConfig {
+            equivocations: vec![
+                EquivocationConfig {
+                    era_id: 2,
+                    validator_index: 3,
+                },
+                EquivocationConfig {
+                    era_id: 40,
+                    validator_index: 0,
+                },
+            ],
+            protocol_upgrades: vec![
+                ProtocolUpgradeConfig {
+                    era_id: 3,
+                    protocol_version: ProtocolVersion::new(SemanticVersion::new(2, 1, 0)),
+                },
+                ProtocolUpgradeConfig {
+                    era_id: 60,
+                    protocol_version: ProtocolVersion::new(SemanticVersion::new(2, 2, 0)),
+                },
+            ],
+            validator_count: 7,
+            validator_weights: vec![1, 2, 3, 4, 5, 6, 7],
+            ..Default::default()
+        })
+        .unwrap();
+
+        (
+            simulator.by_ref().take(count).collect(),
+            simulator.chain_name_digest(),
+        )
+    }
+
+    // Returns heights of switch blocks within a chain.
+    fn get_switch_block_heights(chain: &[BlockWithProofs]) -> Vec<usize> {
+        chain
+            .iter()
+            .enumerate()
+            .filter(|(_, block_with_proofs)| {
+                get_next_era_consensus_info(get_block_v2(block_with_proofs).unwrap())
+                    .unwrap()
+                    .is_some()
+            })
+            .map(|(height, _)| height)
+            .collect()
+    }
+
+    #[test]
+    fn test_next_era_consensus_info_is_declared_by_switch_block() {
+        let switch_block = get_block_with_proofs(11);
+        let era_consensus_info = get_next_era_consensus_info(get_block_v2(&switch_block).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(2));
+        assert!(!era_consensus_info.validator_weights().is_empty());
+    }
+
+    #[test]
+    fn test_next_era_consensus_info_is_none_for_non_switch_block() {
+        let block = get_block_with_proofs(12);
+        assert!(get_next_era_consensus_info(get_block_v2(&block).unwrap())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_next_era_signatories_are_declared_by_switch_block() {
+        let switch_block = get_block_with_proofs(22);
+        let era_consensus_info = get_next_era_consensus_info(get_block_v2(&switch_block).unwrap())
+            .unwrap()
+            .unwrap();
+        let block = get_block_with_proofs(23);
+
+        assert_eq!(
+            verify_signatories(block.proofs(), &era_consensus_info),
+            Ok(())
+        );
+        assert!(FinalityThreshold::default().is_exceeded_by(
+            get_signed_weight(block.proofs(), &era_consensus_info).unwrap(),
+            era_consensus_info.total_weight()
+        ));
+    }
+
+    fn get_era_consensus_info(trusted_height: u64, switch_height: u64) -> EraConsensusInfo {
+        get_trusted_era_consensus_info(
+            &get_block_with_proofs(trusted_height),
+            &get_block_with_proofs(switch_height),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_trusted_era_consensus_info_is_declared_by_trusted_switch_block() {
+        assert_eq!(get_era_consensus_info(22, 22).era_id(), &EraId::new(3));
+    }
+
+    #[test]
+    fn test_trusted_era_consensus_info_is_declared_by_referenced_switch_block() {
+        assert_eq!(get_era_consensus_info(24, 22).era_id(), &EraId::new(3));
+    }
+
+    #[test]
+    fn test_error_if_switch_block_is_not_referenced_by_trusted_block() {
+        for (trusted_height, switch_height) in [(24, 11), (22, 11), (24, 24)] {
+            assert_eq!(
+                get_trusted_era_consensus_info(
+                    &get_block_with_proofs(trusted_height),
+                    &get_block_with_proofs(switch_height),
+                ),
+                Err(VerificationError::SwitchBlockHashMismatch)
+            );
+        }
+    }
+
+    #[test]
+    fn test_chain_is_verified_across_era_transitions() {
+        let (tip, era_consensus_info) = verify_chain_v2_with_proofs(
+            get_block_with_proofs(0),
+            get_era_consensus_info(0, 0),
+            (1..=50).map(get_block_with_proofs).collect(),
+            ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert_eq!(tip, get_block_with_proofs(50));
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(5));
+    }
+
+    #[test]
+    fn test_chain_is_verified_from_a_trusted_non_switch_block() {
+        let (tip, era_consensus_info) = verify_chain_v2_with_proofs(
+            get_block_with_proofs(24),
+            get_era_consensus_info(24, 22),
+            (25..=50).map(get_block_with_proofs).collect(),
+            ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert_eq!(tip, get_block_with_proofs(50));
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(5));
+    }
+
+    #[test]
+    fn test_error_if_descendant_of_trusted_block_is_not_finalized() {
+        let block_with_proofs = get_block_with_proofs(25);
+
+        assert_eq!(
+            verify_chain_v2_with_proofs(
+                get_block_with_proofs(24),
+                get_era_consensus_info(24, 22),
+                vec![BlockWithProofs::new(
+                    block_with_proofs.block().to_owned(),
+                    vec![]
+                )],
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::InsufficientSignatureWeight)
+        );
+    }
+
+    #[test]
+    fn test_error_if_finality_threshold_is_not_exceeded() {
+        assert_eq!(
+            verify_chain_v2_with_proofs(
+                get_block_with_proofs(11),
+                get_era_consensus_info(11, 11),
+                vec![get_block_with_proofs(12)],
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::new(1, 1).unwrap(),
+            ),
+            Err(VerificationError::InsufficientSignatureWeight)
+        );
+    }
+
+    #[test]
+    fn test_error_if_chain_is_not_contiguous() {
+        assert_eq!(
+            verify_chain_v2_with_proofs(
+                get_block_with_proofs(11),
+                get_era_consensus_info(11, 11),
+                vec![get_block_with_proofs(13)],
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::ParentHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_body_is_swapped() {
+        let block_with_proofs = get_block_with_proofs(12);
+        let block = get_block_v2(&block_with_proofs).unwrap();
+        let body = BlockV2Body::new(
+            block.body().rewarded_signatures().to_owned(),
+            BTreeMap::from([(
+                0,
+                vec![TransactionHash::V2(TransactionV2Hash::new(Digest::from(
+                    block.hash().inner().as_slice(),
+                )))],
+            )]),
+        );
+        let block = BlockV2::new(body, block.hash().to_owned(), block.header().to_owned());
+
+        assert_eq!(
+            verify_chain_v2_with_proofs(
+                get_block_with_proofs(11),
+                get_era_consensus_info(11, 11),
+                vec![BlockWithProofs::new(
+                    Block::new_v2(block),
+                    block_with_proofs.proofs().to_owned(),
+                )],
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::BlockBodyHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_child_block_is_verified() {
+        let era_consensus_info = get_era_consensus_info(22, 22);
+
+        assert_eq!(
+            verify_child_block_v2_with_proofs(
+                &get_block_with_proofs(23),
+                get_block_with_proofs(24),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                era_consensus_info.clone(),
+                FinalityThreshold::default(),
+            ),
+            Ok(era_consensus_info)
+        );
+    }
+
+    #[test]
+    fn test_child_switch_block_declares_next_era_consensus_info() {
+        let era_consensus_info = verify_child_block_v2_with_proofs(
+            &get_block_with_proofs(10),
+            get_block_with_proofs(11),
+            ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            get_era_consensus_info(10, 0),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(2));
+    }
+
+    #[test]
+    fn test_error_if_child_block_is_not_a_child() {
+        assert_eq!(
+            verify_child_block_v2_with_proofs(
+                &get_block_with_proofs(23),
+                get_block_with_proofs(25),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                get_era_consensus_info(22, 22),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::ParentHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_child_block_is_from_another_era() {
+        let era_consensus_info = get_era_consensus_info(11, 11);
+
+        assert_eq!(
+            verify_child_block_v2_with_proofs(
+                &get_block_with_proofs(22),
+                get_block_with_proofs(23),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                era_consensus_info,
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::EraMismatch)
+        );
+    }
+
+    #[test]
+    fn test_block_range_is_verified() {
+        assert_eq!(
+            verify_block_range_v2_with_proofs(
+                (11..=22).map(get_block_with_proofs).collect(),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11, 11),
+                FinalityThreshold::default(),
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_range_tip_is_not_from_era_of_validator_set() {
+        assert_eq!(
+            verify_block_range_v2_with_proofs(
+                (11..=23).map(get_block_with_proofs).collect(),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11, 11),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::EraMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_range_is_empty() {
+        assert_eq!(
+            verify_block_range_v2_with_proofs(
+                vec![],
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11, 11),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::EmptyBlockRange)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_range_is_out_of_order() {
+        let mut set_of_blocks_with_proofs: Vec<BlockWithProofs> =
+            (11..=13).map(get_block_with_proofs).collect();
+        set_of_blocks_with_proofs.swap(1, 2);
+
+        assert_eq!(
+            verify_block_range_v2_with_proofs(
+                set_of_blocks_with_proofs,
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11, 11),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::ParentHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_range_ancestor_is_tampered() {
+        let mut set_of_blocks_with_proofs: Vec<BlockWithProofs> =
+            (11..=13).map(get_block_with_proofs).collect();
+        let block_with_proofs = get_block_with_proofs(12);
+        let block = get_block_v2(&block_with_proofs).unwrap();
+        let header = block.header();
+        set_of_blocks_with_proofs[1] = BlockWithProofs::new(
+            Block::new_v2(BlockV2::new(
+                block.body().to_owned(),
+                block.hash().to_owned(),
+                BlockV2Header::new(
+                    header.accumulated_seed().to_owned(),
+                    header.body_hash().to_owned(),
+                    header.current_gas_price().to_owned(),
+                    header.era_end().to_owned(),
+                    header.era_id().to_owned(),
+                    header.height().to_owned(),
+                    header.last_switch_block_hash().to_owned(),
+                    header.parent_hash().to_owned(),
+                    header.proposer().to_owned(),
+                    header.protocol_version().to_owned(),
+                    !header.random_bit(),
+                    header.state_root_hash().to_owned(),
+                    header.timestamp().to_owned(),
+                ),
+            )),
+            block_with_proofs.proofs().to_owned(),
+        );
+
+        assert_eq!(
+            verify_block_range_v2_with_proofs(
+                set_of_blocks_with_proofs,
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11, 11),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::BlockHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_range_ancestor_body_is_tampered() {
+        let mut set_of_blocks_with_proofs: Vec<BlockWithProofs> =
+            (11..=13).map(get_block_with_proofs).collect();
+        let block_with_proofs = get_block_with_proofs(12);
+        let block = get_block_v2(&block_with_proofs).unwrap();
+        let body = BlockV2Body::new(
+            block.body().rewarded_signatures().to_owned(),
+            BTreeMap::from([(
+                0,
+                vec![TransactionHash::V2(TransactionV2Hash::new(Digest::from(
+                    block.hash().inner().as_slice(),
+                )))],
+            )]),
+        );
+        set_of_blocks_with_proofs[1] = BlockWithProofs::new(
+            Block::new_v2(BlockV2::new(
+                body,
+                block.hash().to_owned(),
+                block.header().to_owned(),
+            )),
+            block_with_proofs.proofs().to_owned(),
+        );
+
+        assert_eq!(
+            verify_block_range_v2_with_proofs(
+                set_of_blocks_with_proofs,
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11, 11),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::BlockBodyHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_switch_block_is_verified() {
+        assert_eq!(
+            verify_switch_block_v2_with_proofs(
+                get_block_with_proofs(11),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_if_trusted_block_is_not_a_switch_block() {
+        assert_eq!(
+            verify_switch_block_v2_with_proofs(
+                get_block_with_proofs(12),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            ),
+            Err(VerificationError::NotASwitchBlock)
+        );
+    }
+
+    #[test]
+    fn test_era_transitions_are_verified() {
+        for (previous, next) in [(11, 22), (22, 33), (33, 44)] {
+            assert_eq!(
+                verify_era_transition_v2_with_proofs(
+                    &get_block_with_proofs(previous),
+                    get_block_with_proofs(next),
+                    ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                    FinalityThreshold::default(),
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_if_era_transition_skips_an_era() {
+        assert_eq!(
+            verify_era_transition_v2_with_proofs(
+                &get_block_with_proofs(11),
+                get_block_with_proofs(33),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::LastSwitchBlockHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_era_transition_is_to_a_non_contiguous_era() {
+        let block_with_proofs = get_block_with_proofs(22);
+        let block = get_block_v2(&block_with_proofs).unwrap();
+        let header = block.header();
+        let header = BlockV2Header::new(
+            header.accumulated_seed().to_owned(),
+            header.body_hash().to_owned(),
+            header.current_gas_price().to_owned(),
+            header.era_end().to_owned(),
+            EraId::new(header.era_id().inner() + 1),
+            header.height().to_owned(),
+            header.last_switch_block_hash().to_owned(),
+            header.parent_hash().to_owned(),
+            header.proposer().to_owned(),
+            header.protocol_version().to_owned(),
+            header.random_bit().to_owned(),
+            header.state_root_hash().to_owned(),
+            header.timestamp().to_owned(),
+        );
+        let block = BlockV2::new(block.body().to_owned(), block.hash().to_owned(), header);
+
+        assert_eq!(
+            verify_era_transition_v2_with_proofs(
+                &get_block_with_proofs(11),
+                BlockWithProofs::new(Block::new_v2(block), block_with_proofs.proofs().to_owned()),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::NonContiguousEra)
+        );
+    }
+
+    #[test]
+    fn test_error_if_era_transition_is_to_a_non_switch_block() {
+        assert_eq!(
+            verify_era_transition_v2_with_proofs(
+                &get_block_with_proofs(11),
+                get_block_with_proofs(12),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::NotASwitchBlock)
+        );
+    }
+
+    #[test]
+    fn test_error_if_era_transition_is_from_a_non_switch_block() {
+        assert_eq!(
+            verify_era_transition_v2_with_proofs(
+                &get_block_with_proofs(12),
+                get_block_with_proofs(22),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::PreviousNotASwitchBlock)
+        );
+    }
+
+    #[test]
+    fn test_simulated_chain_is_verified_at_scale() {
+        let (mut chain, chain_name_digest) = get_simulated_chain(1_000);
+        let trusted = chain.remove(0);
+        let (tip, era_consensus_info) = verify_chain_v2_with_proofs(
+            trusted.clone(),
+            get_trusted_era_consensus_info(&trusted, &trusted).unwrap(),
+            chain,
+            chain_name_digest,
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert_eq!(tip.block().height().inner(), 999);
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(100));
+    }
+
+    #[test]
+    fn test_simulated_block_ranges_and_era_transitions_are_verified_at_scale() {
+        let (chain, chain_name_digest) = get_simulated_chain(1_000);
+        let switch_block_heights = get_switch_block_heights(&chain);
+        assert_eq!(switch_block_heights.len(), 100);
+
+        for pair in switch_block_heights.windows(2) {
+            let (previous, next) = (pair[0], pair[1]);
+            let era_consensus_info =
+                get_trusted_era_consensus_info(&chain[previous], &chain[previous]).unwrap();
+            assert_eq!(
+                verify_block_range_v2_with_proofs(
+                    chain[previous..=next].to_vec(),
+                    chain_name_digest,
+                    &era_consensus_info,
+                    FinalityThreshold::default(),
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                verify_era_transition_v2_with_proofs(
+                    &chain[previous],
+                    chain[next].to_owned(),
+                    chain_name_digest,
+                    FinalityThreshold::default(),
+                ),
+                Ok(())
+            );
+        }
+    }
+}
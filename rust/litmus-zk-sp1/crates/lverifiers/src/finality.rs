@@ -0,0 +1,226 @@
+use ltypeset::{
+    chain::{BlockSignature, EraConsensusInfo, Motes, ValidatorID},
+    verification::VerificationError,
+};
+use std::collections::BTreeSet;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Fraction of an era's total validator weight that finality signatures must exceed.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct FinalityThreshold {
+    /// Fraction numerator.
+    numerator: u64,
+
+    /// Fraction denominator.
+    denominator: u64,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl FinalityThreshold {
+    /// Constructor: returns a threshold of `numerator / denominator`, erroring if it is not within range [0, 1].
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator` - Fraction numerator.
+    /// * `denominator` - Fraction denominator.
+    ///
+    pub fn new(numerator: u64, denominator: u64) -> Result<Self, VerificationError> {
+        if denominator == 0 || numerator > denominator {
+            return Err(VerificationError::InvalidFinalityThreshold);
+        }
+
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl FinalityThreshold {
+    pub fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl FinalityThreshold {
+    /// Predicate: does signed weight strictly exceed threshold fraction of total weight ?
+    ///
+    /// # Arguments
+    ///
+    /// * `signed_weight` - Accumulated weight of finality signatories.
+    /// * `total_weight` - Total weight of era validator set.
+    ///
+    pub fn is_exceeded_by(&self, signed_weight: Motes, total_weight: Motes) -> bool {
+        // N.B. cross multiply in u128 space to avoid both overflow & rounding.
+        signed_weight.inner() as u128 * self.denominator as u128
+            > total_weight.inner() as u128 * self.numerator as u128
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl Default for FinalityThreshold {
+    /// Byzantine fault tolerance threshold, i.e. more than 2/3 of total weight.
+    fn default() -> Self {
+        Self {
+            numerator: 2,
+            denominator: 3,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns accumulated weight of era validators that issued a finality signature.
+///
+/// N.B. Signatories outside of era validator set carry no weight and each validator is counted once.
+/// Errors if accumulated weight overflows.
+///
+/// # Arguments
+///
+/// * `proofs` - Set of finality signatures issued over a block.
+/// * `era_consensus_info` - Era validator set plus respective weights.
+///
+pub fn get_signed_weight(
+    proofs: &[BlockSignature],
+    era_consensus_info: &EraConsensusInfo,
+) -> Result<Motes, VerificationError> {
+    let signatories: BTreeSet<ValidatorID> = proofs
+        .iter()
+        .map(|p| p.verification_key().to_owned())
+        .collect();
+
+    signatories
+        .iter()
+        .filter_map(|validator_id| era_consensus_info.get_validator_weight(validator_id))
+        .try_fold(Motes::MIN, |acc, weight| acc.checked_add(weight))
+        .ok_or(VerificationError::WeightOverflow)
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::{
+        chain::{EraId, ValidatorWeight},
+        crypto::{Signature, VerificationKey},
+    };
+
+    const SIG_ED25519_TAGGED_HEX: &str = "012fa8e929a7514496545d098e86841463ef66358ff0930073fde3b138f66a2cef5304d884baa693a971d002d7e071f658fb16de8c1e5c80ba5ecea8b3866f8106";
+    const VKEY_SET: [&str; 4] = [
+        "016c07fa407f46f7381bb424fbe18c320e8184a7d3e4360acb38d2d58172a9028a",
+        "01706f36a2ebfccea720b49a6424c196cf0bb7aa929f39842975865848b87773ef",
+        "018b2bda41097182c387b72c41a490984aad26a64bc75cd926dee7f0b8d82b4e43",
+        "019b5bd3e7f3c2ba280bb264928ba0d18aab832eb92714d9a455e7737e976c8a41",
+    ];
+    const VKEY_OUTSIDER: &str =
+        "01f0aaadbf1ef00a83e161eaccadaf4e499d9730a09e8979cb9e8c2d3fc4d8b6f7";
+
+    fn get_era_consensus_info() -> EraConsensusInfo {
+        EraConsensusInfo::new(
+            EraId::new(1),
+            VKEY_SET
+                .iter()
+                .zip([10, 20, 30, 40])
+                .map(|(vkey, weight)| {
+                    ValidatorWeight::new(VerificationKey::from(*vkey), Motes::new(weight))
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn get_proofs(vkeys: &[&str]) -> Vec<BlockSignature> {
+        vkeys
+            .iter()
+            .map(|vkey| {
+                BlockSignature::new(
+                    Signature::from(SIG_ED25519_TAGGED_HEX),
+                    VerificationKey::from(*vkey),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_default_threshold_is_two_thirds() {
+        let threshold = FinalityThreshold::default();
+        assert_eq!((threshold.numerator(), threshold.denominator()), (2, 3));
+    }
+
+    #[test]
+    fn test_threshold_must_be_strictly_exceeded() {
+        let threshold = FinalityThreshold::default();
+        assert!(!threshold.is_exceeded_by(Motes::new(66), Motes::new(99)));
+        assert!(threshold.is_exceeded_by(Motes::new(67), Motes::new(99)));
+        assert!(!threshold.is_exceeded_by(Motes::new(0), Motes::new(0)));
+    }
+
+    #[test]
+    fn test_threshold_does_not_overflow() {
+        let threshold = FinalityThreshold::default();
+        assert!(threshold.is_exceeded_by(Motes::new(u64::MAX), Motes::new(u64::MAX)));
+    }
+
+    #[test]
+    fn test_error_if_threshold_denominator_is_zero() {
+        assert_eq!(
+            FinalityThreshold::new(1, 0),
+            Err(VerificationError::InvalidFinalityThreshold)
+        );
+    }
+
+    #[test]
+    fn test_error_if_threshold_exceeds_one() {
+        assert_eq!(
+            FinalityThreshold::new(4, 3),
+            Err(VerificationError::InvalidFinalityThreshold)
+        );
+    }
+
+    #[test]
+    fn test_signed_weight_of_full_validator_set() {
+        let info = get_era_consensus_info();
+        let weight = get_signed_weight(&get_proofs(&VKEY_SET), &info).unwrap();
+        assert_eq!(weight, info.total_weight());
+    }
+
+    #[test]
+    fn test_signed_weight_ignores_outsiders() {
+        let info = get_era_consensus_info();
+        let weight = get_signed_weight(&get_proofs(&[VKEY_SET[0], VKEY_OUTSIDER]), &info).unwrap();
+        assert_eq!(weight, Motes::new(10));
+    }
+
+    #[test]
+    fn test_signed_weight_counts_each_validator_once() {
+        let info = get_era_consensus_info();
+        let weight = get_signed_weight(&get_proofs(&[VKEY_SET[3], VKEY_SET[3]]), &info).unwrap();
+        assert_eq!(weight, Motes::new(40));
+    }
+}
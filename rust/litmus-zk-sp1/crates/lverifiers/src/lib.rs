@@ -1,9 +1,80 @@
+mod chain;
 mod digests;
+mod finality;
+mod report;
+mod signatories;
+mod signatures;
+mod state;
+mod transactions;
 
-use ltypeset::chain::{Block, BlockHash, BlockWithProofs, ChainNameDigest, EraConsensusInfo};
+pub use chain::{
+    get_next_era_consensus_info, get_trusted_era_consensus_info, verify_block_range_v2_with_proofs,
+    verify_chain_v2_with_proofs, verify_child_block_v2_with_proofs,
+    verify_era_transition_v2_with_proofs, verify_switch_block_v2_with_proofs,
+};
+pub use finality::{get_signed_weight, FinalityThreshold};
+pub use report::{get_block_v2_report, BlockReport, RuleOutcome};
+pub use signatories::{verify_signatories, verify_unique_signatories};
+pub use signatures::verify_finality_signatures;
+pub use state::{verify_state_proof, verify_state_v2_inclusion};
+pub use transactions::{is_transaction_in_block, verify_transaction_v2_inclusion};
 
-pub fn verify_block_v1_with_proofs(_: BlockWithProofs) {
-    unimplemented!("verify_block_v1_with_proofs");
+use ltypeset::{
+    chain::{Block, BlockWithProofs, ChainNameDigest, EraConsensusInfo},
+    crypto::Digest,
+    verification::VerificationError,
+};
+use ltypeset_utils::crypto::{
+    get_hash_for_block, get_hash_for_block_body, get_hash_for_era_consensus_info,
+};
+
+/// Returns digest over an era validator set, i.e. as committed alongside a block verified against it.
+///
+/// # Arguments
+///
+/// * `era_consensus_info` - Era validator set plus respective weights.
+///
+pub fn get_validator_set_digest(era_consensus_info: &EraConsensusInfo) -> Digest {
+    get_hash_for_era_consensus_info(era_consensus_info)
+}
+
+/// Verifies a version one block, i.e. a block produced prior to protocol version 2.0.
+///
+/// # Arguments
+///
+/// * `entity` - Block to be verified.
+/// * `era_consensus_info` - Information pulled from a previous era necessary to tally finality signatures.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+///
+pub fn verify_block_v1_with_proofs(
+    block_with_proofs: BlockWithProofs,
+    era_consensus_info: &EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+) -> Result<(), VerificationError> {
+    // Destructure inner block.
+    let block = match block_with_proofs.block() {
+        Block::V1(inner) => inner,
+        _ => return Err(VerificationError::BlockVersionMismatch),
+    };
+
+    // BL-001: Verify that recomputed block hash is equal to actual block hash.
+    verify_block_hash(block_with_proofs.block())?;
+
+    // BL-002: Verify that block is from era of validator set.
+    if block.header().era_id() != era_consensus_info.era_id() {
+        return Err(VerificationError::EraMismatch);
+    }
+
+    // BL-006: Verify that recomputed block body hash is equal to header body hash.
+    verify_block_body_hash(block_with_proofs.block())?;
+
+    // BL-003 -> BL-005: Verify finality signatures.
+    verify_proofs(
+        &block_with_proofs,
+        &block.get_bytes_for_finality_signature(),
+        era_consensus_info,
+        finality_threshold,
+    )
 }
 
 /// Verifies a version two block.
@@ -13,30 +84,442 @@ pub fn verify_block_v1_with_proofs(_: BlockWithProofs) {
 /// * `entity` - Block to be verified.
 /// * `chain_name_digest` - Digest over name of a blockchain.
 /// * `era_consensus_info` - Information pulled from a previous era necessary to tally finality signatures.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
 ///
 pub fn verify_block_v2_with_proofs(
     block_with_proofs: BlockWithProofs,
     chain_name_digest: ChainNameDigest,
-    era_consensus_info: Option<EraConsensusInfo>,
-) {
+    era_consensus_info: &EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+) -> Result<(), VerificationError> {
+    // Destructure inner block.
+    let block = match block_with_proofs.block() {
+        Block::V2(inner) => inner,
+        _ => return Err(VerificationError::BlockVersionMismatch),
+    };
+
+    // BL-001: Verify that recomputed block hash is equal to actual block hash.
+    verify_block_hash(block_with_proofs.block())?;
+
+    // BL-002: Verify that block is from era of validator set.
+    if block.header().era_id() != era_consensus_info.era_id() {
+        return Err(VerificationError::EraMismatch);
+    }
+
+    // BL-006: Verify that recomputed block body hash is equal to header body hash.
+    verify_block_body_hash(block_with_proofs.block())?;
+
+    // BL-003 -> BL-005: Verify finality signatures.
+    verify_proofs(
+        &block_with_proofs,
+        &block.get_bytes_for_finality_signature(&chain_name_digest),
+        era_consensus_info,
+        finality_threshold,
+    )
+}
+
+/// Verifies a version two block trusted out of band, e.g. a light client's trusted switch block.
+///
+/// N.B. Finality is NOT verified: signatories are neither checked against an era validator set
+/// (BL-003) nor tallied (BL-005), hence block must be trusted by caller.
+///
+/// # Arguments
+///
+/// * `block_with_proofs` - Trusted block plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+///
+pub fn verify_trusted_block_v2_with_proofs_unchecked(
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+) -> Result<(), VerificationError> {
     // Destructure inner block.
     let block = match block_with_proofs.block() {
         Block::V2(inner) => inner,
-        _ => panic!("Invalid block version."),
+        _ => return Err(VerificationError::BlockVersionMismatch),
     };
 
     // BL-001: Verify that recomputed block hash is equal to actual block hash.
-    assert_eq!(block.hash(), &BlockHash::from(block.get_bytes_for_digest()));
+    verify_block_hash(block_with_proofs.block())?;
 
-    // BL-002: Verify that switch block is not from a previous era.
+    // BL-006: Verify that recomputed block body hash is equal to header body hash.
+    verify_block_body_hash(block_with_proofs.block())?;
 
-    // BL-003: Verify that block signatory is an era signatory.
+    // BL-003: Verify that each block signatory is unique.
+    verify_unique_signatories(block_with_proofs.proofs())?;
 
     // BL-004: Verify that each finality signature is valid.
-    let msg = block.get_bytes_for_finality_signature(&chain_name_digest);
-    for proof in block_with_proofs.proofs() {
-        proof.signature().verify(proof.verification_key(), &msg);
+    verify_finality_signatures(
+        block_with_proofs.proofs(),
+        &block.get_bytes_for_finality_signature(&chain_name_digest),
+    )
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// BL-001: Verifies that recomputed block hash is equal to actual block hash.
+fn verify_block_hash(block: &Block) -> Result<(), VerificationError> {
+    if block.hash() != &get_hash_for_block(block) {
+        return Err(VerificationError::BlockHashMismatch);
+    }
+
+    Ok(())
+}
+
+// BL-006: Verifies that recomputed block body hash is equal to header body hash.
+fn verify_block_body_hash(block: &Block) -> Result<(), VerificationError> {
+    let body_hash = match block {
+        Block::V1(inner) => inner.header().body_hash(),
+        Block::V2(inner) => inner.header().body_hash(),
+    };
+    if body_hash != &get_hash_for_block_body(block) {
+        return Err(VerificationError::BlockBodyHashMismatch);
     }
 
+    Ok(())
+}
+
+// BL-003 -> BL-005: Verifies a block's finality signatures against signed over message.
+fn verify_proofs(
+    block_with_proofs: &BlockWithProofs,
+    msg: &[u8],
+    era_consensus_info: &EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+) -> Result<(), VerificationError> {
+    // BL-003: Verify that each block signatory is a unique era signatory.
+    verify_signatories(block_with_proofs.proofs(), era_consensus_info)?;
+
+    // BL-004: Verify that each finality signature is valid.
+    // N.B. cycle tracker markers are reported by zk-vm executor, see `--execute`.
+    #[cfg(feature = "cycle-tracker")]
+    println!("cycle-tracker-report-start: BL-004");
+    let verified = verify_finality_signatures(block_with_proofs.proofs(), msg);
+    #[cfg(feature = "cycle-tracker")]
+    println!("cycle-tracker-report-end: BL-004");
+    verified?;
+
     // BL-005: Verify that weight of finality signatures is sufficient.
+    let signed_weight = get_signed_weight(block_with_proofs.proofs(), era_consensus_info)?;
+    if !finality_threshold.is_exceeded_by(signed_weight, era_consensus_info.total_weight()) {
+        return Err(VerificationError::InsufficientSignatureWeight);
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        get_next_era_consensus_info, get_trusted_era_consensus_info, verify_chain_v2_with_proofs,
+    };
+    use ed25519_consensus::SigningKey;
+    use ltypeset::{
+        chain::{
+            BlockHash, BlockHeight, BlockSignature, BlockV1, BlockV1Body, BlockV1Header,
+            BlockV1HeaderFields, EraId, Motes, ProtocolVersion, ValidatorWeight,
+        },
+        crypto::{Digest, Signature, VerificationKey},
+        primitives::{SemanticVersion, Timestamp},
+    };
+    use ltypeset_utils::generator::{get_signing_keys, ChainGenerator};
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_signing_key() -> SigningKey {
+        SigningKey::from([7_u8; 32])
+    }
+
+    fn get_verification_key() -> VerificationKey {
+        let mut tagged = vec![1_u8];
+        tagged.extend_from_slice(get_signing_key().verification_key().as_bytes());
+
+        VerificationKey::from(tagged)
+    }
+
+    fn get_block_v1(hash: BlockHash) -> BlockV1 {
+        let block = Block::new_v1(get_block_v1_with_body_hash(hash, Digest::from(DIGEST_HEX)));
+
+        get_block_v1_with_body_hash(hash, get_hash_for_block_body(&block))
+    }
+
+    fn get_block_v1_with_body_hash(hash: BlockHash, body_hash: Digest) -> BlockV1 {
+        BlockV1::new(
+            BlockV1Body::new(vec![], get_verification_key(), vec![]),
+            hash,
+            BlockV1Header::new(BlockV1HeaderFields {
+                accumulated_seed: Digest::from(DIGEST_HEX),
+                body_hash,
+                era_end: None,
+                era_id: EraId::new(5),
+                height: BlockHeight::new(100),
+                parent_hash: BlockHash::from(DIGEST_HEX),
+                protocol_version: ProtocolVersion::new(SemanticVersion::new(1, 5, 8)),
+                random_bit: true,
+                state_root_hash: Digest::from(DIGEST_HEX),
+                timestamp: Timestamp::from(1_700_000_000_000_u128),
+            }),
+        )
+    }
+
+    fn get_block_v1_with_proofs() -> BlockWithProofs {
+        let block = Block::new_v1(get_block_v1(BlockHash::from(DIGEST_HEX)));
+        let block = Block::new_v1(get_block_v1(get_hash_for_block(&block)));
+        let msg = match &block {
+            Block::V1(inner) => inner.get_bytes_for_finality_signature(),
+            _ => unreachable!(),
+        };
+        let mut tagged_sig = vec![1_u8];
+        tagged_sig.extend_from_slice(&get_signing_key().sign(&msg).to_bytes());
+
+        BlockWithProofs::new(
+            block,
+            vec![BlockSignature::new(
+                Signature::from(tagged_sig),
+                get_verification_key(),
+            )],
+        )
+    }
+
+    fn get_era_consensus_info() -> EraConsensusInfo {
+        EraConsensusInfo::new(
+            EraId::new(5),
+            vec![ValidatorWeight::new(
+                get_verification_key(),
+                Motes::new(100),
+            )],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_block_v1_with_proofs_is_verified() {
+        assert_eq!(
+            verify_block_v1_with_proofs(
+                get_block_v1_with_proofs(),
+                &get_era_consensus_info(),
+                FinalityThreshold::default(),
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_v1_is_not_from_era_of_validator_set() {
+        let era_consensus_info = get_era_consensus_info();
+
+        assert_eq!(
+            verify_block_v1_with_proofs(
+                get_block_v1_with_proofs(),
+                &EraConsensusInfo::new(
+                    EraId::new(6),
+                    era_consensus_info.validator_weights().to_owned(),
+                )
+                .unwrap(),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::EraMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_v1_hash_is_invalid() {
+        let block_with_proofs = get_block_v1_with_proofs();
+        let block = Block::new_v1(get_block_v1(BlockHash::from(DIGEST_HEX)));
+
+        assert_eq!(
+            verify_block_v1_with_proofs(
+                BlockWithProofs::new(block, block_with_proofs.proofs().to_owned()),
+                &get_era_consensus_info(),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::BlockHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_v1_body_hash_is_invalid() {
+        let block_with_proofs = get_block_v1_with_proofs();
+        let body_hash = Digest::from(DIGEST_HEX);
+        let block = Block::new_v1(get_block_v1_with_body_hash(
+            BlockHash::from(DIGEST_HEX),
+            body_hash,
+        ));
+        let block = Block::new_v1(get_block_v1_with_body_hash(
+            get_hash_for_block(&block),
+            body_hash,
+        ));
+
+        assert_eq!(
+            verify_block_v1_with_proofs(
+                BlockWithProofs::new(block, block_with_proofs.proofs().to_owned()),
+                &get_era_consensus_info(),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::BlockBodyHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_v1_finality_signature_is_invalid() {
+        let block_with_proofs = get_block_v1_with_proofs();
+        let proof = &block_with_proofs.proofs()[0];
+        let mut tagged_sig = vec![1_u8];
+        tagged_sig.extend_from_slice(&get_signing_key().sign(&[0_u8; 32]).to_bytes());
+
+        assert_eq!(
+            verify_block_v1_with_proofs(
+                BlockWithProofs::new(
+                    block_with_proofs.block().to_owned(),
+                    vec![BlockSignature::new(
+                        Signature::from(tagged_sig),
+                        proof.verification_key().to_owned(),
+                    )],
+                ),
+                &get_era_consensus_info(),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::InvalidFinalitySignature(
+                get_verification_key(),
+                Box::new(VerificationError::InvalidSignature)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_v1_is_verified_as_block_v2() {
+        assert_eq!(
+            verify_block_v2_with_proofs(
+                get_block_v1_with_proofs(),
+                ChainNameDigest::new_from_chain_name("casper"),
+                &get_era_consensus_info(),
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::BlockVersionMismatch)
+        );
+    }
+
+    // Returns a synthetic chain of 3 validators per era, rotated through a pool of 4.
+    fn get_synthetic_chain() -> (ChainGenerator, Vec<BlockWithProofs>) {
+        let mut generator = ChainGenerator::new("cspr-synthetic", 5, 3, get_signing_keys(4));
+        let chain = generator.by_ref().take(16).collect();
+
+        (generator, chain)
+    }
+
+    fn get_synthetic_era_consensus_info(switch_block: &BlockWithProofs) -> EraConsensusInfo {
+        match switch_block.block() {
+            Block::V2(inner) => get_next_era_consensus_info(inner).unwrap().unwrap(),
+            Block::V1(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_synthetic_chain_is_verified_across_validator_rotations() {
+        let (generator, mut chain) = get_synthetic_chain();
+        let trusted = chain.remove(0);
+        let (tip, era_consensus_info) = verify_chain_v2_with_proofs(
+            trusted.clone(),
+            get_trusted_era_consensus_info(&trusted, &trusted).unwrap(),
+            chain,
+            generator.chain_name_digest(),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert_eq!(tip.block().height().inner(), 15);
+        assert_eq!(era_consensus_info.era_id(), &EraId::new(4));
+    }
+
+    #[test]
+    fn test_error_if_signed_weight_is_insufficient() {
+        let (generator, chain) = get_synthetic_chain();
+        let era_consensus_info = get_synthetic_era_consensus_info(&chain[5]);
+        let proofs = chain[7].proofs();
+
+        // N.B. era validator weights are 1, 2 & 3 units, i.e. 5 of 6 units exceeds 2/3.
+        for (signatories, expected) in [
+            (&proofs[1..], Ok(())),
+            (
+                &proofs[2..],
+                Err(VerificationError::InsufficientSignatureWeight),
+            ),
+        ] {
+            assert_eq!(
+                verify_block_v2_with_proofs(
+                    BlockWithProofs::new(chain[7].block().to_owned(), signatories.to_vec()),
+                    generator.chain_name_digest(),
+                    &era_consensus_info,
+                    FinalityThreshold::default(),
+                ),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_if_validly_signed_by_non_era_validator() {
+        let (generator, chain) = get_synthetic_chain();
+        let era_consensus_info = get_synthetic_era_consensus_info(&chain[5]);
+        let outsider = get_signing_keys(4)
+            .into_iter()
+            .find(|signing_key| !era_consensus_info.is_validator(&signing_key.verification_key()))
+            .unwrap();
+        let msg = match chain[7].block() {
+            Block::V2(inner) => {
+                inner.get_bytes_for_finality_signature(&generator.chain_name_digest())
+            }
+            Block::V1(_) => unreachable!(),
+        };
+        let mut proofs = chain[7].proofs().to_owned();
+        proofs.push(BlockSignature::new(
+            outsider.sign(&msg),
+            outsider.verification_key(),
+        ));
+
+        assert_eq!(
+            verify_block_v2_with_proofs(
+                BlockWithProofs::new(chain[7].block().to_owned(), proofs),
+                generator.chain_name_digest(),
+                &era_consensus_info,
+                FinalityThreshold::default(),
+            ),
+            Err(VerificationError::NonEraSignatory(
+                outsider.verification_key()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_trusted_block_is_verified_without_era_validator_set() {
+        let (generator, chain) = get_synthetic_chain();
+
+        assert_eq!(
+            verify_trusted_block_v2_with_proofs_unchecked(
+                chain[5].to_owned(),
+                generator.chain_name_digest(),
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_if_trusted_block_finality_signature_is_invalid() {
+        let (generator, chain) = get_synthetic_chain();
+        let mut proofs = chain[7].proofs().to_owned();
+        proofs[0] = chain[6].proofs()[0].to_owned();
+
+        assert!(matches!(
+            verify_trusted_block_v2_with_proofs_unchecked(
+                BlockWithProofs::new(chain[7].block().to_owned(), proofs),
+                generator.chain_name_digest(),
+            ),
+            Err(VerificationError::InvalidFinalitySignature(_, _))
+        ));
+    }
 }
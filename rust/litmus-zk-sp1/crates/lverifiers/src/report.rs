@@ -0,0 +1,406 @@
+use super::{
+    get_signed_weight, verify_block_body_hash, verify_block_hash, verify_signatories,
+    verify_unique_signatories, FinalityThreshold,
+};
+use ltypeset::{
+    chain::{Block, BlockWithProofs, ChainNameDigest, EraConsensusInfo, Motes},
+    verification::VerificationError,
+};
+use std::fmt;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Outcome of evaluating a single verification rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RuleOutcome {
+    /// Rule was satisfied.
+    Passed,
+
+    /// Rule was violated.
+    Failed(VerificationError),
+
+    /// Rule was not evaluated, e.g. because era validator set is unknown.
+    Skipped,
+}
+
+/// Outcome of evaluating each block verification rule, plus a tally of finality signatures.
+///
+/// N.B. Unlike verifiers, which stop upon first violation, every rule is evaluated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockReport {
+    /// Fraction of era weight that finality signatures must exceed.
+    finality_threshold: FinalityThreshold,
+
+    /// Outcomes keyed by rule code, e.g. BL-001, in order of evaluation.
+    outcomes: Vec<(&'static str, RuleOutcome)>,
+
+    /// Number of finality signatures checked.
+    signatures_checked: usize,
+
+    /// Number of finality signatures found to be valid.
+    signatures_valid: usize,
+
+    /// Weight of era validators that issued a valid finality signature.
+    signed_weight: Option<Motes>,
+
+    /// Total weight of era validator set.
+    total_weight: Option<Motes>,
+}
+
+// ------------------------------------------------------------------------
+// Accessors.
+// ------------------------------------------------------------------------
+
+impl BlockReport {
+    pub fn finality_threshold(&self) -> FinalityThreshold {
+        self.finality_threshold
+    }
+
+    pub fn outcomes(&self) -> &[(&'static str, RuleOutcome)] {
+        &self.outcomes
+    }
+
+    pub fn signatures_checked(&self) -> usize {
+        self.signatures_checked
+    }
+
+    pub fn signatures_valid(&self) -> usize {
+        self.signatures_valid
+    }
+
+    pub fn signed_weight(&self) -> Option<Motes> {
+        self.signed_weight
+    }
+
+    pub fn total_weight(&self) -> Option<Motes> {
+        self.total_weight
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl BlockReport {
+    /// Predicate: were no rules violated ?
+    ///
+    /// N.B. Rules may have been skipped, see `is_finality_verified`.
+    pub fn is_verified(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|(_, outcome)| !matches!(outcome, RuleOutcome::Failed(_)))
+    }
+
+    /// Predicate: was weight of finality signatures found to exceed finality threshold, i.e. did BL-005 pass ?
+    pub fn is_finality_verified(&self) -> bool {
+        self.outcomes
+            .iter()
+            .any(|(rule, outcome)| *rule == "BL-005" && outcome == &RuleOutcome::Passed)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Traits.
+// ------------------------------------------------------------------------
+
+impl fmt::Display for RuleOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleOutcome::Passed => write!(f, "passed"),
+            RuleOutcome::Failed(err) => write!(f, "FAILED :: {err}"),
+            RuleOutcome::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+impl From<Result<(), VerificationError>> for RuleOutcome {
+    fn from(value: Result<(), VerificationError>) -> Self {
+        match value {
+            Ok(()) => RuleOutcome::Passed,
+            Err(err) => RuleOutcome::Failed(err),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns outcome of evaluating each verification rule against a version two block.
+///
+/// # Arguments
+///
+/// * `block_with_proofs` - Block to be verified plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `era_consensus_info` - Information pulled from a previous era necessary to tally finality signatures.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+///
+pub fn get_block_v2_report(
+    block_with_proofs: &BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: Option<&EraConsensusInfo>,
+    finality_threshold: FinalityThreshold,
+) -> Result<BlockReport, VerificationError> {
+    // Destructure inner block.
+    let block = match block_with_proofs.block() {
+        Block::V2(inner) => inner,
+        _ => return Err(VerificationError::BlockVersionMismatch),
+    };
+    let mut outcomes = Vec::new();
+
+    // BL-001: Verify that recomputed block hash is equal to actual block hash.
+    outcomes.push((
+        "BL-001",
+        RuleOutcome::from(verify_block_hash(block_with_proofs.block())),
+    ));
+
+    // BL-002: Verify that block is from era of validator set.
+    outcomes.push((
+        "BL-002",
+        match era_consensus_info {
+            Some(era_consensus_info) if block.header().era_id() != era_consensus_info.era_id() => {
+                RuleOutcome::Failed(VerificationError::EraMismatch)
+            }
+            Some(_) => RuleOutcome::Passed,
+            None => RuleOutcome::Skipped,
+        },
+    ));
+
+    // BL-003: Verify that each block signatory is a unique era signatory - N.B. skipped unless
+    // era validator set is known, though duplicate signatories are reported regardless.
+    outcomes.push((
+        "BL-003",
+        match era_consensus_info {
+            Some(era_consensus_info) => RuleOutcome::from(verify_signatories(
+                block_with_proofs.proofs(),
+                era_consensus_info,
+            )),
+            None => match verify_unique_signatories(block_with_proofs.proofs()) {
+                Ok(()) => RuleOutcome::Skipped,
+                Err(err) => RuleOutcome::Failed(err),
+            },
+        },
+    ));
+
+    // BL-004: Verify each finality signature, retaining first failure.
+    let msg = block.get_bytes_for_finality_signature(&chain_name_digest);
+    let mut valid_proofs = Vec::new();
+    let mut outcome = RuleOutcome::Passed;
+    for proof in block_with_proofs.proofs() {
+        match proof.signature().verify(proof.verification_key(), &msg) {
+            Ok(()) => valid_proofs.push(proof.to_owned()),
+            Err(err) => {
+                if outcome == RuleOutcome::Passed {
+                    outcome = RuleOutcome::Failed(VerificationError::InvalidFinalitySignature(
+                        proof.verification_key().to_owned(),
+                        Box::new(err),
+                    ));
+                }
+            }
+        }
+    }
+    outcomes.push(("BL-004", outcome));
+
+    // BL-005: Verify that weight of valid finality signatures is sufficient.
+    let signed_weight = era_consensus_info
+        .map(|inner| get_signed_weight(&valid_proofs, inner))
+        .transpose()?;
+    let total_weight = era_consensus_info.map(EraConsensusInfo::total_weight);
+    outcomes.push((
+        "BL-005",
+        match (signed_weight, total_weight) {
+            (Some(signed_weight), Some(total_weight))
+                if !finality_threshold.is_exceeded_by(signed_weight, total_weight) =>
+            {
+                RuleOutcome::Failed(VerificationError::InsufficientSignatureWeight)
+            }
+            (Some(_), Some(_)) => RuleOutcome::Passed,
+            _ => RuleOutcome::Skipped,
+        },
+    ));
+
+    // BL-006: Verify that recomputed block body hash is equal to header body hash.
+    outcomes.push((
+        "BL-006",
+        RuleOutcome::from(verify_block_body_hash(block_with_proofs.block())),
+    ));
+
+    Ok(BlockReport {
+        finality_threshold,
+        outcomes,
+        signatures_checked: block_with_proofs.proofs().len(),
+        signatures_valid: valid_proofs.len(),
+        signed_weight,
+        total_weight,
+    })
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_next_era_consensus_info, verify_block_v2_with_proofs};
+    use ltypeset::chain::BlockSignature;
+    use ltypeset_utils::fixtures::get_block_with_proofs;
+
+    fn get_chain_name_digest() -> ChainNameDigest {
+        ChainNameDigest::new_from_chain_name("cspr-dev-cctl")
+    }
+
+    fn get_era_consensus_info(height_of_switch_block: u64) -> EraConsensusInfo {
+        match get_block_with_proofs(height_of_switch_block).block() {
+            Block::V2(inner) => get_next_era_consensus_info(inner).unwrap().unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_rule_outcome(report: &BlockReport, rule: &str) -> RuleOutcome {
+        report
+            .outcomes()
+            .iter()
+            .find(|(code, _)| *code == rule)
+            .map(|(_, outcome)| outcome.to_owned())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_that_each_rule_passes_for_a_valid_block() {
+        let block_with_proofs = get_block_with_proofs(24);
+        let era_consensus_info = get_era_consensus_info(22);
+        let report = get_block_v2_report(
+            &block_with_proofs,
+            get_chain_name_digest(),
+            Some(&era_consensus_info),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert!(report.is_verified());
+        assert!(report.is_finality_verified());
+        assert!(report
+            .outcomes()
+            .iter()
+            .all(|(_, outcome)| outcome == &RuleOutcome::Passed));
+        assert_eq!(
+            report.signatures_checked(),
+            block_with_proofs.proofs().len()
+        );
+        assert_eq!(report.signatures_valid(), report.signatures_checked());
+        assert_eq!(
+            report.signed_weight(),
+            Some(get_signed_weight(block_with_proofs.proofs(), &era_consensus_info).unwrap())
+        );
+        assert_eq!(
+            report.total_weight(),
+            Some(era_consensus_info.total_weight())
+        );
+    }
+
+    #[test]
+    fn test_that_era_dependent_rules_are_skipped_without_era_consensus_info() {
+        let report = get_block_v2_report(
+            &get_block_with_proofs(24),
+            get_chain_name_digest(),
+            None,
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert!(report.is_verified());
+        assert!(!report.is_finality_verified());
+        assert_eq!(get_rule_outcome(&report, "BL-002"), RuleOutcome::Skipped);
+        assert_eq!(get_rule_outcome(&report, "BL-003"), RuleOutcome::Skipped);
+        assert_eq!(get_rule_outcome(&report, "BL-005"), RuleOutcome::Skipped);
+        assert_eq!(report.signed_weight(), None);
+    }
+
+    #[test]
+    fn test_that_every_rule_is_evaluated_after_a_failure() {
+        let report = get_block_v2_report(
+            &get_block_with_proofs(24),
+            get_chain_name_digest(),
+            Some(&get_era_consensus_info(11)),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert!(!report.is_verified());
+        assert_eq!(
+            get_rule_outcome(&report, "BL-002"),
+            RuleOutcome::Failed(VerificationError::EraMismatch)
+        );
+        assert_eq!(report.outcomes().len(), 6);
+        assert_eq!(get_rule_outcome(&report, "BL-001"), RuleOutcome::Passed);
+        assert_eq!(get_rule_outcome(&report, "BL-004"), RuleOutcome::Passed);
+    }
+
+    #[test]
+    fn test_that_invalid_signatures_are_not_tallied() {
+        let block_with_proofs = get_block_with_proofs(24);
+        let era_consensus_info = get_era_consensus_info(22);
+        let foreign_proofs = get_block_with_proofs(25).proofs().to_owned();
+        let proofs: Vec<BlockSignature> = block_with_proofs
+            .proofs()
+            .iter()
+            .map(|proof| {
+                foreign_proofs
+                    .iter()
+                    .find(|p| p.verification_key() == proof.verification_key())
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect();
+        let report = get_block_v2_report(
+            &BlockWithProofs::new(block_with_proofs.block().to_owned(), proofs),
+            get_chain_name_digest(),
+            Some(&era_consensus_info),
+            FinalityThreshold::default(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            get_rule_outcome(&report, "BL-004"),
+            RuleOutcome::Failed(VerificationError::InvalidFinalitySignature(_, _))
+        ));
+        assert_eq!(report.signatures_valid(), 0);
+        assert_eq!(
+            get_rule_outcome(&report, "BL-005"),
+            RuleOutcome::Failed(VerificationError::InsufficientSignatureWeight)
+        );
+    }
+
+    #[test]
+    fn test_that_report_agrees_with_verifier() {
+        for (height, height_of_switch_block, finality_threshold) in [
+            (12, 11, FinalityThreshold::default()),
+            (24, 22, FinalityThreshold::default()),
+            (24, 22, FinalityThreshold::new(1, 1).unwrap()),
+        ] {
+            let block_with_proofs = get_block_with_proofs(height);
+            let era_consensus_info = get_era_consensus_info(height_of_switch_block);
+            let report = get_block_v2_report(
+                &block_with_proofs,
+                get_chain_name_digest(),
+                Some(&era_consensus_info),
+                finality_threshold,
+            )
+            .unwrap();
+
+            assert_eq!(
+                report.is_verified(),
+                verify_block_v2_with_proofs(
+                    block_with_proofs,
+                    get_chain_name_digest(),
+                    &era_consensus_info,
+                    finality_threshold,
+                )
+                .is_ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use ltypeset::{
+    chain::{BlockSignature, EraConsensusInfo, ValidatorID},
+    verification::VerificationError,
+};
+use std::collections::BTreeSet;
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Verifies that each finality signatory is unique and a member of era validator set.
+///
+/// # Arguments
+///
+/// * `proofs` - Set of finality signatures issued over a block.
+/// * `era_consensus_info` - Era validator set plus respective weights.
+///
+pub fn verify_signatories(
+    proofs: &[BlockSignature],
+    era_consensus_info: &EraConsensusInfo,
+) -> Result<(), VerificationError> {
+    verify_unique_signatories(proofs)?;
+    for proof in proofs {
+        if !era_consensus_info.is_validator(proof.verification_key()) {
+            return Err(VerificationError::NonEraSignatory(
+                proof.verification_key().to_owned(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies that each finality signatory is unique.
+///
+/// N.B. Does not verify era membership, i.e. only applicable to a block trusted out of band.
+///
+/// # Arguments
+///
+/// * `proofs` - Set of finality signatures issued over a block.
+///
+pub fn verify_unique_signatories(proofs: &[BlockSignature]) -> Result<(), VerificationError> {
+    let mut signatories: BTreeSet<ValidatorID> = BTreeSet::new();
+    for proof in proofs {
+        let validator_id = proof.verification_key();
+        if !signatories.insert(validator_id.to_owned()) {
+            return Err(VerificationError::DuplicateSignatory(
+                validator_id.to_owned(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::{
+        chain::{EraId, Motes, ValidatorWeight},
+        crypto::{Signature, VerificationKey},
+    };
+
+    const SIG_ED25519_TAGGED_HEX: &str = "012fa8e929a7514496545d098e86841463ef66358ff0930073fde3b138f66a2cef5304d884baa693a971d002d7e071f658fb16de8c1e5c80ba5ecea8b3866f8106";
+    const VKEY_SET: [&str; 2] = [
+        "016c07fa407f46f7381bb424fbe18c320e8184a7d3e4360acb38d2d58172a9028a",
+        "01706f36a2ebfccea720b49a6424c196cf0bb7aa929f39842975865848b87773ef",
+    ];
+    const VKEY_OUTSIDER: &str =
+        "01f0aaadbf1ef00a83e161eaccadaf4e499d9730a09e8979cb9e8c2d3fc4d8b6f7";
+
+    fn get_era_consensus_info() -> EraConsensusInfo {
+        EraConsensusInfo::new(
+            EraId::new(1),
+            VKEY_SET
+                .iter()
+                .map(|vkey| ValidatorWeight::new(VerificationKey::from(*vkey), Motes::new(1)))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn get_proofs(vkeys: &[&str]) -> Vec<BlockSignature> {
+        vkeys
+            .iter()
+            .map(|vkey| {
+                BlockSignature::new(
+                    Signature::from(SIG_ED25519_TAGGED_HEX),
+                    VerificationKey::from(*vkey),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_era_signatories_are_accepted() {
+        assert_eq!(
+            verify_signatories(&get_proofs(&VKEY_SET), &get_era_consensus_info()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_membership_is_not_verified_by_uniqueness_check() {
+        assert_eq!(
+            verify_unique_signatories(&get_proofs(&[VKEY_OUTSIDER])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_if_signatory_is_not_an_era_validator() {
+        assert_eq!(
+            verify_signatories(
+                &get_proofs(&[VKEY_SET[0], VKEY_OUTSIDER]),
+                &get_era_consensus_info(),
+            ),
+            Err(VerificationError::NonEraSignatory(VerificationKey::from(
+                VKEY_OUTSIDER
+            )))
+        );
+    }
+
+    #[test]
+    fn test_error_if_signatory_is_duplicated() {
+        assert_eq!(
+            verify_signatories(
+                &get_proofs(&[VKEY_SET[0], VKEY_SET[0]]),
+                &get_era_consensus_info(),
+            ),
+            Err(VerificationError::DuplicateSignatory(
+                VerificationKey::from(VKEY_SET[0])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_error_if_signatory_is_duplicated_without_era_consensus_info() {
+        assert_eq!(
+            verify_unique_signatories(&get_proofs(&[VKEY_SET[1], VKEY_SET[1]])),
+            Err(VerificationError::DuplicateSignatory(
+                VerificationKey::from(VKEY_SET[1])
+            ))
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use ltypeset::{chain::BlockSignature, crypto::verify_signatures, verification::VerificationError};
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Verifies that each finality signature was issued over a block's signed over message.
+///
+/// N.B. ed25519 signatures are verified as a batch. Upon batch failure each signature is
+/// re-verified so as to identify the offending signatory.
+///
+/// # Arguments
+///
+/// * `proofs` - Set of finality signatures issued over a block.
+/// * `msg` - Data over which finality signatures were issued.
+///
+pub fn verify_finality_signatures(
+    proofs: &[BlockSignature],
+    msg: &[u8],
+) -> Result<(), VerificationError> {
+    let batch: Vec<_> = proofs
+        .iter()
+        .map(|proof| (proof.signature(), proof.verification_key()))
+        .collect();
+    if verify_signatures(&batch, msg).is_ok() {
+        return Ok(());
+    }
+
+    for proof in proofs {
+        proof
+            .signature()
+            .verify(proof.verification_key(), msg)
+            .map_err(|err| {
+                VerificationError::InvalidFinalitySignature(
+                    proof.verification_key().to_owned(),
+                    Box::new(err),
+                )
+            })?;
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::chain::{Block, ChainNameDigest};
+    use ltypeset_utils::fixtures::get_block_with_proofs;
+
+    fn get_msg(height: u64) -> Vec<u8> {
+        match get_block_with_proofs(height).block() {
+            Block::V2(inner) => inner.get_bytes_for_finality_signature(
+                &ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_finality_signatures_are_verified() {
+        assert_eq!(
+            verify_finality_signatures(get_block_with_proofs(24).proofs(), &get_msg(24)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_identifies_invalid_finality_signature() {
+        let mut proofs = get_block_with_proofs(24).proofs().to_owned();
+        let foreign = get_block_with_proofs(25)
+            .proofs()
+            .iter()
+            .find(|p| p.verification_key() == proofs[1].verification_key())
+            .unwrap()
+            .to_owned();
+        proofs[1] = foreign;
+
+        assert_eq!(
+            verify_finality_signatures(&proofs, &get_msg(24)),
+            Err(VerificationError::InvalidFinalitySignature(
+                proofs[1].verification_key().to_owned(),
+                Box::new(VerificationError::InvalidSignature),
+            ))
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use super::{verify_block_v2_with_proofs, FinalityThreshold};
+use ltypeset::{
+    chain::{Block, BlockWithProofs, ChainNameDigest, EraConsensusInfo},
+    trie::TrieMerkleProof,
+    verification::VerificationError,
+};
+use ltypeset_utils::trie::get_state_root_hash_for_proof;
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Verifies that a key/value pair is stored within global state as at a block.
+///
+/// # Arguments
+///
+/// * `block` - Block whose state root hash anchors global state.
+/// * `proof` - Proof that a key/value pair is stored within global state.
+///
+pub fn verify_state_proof(block: &Block, proof: &TrieMerkleProof) -> Result<(), VerificationError> {
+    if block.state_root_hash() != &get_state_root_hash_for_proof(proof)? {
+        return Err(VerificationError::StateRootHashMismatch);
+    }
+
+    Ok(())
+}
+
+/// Verifies that a key/value pair is stored within global state as at a verified version two block.
+///
+/// # Arguments
+///
+/// * `block_with_proofs` - Block plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `era_consensus_info` - Validator set in force for block's era.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+/// * `proof` - Proof that a key/value pair is stored within global state.
+///
+pub fn verify_state_v2_inclusion(
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: &EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+    proof: &TrieMerkleProof,
+) -> Result<(), VerificationError> {
+    // BL-001 -> BL-006: Verify block.
+    verify_block_v2_with_proofs(
+        block_with_proofs.clone(),
+        chain_name_digest,
+        era_consensus_info,
+        finality_threshold,
+    )?;
+
+    // GS-001: Verify that key/value pair is anchored by block's state root hash.
+    verify_state_proof(block_with_proofs.block(), proof)
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::{
+        chain::{BlockV2, BlockV2Header},
+        crypto::Digest,
+        trie::{Pointer, TrieMerkleProofStep},
+    };
+    use ltypeset_utils::fixtures::{get_block_with_proofs, get_era_consensus_info};
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_proof() -> TrieMerkleProof {
+        TrieMerkleProof::new(
+            vec![1, 2, 3],
+            vec![TrieMerkleProofStep::Node {
+                hole_index: 1,
+                indexed_pointers_with_hole: vec![(0, Pointer::Node(Digest::from(DIGEST_HEX)))],
+            }],
+            vec![6],
+        )
+    }
+
+    // Returns a fixture block whose state root hash is overridden.
+    fn get_block(state_root_hash: Digest) -> Block {
+        let block_with_proofs = get_block_with_proofs(12);
+        let block = match block_with_proofs.block() {
+            Block::V2(inner) => inner,
+            _ => unreachable!(),
+        };
+        let header = block.header();
+
+        Block::new_v2(BlockV2::new(
+            block.body().to_owned(),
+            block.hash().to_owned(),
+            BlockV2Header::new(
+                header.accumulated_seed().to_owned(),
+                header.body_hash().to_owned(),
+                header.current_gas_price().to_owned(),
+                header.era_end().to_owned(),
+                header.era_id().to_owned(),
+                header.height().to_owned(),
+                header.last_switch_block_hash().to_owned(),
+                header.parent_hash().to_owned(),
+                header.proposer().to_owned(),
+                header.protocol_version().to_owned(),
+                header.random_bit().to_owned(),
+                state_root_hash,
+                header.timestamp().to_owned(),
+            ),
+        ))
+    }
+
+    #[test]
+    fn test_state_proof_is_verified() {
+        let proof = get_proof();
+
+        assert_eq!(
+            verify_state_proof(
+                &get_block(get_state_root_hash_for_proof(&proof).unwrap()),
+                &proof
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_error_if_state_proof_value_is_tampered() {
+        let proof = get_proof();
+        let block = get_block(get_state_root_hash_for_proof(&proof).unwrap());
+        let proof = TrieMerkleProof::new(
+            proof.key().to_owned(),
+            proof.proof_steps().to_owned(),
+            vec![7],
+        );
+
+        assert_eq!(
+            verify_state_proof(&block, &proof),
+            Err(VerificationError::StateRootHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_state_proof_is_not_anchored_by_verified_block() {
+        assert_eq!(
+            verify_state_v2_inclusion(
+                get_block_with_proofs(12),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11),
+                FinalityThreshold::default(),
+                &get_proof(),
+            ),
+            Err(VerificationError::StateRootHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_state_proof_block_is_not_from_era_of_validator_set() {
+        assert_eq!(
+            verify_state_v2_inclusion(
+                get_block_with_proofs(12),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(22),
+                FinalityThreshold::default(),
+                &get_proof(),
+            ),
+            Err(VerificationError::EraMismatch)
+        );
+    }
+}
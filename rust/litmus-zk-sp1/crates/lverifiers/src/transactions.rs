@@ -0,0 +1,181 @@
+use super::{verify_block_v2_with_proofs, FinalityThreshold};
+use ltypeset::{
+    chain::{
+        Block, BlockV2, BlockWithProofs, ChainNameDigest, EraConsensusInfo, TransactionHash,
+        TransactionV2Hash,
+    },
+    verification::VerificationError,
+};
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Returns flag indicating whether a transaction is listed within a block's body under a category.
+///
+/// # Arguments
+///
+/// * `block` - Block whose body lists executed transactions.
+/// * `category` - Category under which transaction was executed, e.g. mint | auction | install-upgrade.
+/// * `transaction_hash` - Hash of a version two transaction.
+///
+pub fn is_transaction_in_block(
+    block: &BlockV2,
+    category: u8,
+    transaction_hash: &TransactionV2Hash,
+) -> bool {
+    block
+        .body()
+        .transactions()
+        .get(&category)
+        .is_some_and(|hashes| hashes.contains(&TransactionHash::V2(transaction_hash.to_owned())))
+}
+
+/// Verifies that a transaction is included within a verified version two block.
+///
+/// # Arguments
+///
+/// * `block_with_proofs` - Block plus finality signatures.
+/// * `chain_name_digest` - Digest over name of a blockchain.
+/// * `era_consensus_info` - Validator set in force for block's era.
+/// * `finality_threshold` - Fraction of era weight that finality signatures must exceed.
+/// * `category` - Category under which transaction was executed.
+/// * `transaction_hash` - Hash of a version two transaction.
+///
+pub fn verify_transaction_v2_inclusion(
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: &EraConsensusInfo,
+    finality_threshold: FinalityThreshold,
+    category: u8,
+    transaction_hash: TransactionV2Hash,
+) -> Result<(), VerificationError> {
+    // BL-001 -> BL-006: Verify block, which also binds body to block hash.
+    verify_block_v2_with_proofs(
+        block_with_proofs.clone(),
+        chain_name_digest,
+        era_consensus_info,
+        finality_threshold,
+    )?;
+
+    // Verify that transaction is listed within block body.
+    let block = match block_with_proofs.block() {
+        Block::V2(inner) => inner,
+        _ => return Err(VerificationError::BlockVersionMismatch),
+    };
+    if !is_transaction_in_block(block, category, &transaction_hash) {
+        return Err(VerificationError::TransactionNotInBlock);
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltypeset::{
+        chain::{BlockV2Body, TransactionV1Hash},
+        crypto::Digest,
+    };
+    use ltypeset_utils::fixtures::{get_block_with_proofs, get_era_consensus_info};
+    use std::collections::BTreeMap;
+
+    const DIGEST_HEX: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    fn get_block_with_transaction(category: u8, transaction_hash: TransactionHash) -> BlockV2 {
+        let block_with_proofs = get_block_with_proofs(12);
+        let block = match block_with_proofs.block() {
+            Block::V2(inner) => inner,
+            _ => unreachable!(),
+        };
+
+        BlockV2::new(
+            BlockV2Body::new(
+                block.body().rewarded_signatures().to_owned(),
+                BTreeMap::from([(category, vec![transaction_hash])]),
+            ),
+            block.hash().to_owned(),
+            block.header().to_owned(),
+        )
+    }
+
+    fn get_transaction_hash() -> TransactionV2Hash {
+        TransactionV2Hash::new(Digest::from(DIGEST_HEX))
+    }
+
+    #[test]
+    fn test_transaction_is_in_block() {
+        let block = get_block_with_transaction(3, TransactionHash::V2(get_transaction_hash()));
+
+        assert!(is_transaction_in_block(&block, 3, &get_transaction_hash()));
+    }
+
+    #[test]
+    fn test_transaction_is_not_in_block_under_other_category() {
+        let block = get_block_with_transaction(3, TransactionHash::V2(get_transaction_hash()));
+
+        assert!(!is_transaction_in_block(&block, 0, &get_transaction_hash()));
+    }
+
+    #[test]
+    fn test_deploy_is_not_a_version_two_transaction() {
+        let block = get_block_with_transaction(
+            3,
+            TransactionHash::V1(TransactionV1Hash::new(Digest::from(DIGEST_HEX))),
+        );
+
+        assert!(!is_transaction_in_block(&block, 3, &get_transaction_hash()));
+    }
+
+    #[test]
+    fn test_error_if_transaction_is_not_in_verified_block() {
+        assert_eq!(
+            verify_transaction_v2_inclusion(
+                get_block_with_proofs(12),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11),
+                FinalityThreshold::default(),
+                3,
+                get_transaction_hash(),
+            ),
+            Err(VerificationError::TransactionNotInBlock)
+        );
+    }
+
+    #[test]
+    fn test_error_if_transaction_is_in_unverified_body() {
+        let block_with_proofs = get_block_with_proofs(12);
+        let block = get_block_with_transaction(3, TransactionHash::V2(get_transaction_hash()));
+
+        assert_eq!(
+            verify_transaction_v2_inclusion(
+                BlockWithProofs::new(Block::new_v2(block), block_with_proofs.proofs().to_owned()),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(11),
+                FinalityThreshold::default(),
+                3,
+                get_transaction_hash(),
+            ),
+            Err(VerificationError::BlockBodyHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_error_if_block_is_not_from_era_of_validator_set() {
+        assert_eq!(
+            verify_transaction_v2_inclusion(
+                get_block_with_proofs(12),
+                ChainNameDigest::new_from_chain_name("cspr-dev-cctl"),
+                &get_era_consensus_info(22),
+                FinalityThreshold::default(),
+                3,
+                get_transaction_hash(),
+            ),
+            Err(VerificationError::EraMismatch)
+        );
+    }
+}
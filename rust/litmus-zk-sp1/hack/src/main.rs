@@ -1,5 +1,5 @@
 use hex;
-use lkernel::{FetcherConfig, Kernel, KernelConfig};
+use lkernel::{CacheConfig, FetcherConfig, Kernel, KernelConfig, ProverConfig, ProverMode};
 use ltypes::chain::BlockHash;
 use ltypes::crypto::Digest;
 use toml;
@@ -13,11 +13,19 @@ fn main() {
     let digest_bytes = hex::decode(MSG_DIGEST_BLAKE2B_HEX).unwrap();
 
     let config = KernelConfig {
+        cache: CacheConfig {
+            path_to_root: "cache".to_string(),
+        },
         name_of_chain: "xxx".to_string(),
         trusted_block_hash: BlockHash::new(Digest::from(digest_bytes)),
         fetcher: FetcherConfig::FileSystem {
             path_to_root: PATH_TO_ROOT.to_string(),
         },
+        prover: ProverConfig {
+            mode: ProverMode::Mock,
+            path_to_elf: "elf/riscv32im-succinct-zkvm-elf".to_string(),
+            path_to_output: "proofs".to_string(),
+        },
     };
 
     let config_as_str = toml::to_string(&config).unwrap();
@@ -1,18 +1,99 @@
-use ltypeset::chain::{BlockWithProofs, ChainNameDigest};
-use lverifiers;
+use ltypeset::{
+    chain::{BlockWithProofs, ChainNameDigest, EraConsensusInfo, TransactionV2Hash},
+    verification::{BlockPublicValues, PublicValues, TransactionPublicValues},
+};
+use lverifiers::{self, FinalityThreshold};
 
-pub fn verify_block_v1_with_proofs(encoded_block_with_proofs: Vec<u8>) {
-    unimplemented!("verify_block_v1_with_proofs");
+pub fn verify_block_v1_with_proofs(
+    block_with_proofs: BlockWithProofs,
+    era_consensus_info: EraConsensusInfo,
+) -> PublicValues {
+    lverifiers::verify_block_v1_with_proofs(
+        block_with_proofs.clone(),
+        &era_consensus_info,
+        FinalityThreshold::default(),
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    PublicValues::BlockV1(BlockPublicValues::new_from_block(
+        block_with_proofs.block(),
+        None,
+        Some(lverifiers::get_validator_set_digest(&era_consensus_info)),
+    ))
 }
 
 pub fn verify_block_v2_with_proofs(
-    encoded_block_with_proofs: Vec<u8>,
-    encoded_chain_name_digest: Vec<u8>,
-) {
-    let block_with_proofs: BlockWithProofs =
-        serde_cbor::from_slice(&encoded_block_with_proofs).unwrap();
-    let chain_name_digest: ChainNameDigest =
-        serde_cbor::from_slice(&encoded_chain_name_digest).unwrap();
-
-    lverifiers::verify_block_v2_with_proofs(block_with_proofs, chain_name_digest, None);
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: EraConsensusInfo,
+) -> PublicValues {
+    lverifiers::verify_block_v2_with_proofs(
+        block_with_proofs.clone(),
+        chain_name_digest,
+        &era_consensus_info,
+        FinalityThreshold::default(),
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    PublicValues::BlockV2(BlockPublicValues::new_from_block(
+        block_with_proofs.block(),
+        Some(chain_name_digest),
+        Some(lverifiers::get_validator_set_digest(&era_consensus_info)),
+    ))
+}
+
+pub fn verify_block_range_v2_with_proofs(
+    set_of_blocks_with_proofs: Vec<BlockWithProofs>,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: EraConsensusInfo,
+) -> PublicValues {
+    lverifiers::verify_block_range_v2_with_proofs(
+        set_of_blocks_with_proofs.clone(),
+        chain_name_digest,
+        &era_consensus_info,
+        FinalityThreshold::default(),
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    // N.B. validator set digest is committed for tip only, ancestors are authenticated by hash chaining.
+    PublicValues::BlockRangeV2(
+        BlockPublicValues::new_from_block(
+            set_of_blocks_with_proofs.first().unwrap().block(),
+            Some(chain_name_digest),
+            None,
+        ),
+        BlockPublicValues::new_from_block(
+            set_of_blocks_with_proofs.last().unwrap().block(),
+            Some(chain_name_digest),
+            Some(lverifiers::get_validator_set_digest(&era_consensus_info)),
+        ),
+    )
+}
+
+pub fn verify_transaction_v2_inclusion(
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: EraConsensusInfo,
+    category: u8,
+    transaction_hash: TransactionV2Hash,
+) -> PublicValues {
+    lverifiers::verify_transaction_v2_inclusion(
+        block_with_proofs.clone(),
+        chain_name_digest,
+        &era_consensus_info,
+        FinalityThreshold::default(),
+        category,
+        transaction_hash,
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    PublicValues::TransactionV2Inclusion(TransactionPublicValues::new(
+        BlockPublicValues::new_from_block(
+            block_with_proofs.block(),
+            Some(chain_name_digest),
+            Some(lverifiers::get_validator_set_digest(&era_consensus_info)),
+        ),
+        category,
+        transaction_hash,
+    ))
 }
@@ -1,34 +1,36 @@
-use ltypeset::crypto::{Digest, Signature, VerificationKey};
+use ltypeset::{
+    crypto::{Digest, Signature, VerificationKey},
+    verification::PublicValues,
+};
 
 /// Verifies a digest over a byte vector.
 ///
 /// # Arguments
 ///
-/// * `encoded_vkey` - A cbor encoded digest.
+/// * `digest` - A digest.
 /// * `msg` - Message over which digest was claimed to have been computed.
 ///
-pub fn verify_digest(encoded_vkey: Vec<u8>, msg: Vec<u8>) {
-    let digest: Digest = serde_cbor::from_slice(&encoded_vkey).unwrap();
+pub fn verify_digest(digest: Digest, msg: Vec<u8>) -> PublicValues {
+    digest.verify(msg).unwrap_or_else(|err| panic!("{err}"));
 
-    digest.verify(msg);
+    PublicValues::Digest(digest)
 }
 
 /// Verifies a signature over a digest.
 ///
 /// # Arguments
 ///
-/// * `encoded_digest` - A cbor encoded digest.
-/// * `encoded_sig` - A cbor encoded signature.
-/// * `encoded_vkey` - A cbor encoded verification key.
+/// * `digest` - A digest over which signature was claimed to have been issued.
+/// * `sig` - A signature.
+/// * `vkey` - Verification key of signatory.
 ///
 pub fn verify_digest_signature(
-    encoded_sig: Vec<u8>,
-    encoded_vkey: Vec<u8>,
-    encoded_digest: Vec<u8>,
-) {
-    let digest: Digest = serde_cbor::from_slice(&encoded_digest).unwrap();
-    let sig: Signature = serde_cbor::from_slice(&encoded_sig).unwrap();
-    let vkey: VerificationKey = serde_cbor::from_slice(&encoded_vkey).unwrap();
+    digest: Digest,
+    sig: Signature,
+    vkey: VerificationKey,
+) -> PublicValues {
+    sig.verify_digest(&vkey, &digest)
+        .unwrap_or_else(|err| panic!("{err}"));
 
-    sig.verify_digest(&vkey, &digest);
+    PublicValues::Signature(digest, vkey)
 }
@@ -0,0 +1,94 @@
+use lcodecs::binary::Decode;
+use ltypeset::{
+    chain::{Block, BlockWithProofs, ChainNameDigest},
+    crypto::Digest,
+    verification::{BlockPublicValues, EraPublicValues, PublicValues},
+};
+use lverifiers::{self, FinalityThreshold};
+use sha2::{Digest as _, Sha256};
+
+pub fn verify_switch_block_v2_with_proofs(
+    vkey_hash: [u32; 8],
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+) -> PublicValues {
+    lverifiers::verify_switch_block_v2_with_proofs(block_with_proofs.clone(), chain_name_digest)
+        .unwrap_or_else(|err| panic!("{err}"));
+
+    // N.B. trusted switch block is not verified against a validator set.
+    PublicValues::EraV2(EraPublicValues::new(
+        BlockPublicValues::new_from_block(block_with_proofs.block(), Some(chain_name_digest), None),
+        block_with_proofs.block().hash().to_owned(),
+        get_digest_of_vkey_hash(&vkey_hash),
+    ))
+}
+
+pub fn verify_era_transition_v2_with_proofs(
+    vkey_hash: [u32; 8],
+    encoded_previous_public_values: Vec<u8>,
+    previous_block_with_proofs: BlockWithProofs,
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+) -> PublicValues {
+    // Verify previous proof - N.B. deferred to sp1 recursion circuit.
+    sp1_zkvm::lib::verify::verify_sp1_proof(
+        &vkey_hash,
+        &Sha256::digest(&encoded_previous_public_values).into(),
+    );
+    let previous_public_values = match PublicValues::decode_exact(&encoded_previous_public_values) {
+        Ok(PublicValues::EraV2(inner)) => inner,
+        _ => panic!("Era: Previous public values are not era public values"),
+    };
+
+    // Bind inputs to previous proof.
+    assert_eq!(
+        previous_public_values.vkey_hash(),
+        &get_digest_of_vkey_hash(&vkey_hash),
+        "Era: Previous proof was issued by a different program"
+    );
+    assert_eq!(
+        previous_public_values.block().block_hash(),
+        previous_block_with_proofs.block().hash(),
+        "Era: Previous block is not the switch block verified by previous proof"
+    );
+    assert_eq!(
+        previous_public_values.block().chain_name_digest(),
+        &Some(chain_name_digest),
+        "Era: Chain name is not that of previous proof"
+    );
+
+    lverifiers::verify_era_transition_v2_with_proofs(
+        &previous_block_with_proofs,
+        block_with_proofs.clone(),
+        chain_name_digest,
+        FinalityThreshold::default(),
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    let era_consensus_info = match previous_block_with_proofs.block() {
+        Block::V2(inner) => lverifiers::get_next_era_consensus_info(inner)
+            .unwrap()
+            .unwrap(),
+        Block::V1(_) => unreachable!(),
+    };
+
+    PublicValues::EraV2(EraPublicValues::new(
+        BlockPublicValues::new_from_block(
+            block_with_proofs.block(),
+            Some(chain_name_digest),
+            Some(lverifiers::get_validator_set_digest(&era_consensus_info)),
+        ),
+        previous_public_values.trusted_block_hash().to_owned(),
+        previous_public_values.vkey_hash().to_owned(),
+    ))
+}
+
+// Maps a program verification key hash to a digest - N.B. words are big endian as per sp1 `HashableKey::hash_bytes`.
+fn get_digest_of_vkey_hash(vkey_hash: &[u32; 8]) -> Digest {
+    Digest::from(
+        vkey_hash
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect::<Vec<u8>>(),
+    )
+}
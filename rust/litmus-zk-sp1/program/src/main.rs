@@ -4,37 +4,93 @@ sp1_zkvm::entrypoint!(main);
 
 mod chain;
 mod crypto;
+mod era;
+mod state;
 
-// Supported verification types.
-const VERIFICATION_TYPE_DIGEST: u8 = 0;
-const VERIFICATION_TYPE_SIGNATURE: u8 = 1;
-const VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS: u8 = 10;
-const VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS: u8 = 11;
+use lcodecs::binary::Encode;
+use lrequests::VerificationRequest;
 
 /// Program entry point - wrapped by sp1 for execution within zk-vm.
 ///
-/// N.B. Arguments are parsed from SP1 ZK-VM i/o buffer:
+/// N.B. Request is parsed from SP1 ZK-VM i/o buffer, public values are committed as binary encoded bytes.
 pub fn main() {
-    let verification_type_tag = sp1_zkvm::io::read::<u8>();
-    match verification_type_tag {
-        VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS => {
-            chain::verify_block_v1_with_proofs(sp1_zkvm::io::read_vec())
+    let request = VerificationRequest::decode(&sp1_zkvm::io::read_vec())
+        .unwrap_or_else(|err| panic!("{err}"));
+    println!("cycle-tracker-report-start: verify");
+    let public_values = match request {
+        VerificationRequest::Digest { digest, msg } => crypto::verify_digest(digest, msg),
+        VerificationRequest::Signature { digest, sig, vkey } => {
+            crypto::verify_digest_signature(digest, sig, vkey)
         }
-        VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS => {
-            chain::verify_block_v2_with_proofs(sp1_zkvm::io::read_vec(), sp1_zkvm::io::read_vec())
+        VerificationRequest::BlockV1WithProofs {
+            block_with_proofs,
+            era_consensus_info,
+        } => chain::verify_block_v1_with_proofs(block_with_proofs, era_consensus_info),
+        VerificationRequest::BlockV2WithProofs {
+            block_with_proofs,
+            chain_name_digest,
+            era_consensus_info,
+        } => chain::verify_block_v2_with_proofs(
+            block_with_proofs,
+            chain_name_digest,
+            era_consensus_info,
+        ),
+        VerificationRequest::BlockRangeV2WithProofs {
+            chain_name_digest,
+            era_consensus_info,
+            set_of_blocks_with_proofs,
+        } => chain::verify_block_range_v2_with_proofs(
+            set_of_blocks_with_proofs,
+            chain_name_digest,
+            era_consensus_info,
+        ),
+        VerificationRequest::TransactionV2Inclusion {
+            block_with_proofs,
+            category,
+            chain_name_digest,
+            era_consensus_info,
+            transaction_hash,
+        } => chain::verify_transaction_v2_inclusion(
+            block_with_proofs,
+            chain_name_digest,
+            era_consensus_info,
+            category,
+            transaction_hash,
+        ),
+        VerificationRequest::StateV2Inclusion {
+            block_with_proofs,
+            chain_name_digest,
+            era_consensus_info,
+            proof,
+        } => state::verify_state_v2_inclusion(
+            block_with_proofs,
+            chain_name_digest,
+            era_consensus_info,
+            proof,
+        ),
+        VerificationRequest::SwitchBlockV2WithProofs {
+            block_with_proofs,
+            chain_name_digest,
+            vkey_hash,
+        } => {
+            era::verify_switch_block_v2_with_proofs(vkey_hash, block_with_proofs, chain_name_digest)
         }
-        VERIFICATION_TYPE_DIGEST => {
-            crypto::verify_digest(sp1_zkvm::io::read_vec(), sp1_zkvm::io::read_vec())
-        }
-        VERIFICATION_TYPE_DIGEST_SIGNATURE => {
-            crypto::verify_digest_signature(
-                sp1_zkvm::io::read_vec(),
-                sp1_zkvm::io::read_vec(),
-                sp1_zkvm::io::read_vec(),
-            );
-        }
-        _ => {
-            panic!("Unsupported verification type")
-        }
-    }
+        VerificationRequest::EraTransitionV2WithProofs {
+            block_with_proofs,
+            chain_name_digest,
+            previous_block_with_proofs,
+            previous_public_values,
+            vkey_hash,
+        } => era::verify_era_transition_v2_with_proofs(
+            vkey_hash,
+            previous_public_values,
+            previous_block_with_proofs,
+            block_with_proofs,
+            chain_name_digest,
+        ),
+    };
+    println!("cycle-tracker-report-end: verify");
+
+    // Commit public values, i.e. what has been verified.
+    sp1_zkvm::io::commit_slice(&public_values.encode().unwrap());
 }
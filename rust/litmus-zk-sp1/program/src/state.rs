@@ -0,0 +1,32 @@
+use ltypeset::{
+    chain::{BlockWithProofs, ChainNameDigest, EraConsensusInfo},
+    trie::TrieMerkleProof,
+    verification::{BlockPublicValues, PublicValues, StatePublicValues},
+};
+use lverifiers::{self, FinalityThreshold};
+
+pub fn verify_state_v2_inclusion(
+    block_with_proofs: BlockWithProofs,
+    chain_name_digest: ChainNameDigest,
+    era_consensus_info: EraConsensusInfo,
+    proof: TrieMerkleProof,
+) -> PublicValues {
+    lverifiers::verify_state_v2_inclusion(
+        block_with_proofs.clone(),
+        chain_name_digest,
+        &era_consensus_info,
+        FinalityThreshold::default(),
+        &proof,
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    PublicValues::StateV2Inclusion(StatePublicValues::new(
+        BlockPublicValues::new_from_block(
+            block_with_proofs.block(),
+            Some(chain_name_digest),
+            Some(lverifiers::get_validator_set_digest(&era_consensus_info)),
+        ),
+        proof.key().to_owned(),
+        proof.value().to_owned(),
+    ))
+}
@@ -1,33 +1,57 @@
 use super::{
-    types::{
-        CryptoFixtures, DigestFixture, SignatureFixture, WrappedBlockV2WithProofs, WrappedDigest,
-        WrappedSignature,
-    },
+    types::{CryptoFixtures, DigestFixture, SignatureFixture, WrappedDigest, WrappedSignature},
     Fixtures,
 };
 use crate::utils::fsys;
+#[cfg(feature = "simulator")]
+use lsimulator::{Simulator, SimulatorConfig, SimulatorError};
 use ltypeset::{
     chain::{BlockHash, BlockWithProofs, ChainNameDigest},
     crypto::{Digest, Signature, VerificationKey},
 };
-use std::{fs, path::Path};
-
-// TODO: scan folder and derive.
-const BLOCK_RANGE_MIN: u32 = 469;
-const BLOCK_RANGE_MAX: u32 = 474;
+use std::fs;
 
 pub fn get_fixtures() -> Fixtures {
     let crypto_fixtures = get_crypto_fixtures();
     let chain_name_digest = get_chain_name_digest();
 
     Fixtures {
-        set_of_blocks_with_proofs: get_set_of_blocks_with_proofs(&chain_name_digest),
+        chain_name_digest,
+        set_of_blocks_with_proofs: get_set_of_blocks_with_proofs(),
         set_of_digests: get_set_of_digests(&crypto_fixtures.digests),
         set_of_signatures: get_set_of_signatures(&crypto_fixtures.signatures),
         trusted_block_hash: get_trusted_block_hash(),
     }
 }
 
+/// Returns fixtures whose blocks are drawn from a simulated chain, i.e. of any length.
+///
+/// N.B. Simulated genesis block is trusted.
+///
+/// # Arguments
+///
+/// * `config` - Simulator configuration.
+/// * `count` - Number of blocks to simulate, at least one, i.e. trusted genesis block.
+///
+#[cfg(feature = "simulator")]
+pub fn get_simulated_fixtures(
+    config: SimulatorConfig,
+    count: usize,
+) -> Result<Fixtures, SimulatorError> {
+    let crypto_fixtures = get_crypto_fixtures();
+    let mut simulator = Simulator::new(config)?;
+    let set_of_blocks_with_proofs: Vec<BlockWithProofs> =
+        simulator.by_ref().take(count.max(1)).collect();
+
+    Ok(Fixtures {
+        chain_name_digest: simulator.chain_name_digest(),
+        trusted_block_hash: *set_of_blocks_with_proofs[0].block().hash(),
+        set_of_blocks_with_proofs,
+        set_of_digests: get_set_of_digests(&crypto_fixtures.digests),
+        set_of_signatures: get_set_of_signatures(&crypto_fixtures.signatures),
+    })
+}
+
 fn get_chain_name_digest() -> ChainNameDigest {
     let chain_name = fsys::get_fixture_content(String::from("network_name.txt"));
 
@@ -38,49 +62,20 @@ fn get_crypto_fixtures() -> CryptoFixtures {
     serde_json::from_str(&fsys::get_fixture_content(String::from("crypto.json"))).unwrap()
 }
 
-// fn get_set_of_blocks_with_proofs_1(
-//     chain_name_digest: &ChainNameDigest,
-// ) -> Vec<WrappedBlockV2WithProofs> {
-//     fn get_inner(block_id: u32) -> BlockWithProofs {
-//         let fname = format!("block-{block_id}.json");
-
-//         serde_json::from_str(&fsys::get_fixture_content(fname)).unwrap()
-//     }
-
-//     fn get_one(
-//         path_to_file: &Path,
-//         chain_name_digest: &ChainNameDigest,
-//     ) -> WrappedBlockV2WithProofs {
-//         let g = fs::read(path_to_file).unwrap();
-
-//         let h = serde_json::from_str(path_to_file.to_str().unwrap());
-
-//         println!("{:?}", path_to_file);
-//         unimplemented!()
-//         // WrappedBlockV2WithProofs(get_inner(block_id), chain_name_digest.to_owned())
-//     }
-
-//     fsys::get_block_fixtures_directory()
-//         .map(|x| get_one(&x.unwrap().path().as_path(), chain_name_digest))
-//         .collect()
-// }
-
-fn get_set_of_blocks_with_proofs(
-    chain_name_digest: &ChainNameDigest,
-) -> Vec<WrappedBlockV2WithProofs> {
-    fn get_inner(block_id: u32) -> BlockWithProofs {
-        let fname = format!("block-{block_id}.json");
-
-        serde_json::from_str(&fsys::get_fixture_content(fname)).unwrap()
-    }
-
-    fn get_one(block_id: u32, chain_name_digest: &ChainNameDigest) -> WrappedBlockV2WithProofs {
-        WrappedBlockV2WithProofs(get_inner(block_id), chain_name_digest.to_owned())
-    }
-
-    (BLOCK_RANGE_MIN..BLOCK_RANGE_MAX)
-        .map(|x| get_one(x, chain_name_digest))
-        .collect()
+// N.B. Block files are named block-{height}-{hash}.json, i.e. as written by a simulator.
+fn get_set_of_blocks_with_proofs() -> Vec<BlockWithProofs> {
+    let mut set_of_blocks_with_proofs: Vec<BlockWithProofs> = fsys::get_block_resources_directory()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let fname = path.file_name().unwrap().to_str().unwrap();
+            fname.starts_with("block-") && fname.ends_with(".json")
+        })
+        .map(|path| serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap())
+        .collect();
+    set_of_blocks_with_proofs
+        .sort_by_key(|block_with_proofs| block_with_proofs.block().height().inner());
+
+    set_of_blocks_with_proofs
 }
 
 fn get_set_of_digests(f_set: &Vec<DigestFixture>) -> Vec<WrappedDigest> {
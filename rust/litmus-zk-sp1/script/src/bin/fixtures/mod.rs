@@ -2,4 +2,6 @@ mod loader;
 pub mod types;
 
 pub use loader::get_fixtures;
+#[cfg(feature = "simulator")]
+pub use loader::get_simulated_fixtures;
 pub use types::Fixtures;
@@ -1,8 +1,10 @@
 use ltypeset::{
-    chain::{BlockHash, BlockWithProofs, ChainNameDigest},
+    chain::{BlockHash, BlockWithProofs, ChainNameDigest, EraConsensusInfo, TransactionV2Hash},
     crypto::{Digest, Signature, VerificationKey},
+    trie::TrieMerkleProof,
 };
 use serde::{Deserialize, Serialize};
+use sp1_sdk::{SP1ProofWithPublicValues, SP1VerifyingKey};
 
 // Digest information required for verification.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -56,20 +58,29 @@ pub struct CryptoFixtures {
     pub signatures: Vec<SignatureFixture>,
 }
 
-// V1 block with associated proof set.
+// V1 block with associated proof set plus validator set of block's era.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct WrappedBlockV1WithProofs(pub BlockWithProofs);
+pub struct WrappedBlockV1WithProofs(pub BlockWithProofs, pub EraConsensusInfo);
 
 impl WrappedBlockV1WithProofs {
+    // Validator set in force for block's era.
+    pub(crate) fn era_consensus_info(&self) -> &EraConsensusInfo {
+        &self.1
+    }
+
     // Block and set of associated finality signatures.
     pub(crate) fn inner(&self) -> &BlockWithProofs {
         &self.0
     }
 }
 
-// Wrapped V2 block with associated proof set.
+// Wrapped V2 block with associated proof set plus validator set of block's era.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct WrappedBlockV2WithProofs(pub BlockWithProofs, pub ChainNameDigest);
+pub struct WrappedBlockV2WithProofs(
+    pub BlockWithProofs,
+    pub ChainNameDigest,
+    pub EraConsensusInfo,
+);
 
 impl WrappedBlockV2WithProofs {
     // Name of chain associated with block.
@@ -77,10 +88,166 @@ impl WrappedBlockV2WithProofs {
         &self.1
     }
 
+    // Validator set in force for block's era.
+    pub(crate) fn era_consensus_info(&self) -> &EraConsensusInfo {
+        &self.2
+    }
+
+    // Block and set of associated finality signatures.
+    pub(crate) fn inner(&self) -> &BlockWithProofs {
+        &self.0
+    }
+}
+
+// Wrapped contiguous range of V2 blocks with associated proof sets plus validator set of tip's era.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WrappedBlockRangeV2WithProofs(
+    pub Vec<BlockWithProofs>,
+    pub ChainNameDigest,
+    pub EraConsensusInfo,
+);
+
+impl WrappedBlockRangeV2WithProofs {
+    // Name of chain associated with blocks.
+    pub(crate) fn chain_name_digest(&self) -> &ChainNameDigest {
+        &self.1
+    }
+
+    // Validator set in force for tip's era.
+    pub(crate) fn era_consensus_info(&self) -> &EraConsensusInfo {
+        &self.2
+    }
+
+    // Blocks and sets of associated finality signatures ordered by height.
+    pub(crate) fn inner(&self) -> &Vec<BlockWithProofs> {
+        &self.0
+    }
+}
+
+// Wrapped V2 block with associated proof set plus a transaction claimed to be included within it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WrappedTransactionV2Inclusion(
+    pub BlockWithProofs,
+    pub ChainNameDigest,
+    pub EraConsensusInfo,
+    pub u8,
+    pub TransactionV2Hash,
+);
+
+impl WrappedTransactionV2Inclusion {
+    // Category under which transaction was executed.
+    pub(crate) fn category(&self) -> u8 {
+        self.3
+    }
+
+    // Name of chain associated with block.
+    pub(crate) fn chain_name_digest(&self) -> &ChainNameDigest {
+        &self.1
+    }
+
+    // Validator set in force for block's era.
+    pub(crate) fn era_consensus_info(&self) -> &EraConsensusInfo {
+        &self.2
+    }
+
+    // Block and set of associated finality signatures.
+    pub(crate) fn inner(&self) -> &BlockWithProofs {
+        &self.0
+    }
+
+    // Hash of transaction claimed to be included within block.
+    pub(crate) fn transaction_hash(&self) -> &TransactionV2Hash {
+        &self.4
+    }
+}
+
+// Wrapped V2 block with associated proof set plus a global state merkle proof anchored by it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WrappedStateV2Inclusion(
+    pub BlockWithProofs,
+    pub ChainNameDigest,
+    pub EraConsensusInfo,
+    pub TrieMerkleProof,
+);
+
+impl WrappedStateV2Inclusion {
+    // Name of chain associated with block.
+    pub(crate) fn chain_name_digest(&self) -> &ChainNameDigest {
+        &self.1
+    }
+
+    // Validator set in force for block's era.
+    pub(crate) fn era_consensus_info(&self) -> &EraConsensusInfo {
+        &self.2
+    }
+
     // Block and set of associated finality signatures.
     pub(crate) fn inner(&self) -> &BlockWithProofs {
         &self.0
     }
+
+    // Proof that a key/value pair is stored within global state.
+    pub(crate) fn proof(&self) -> &TrieMerkleProof {
+        &self.3
+    }
+}
+
+// Wrapped trusted V2 switch block from which a chain of era proofs is started.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WrappedSwitchBlockV2WithProofs(pub BlockWithProofs, pub ChainNameDigest, pub [u32; 8]);
+
+impl WrappedSwitchBlockV2WithProofs {
+    // Name of chain associated with block.
+    pub(crate) fn chain_name_digest(&self) -> &ChainNameDigest {
+        &self.1
+    }
+
+    // Block and set of associated finality signatures.
+    pub(crate) fn inner(&self) -> &BlockWithProofs {
+        &self.0
+    }
+
+    // Hash of zk-vm program verification key.
+    pub(crate) fn vkey_hash(&self) -> &[u32; 8] {
+        &self.2
+    }
+}
+
+// Wrapped V2 switch block extending a previous era proof by one era.
+#[derive(Clone)]
+pub struct WrappedEraTransitionV2WithProofs(
+    pub SP1ProofWithPublicValues,
+    pub SP1VerifyingKey,
+    pub BlockWithProofs,
+    pub BlockWithProofs,
+    pub ChainNameDigest,
+);
+
+impl WrappedEraTransitionV2WithProofs {
+    // Name of chain associated with blocks.
+    pub(crate) fn chain_name_digest(&self) -> &ChainNameDigest {
+        &self.4
+    }
+
+    // Switch block of next era and set of associated finality signatures.
+    pub(crate) fn inner(&self) -> &BlockWithProofs {
+        &self.3
+    }
+
+    // Switch block verified by previous proof and set of associated finality signatures.
+    pub(crate) fn previous_inner(&self) -> &BlockWithProofs {
+        &self.2
+    }
+
+    // Previous (compressed) era proof.
+    pub(crate) fn previous_proof(&self) -> &SP1ProofWithPublicValues {
+        &self.0
+    }
+
+    // Verification key of zk-vm program that issued previous proof.
+    pub(crate) fn vkey(&self) -> &SP1VerifyingKey {
+        &self.1
+    }
 }
 
 // Wrapped cryptographic digest.
@@ -123,7 +290,8 @@ impl WrappedSignature {
 // Fixtures for tests.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Fixtures {
-    pub set_of_blocks_with_proofs: Vec<WrappedBlockV2WithProofs>,
+    pub chain_name_digest: ChainNameDigest,
+    pub set_of_blocks_with_proofs: Vec<BlockWithProofs>,
     pub set_of_digests: Vec<WrappedDigest>,
     pub set_of_signatures: Vec<WrappedSignature>,
     pub trusted_block_hash: BlockHash,
@@ -1,33 +1,94 @@
 mod fixtures;
 mod utils;
 use camino::Utf8PathBuf;
-use clap::Parser;
-use fixtures::types::WrappedBlockV2WithProofs;
-use lkernel::Kernel;
-use sp1_sdk::{ProverClient, SP1Stdin};
-use std::{fs, path::PathBuf};
-
-/// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
-pub const _ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-elf");
+use clap::{Parser, Subcommand};
+use fixtures::types::{
+    WrappedBlockRangeV2WithProofs, WrappedBlockV1WithProofs, WrappedBlockV2WithProofs,
+    WrappedEraTransitionV2WithProofs, WrappedStateV2Inclusion, WrappedSwitchBlockV2WithProofs,
+    WrappedTransactionV2Inclusion,
+};
+use lkernel::{FollowerSink, Kernel, KernelError};
+use ltypeset::{
+    chain::{Block, BlockHash, BlockID, TransactionV2Hash},
+    crypto::Digest,
+    verification::PublicValues,
+};
+use sp1_sdk::{HashableKey, SP1Stdin};
+use std::{fs, path::PathBuf, time::Duration};
+use utils::{aggregation, inputs, outputs};
 
 /// The arguments for the command.
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
+    /// Extend latest era proof by one era, or start a chain of era proofs from trusted switch block.
+    #[clap(long, requires = "prove", conflicts_with_all = ["path_to_state_proof", "target_block_height", "transaction_hash"])]
+    aggregate: bool,
+
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     #[clap(long)]
     execute: bool,
 
+    /// Continuously verify new finalized blocks from trusted block, emitting each as a line of JSON.
+    #[clap(long, conflicts_with_all = ["aggregate", "execute", "prove"])]
+    follow: bool,
+
+    /// Path to a file to which followed blocks are appended, otherwise they are written to stdout.
+    #[clap(long, requires = "follow")]
+    path_to_follow_output: Option<Utf8PathBuf>,
+
     #[clap(short, long, default_value = "content")]
     path_to_config: Utf8PathBuf,
 
+    /// Directory within which chain of era proofs is stored.
+    #[clap(long, default_value = "proofs")]
+    path_to_proofs: Utf8PathBuf,
+
+    /// Interval at which to poll for a new block whilst following, e.g. "10s".
+    #[clap(long, default_value = "10s", value_parser = humantime::parse_duration, requires = "follow")]
+    poll_interval: Duration,
+
+    /// Path to a JSON encoded global state merkle proof whose inclusion is to be proven.
+    #[clap(long, conflicts_with = "transaction_hash")]
+    path_to_state_proof: Option<Utf8PathBuf>,
+
     #[clap(long)]
     prove: bool,
+
+    /// Height of last block in a range, starting at trusted block, to be proven as a batch.
+    #[clap(long, conflicts_with_all = ["path_to_state_proof", "transaction_hash"])]
+    target_block_height: Option<u64>,
+
+    /// Category under which transaction whose inclusion is to be proven was executed.
+    #[clap(long, default_value_t = 0, requires = "transaction_hash")]
+    transaction_category: u8,
+
+    /// Hex encoded hash of a version two transaction whose inclusion is to be proven.
+    #[clap(long)]
+    transaction_hash: Option<String>,
+}
+
+/// Commands that run natively, i.e. without the zk-vm.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Verify a block on the host & print a per rule report.
+    Verify {
+        /// Hex encoded hash of block to be verified.
+        #[clap(long, conflicts_with = "block_height")]
+        block_hash: Option<String>,
+
+        /// Height of block to be verified, defaults to trusted block.
+        #[clap(long)]
+        block_height: Option<u64>,
+    },
 }
 
 fn main() {
     // Set args.
     let args = Args::parse();
-    if args.execute == args.prove {
+    if args.execute == args.prove && !args.follow && args.command.is_none() {
         eprintln!("Error: You must specify either --execute or --prove");
         std::process::exit(1);
     }
@@ -40,34 +101,266 @@ fn main() {
 
     // Set kernel.
     let kernel = Kernel::new(&args.path_to_config);
-    kernel.init();
+    if let Err(err) = kernel.init() {
+        eprintln!("Error: Kernel initialisation failed :: {err}");
+        std::process::exit(1);
+    }
+
+    // Native commands bypass the zk-vm.
+    if let Some(Command::Verify {
+        block_hash,
+        block_height,
+    }) = &args.command
+    {
+        let block_id = match (block_hash, block_height) {
+            (Some(inner), _) => BlockID::new_from_hash(BlockHash::from(inner.as_str())),
+            (None, Some(inner)) => BlockID::new_from_height(*inner),
+            (None, None) => BlockID::new_from_hash(*kernel.config().trusted_block_hash()),
+        };
+        do_verify(&kernel, block_id);
+        return;
+    }
 
-    // Set stdin set ... i.e. a sequence of ZK-VM prover inputs.
-    let mut set_of_stdin = Vec::<SP1Stdin>::new();
+    // Follow mode runs until a block fails verification.
+    if args.follow {
+        do_follow(&args, &kernel);
+        return;
+    }
 
-    let g = kernel.get_block_with_proofs(None);
-    let g = match g {
-        Some(block) => WrappedBlockV2WithProofs(block, kernel.get_chain_name_digest()),
-        None => panic!("Invalid trusted hash"),
-    };
-    set_of_stdin.push(SP1Stdin::try_from(&g).unwrap());
+    // Era proofs are chained, i.e. each is an input to its successor.
+    if args.aggregate {
+        do_pgm_aggregate(&args, &kernel);
+        return;
+    }
+
+    // Set stdin set ... i.e. a sequence of ZK-VM prover inputs keyed by hash of block to which each pertains.
+    let mut set_of_stdin = Vec::<(BlockHash, SP1Stdin)>::new();
+
+    if let Some(target_block_height) = args.target_block_height {
+        let set_of_blocks_with_proofs = match kernel
+            .get_chain_of_blocks_with_proofs(BlockID::new_from_height(target_block_height))
+        {
+            Ok(Some(inner)) => inner,
+            Ok(None) => panic!("Invalid target block height"),
+            Err(err) => panic!("Chain of blocks could not be fetched :: {err}"),
+        };
+        let era_consensus_info =
+            match kernel.get_era_consensus_info(set_of_blocks_with_proofs.last().unwrap()) {
+                Ok(Some(inner)) => inner,
+                Ok(None) => panic!("Era validator set is unavailable"),
+                Err(err) => panic!("Era validator set is invalid or could not be fetched :: {err}"),
+            };
+        set_of_stdin.push((
+            *set_of_blocks_with_proofs.last().unwrap().block().hash(),
+            SP1Stdin::from(&WrappedBlockRangeV2WithProofs(
+                set_of_blocks_with_proofs,
+                kernel.get_chain_name_digest(),
+                era_consensus_info,
+            )),
+        ));
+    } else {
+        let block_with_proofs = match kernel.get_block_with_proofs(None) {
+            Ok(Some(inner)) => inner,
+            Ok(None) => panic!("Invalid trusted hash"),
+            Err(err) => panic!("Trusted block could not be fetched :: {err}"),
+        };
+        let era_consensus_info = match kernel.get_era_consensus_info(&block_with_proofs) {
+            Ok(Some(inner)) => inner,
+            Ok(None) => panic!("Era validator set is unavailable"),
+            Err(err) => panic!("Era validator set is invalid or could not be fetched :: {err}"),
+        };
+        set_of_stdin.push((
+            *block_with_proofs.block().hash(),
+            match (
+                block_with_proofs.block(),
+                &args.transaction_hash,
+                &args.path_to_state_proof,
+            ) {
+                (Block::V1(_), None, None) => SP1Stdin::from(&WrappedBlockV1WithProofs(
+                    block_with_proofs,
+                    era_consensus_info,
+                )),
+                (Block::V1(_), _, _) => panic!("Inclusion proofs require a version two block"),
+                (Block::V2(_), None, None) => SP1Stdin::from(&WrappedBlockV2WithProofs(
+                    block_with_proofs,
+                    kernel.get_chain_name_digest(),
+                    era_consensus_info,
+                )),
+                (Block::V2(_), Some(transaction_hash), None) => {
+                    SP1Stdin::from(&WrappedTransactionV2Inclusion(
+                        block_with_proofs,
+                        kernel.get_chain_name_digest(),
+                        era_consensus_info,
+                        args.transaction_category,
+                        TransactionV2Hash::new(Digest::from(transaction_hash.as_str())),
+                    ))
+                }
+                (Block::V2(_), None, Some(path_to_state_proof)) => {
+                    SP1Stdin::from(&WrappedStateV2Inclusion(
+                        block_with_proofs,
+                        kernel.get_chain_name_digest(),
+                        era_consensus_info,
+                        serde_json::from_str(&fs::read_to_string(path_to_state_proof).unwrap())
+                            .unwrap(),
+                    ))
+                }
+                (Block::V2(_), Some(_), Some(_)) => unreachable!(),
+            },
+        ));
+    }
 
     // Invoke stdin set.
-    for stdin in set_of_stdin {
+    for (block_hash, stdin) in set_of_stdin {
         if args.execute {
-            do_pgm_execute(&args, &stdin);
+            do_pgm_execute(&kernel, &stdin);
         } else {
-            do_pgm_prove(&args, &stdin);
+            do_pgm_prove(&kernel, &block_hash, &stdin);
+        }
+    }
+}
+
+fn do_verify(kernel: &Kernel, block_id: BlockID) {
+    let (block_with_proofs, report) = match kernel.get_block_report(block_id) {
+        Ok(Some(inner)) => inner,
+        Ok(None) => panic!("Block is unavailable or not a descendant of trusted block"),
+        Err(KernelError::Fetch(err)) => panic!("Chain of blocks could not be fetched :: {err}"),
+        Err(KernelError::Verification(err)) => {
+            eprintln!("VERIFY: chain from trusted block failed verification :: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    // Render report.
+    let block = block_with_proofs.block();
+    println!("VERIFY: block hash  : {}", block.hash());
+    println!("VERIFY: block height: {}", block.height());
+    println!("VERIFY: era id      : {}", block.era_id());
+    for (rule, outcome) in report.outcomes() {
+        println!("VERIFY: {rule}      : {outcome}");
+    }
+    println!(
+        "VERIFY: signatures  : {} checked, {} valid",
+        report.signatures_checked(),
+        report.signatures_valid()
+    );
+    match (report.signed_weight(), report.total_weight()) {
+        (Some(signed_weight), Some(total_weight)) => println!(
+            "VERIFY: weight      : {signed_weight} of {total_weight} signed, threshold {}/{}",
+            report.finality_threshold().numerator(),
+            report.finality_threshold().denominator()
+        ),
+        _ => println!("VERIFY: weight      : not tallied, era validator set is unknown"),
+    }
+
+    if !report.is_verified() {
+        std::process::exit(1);
+    }
+    if !report.is_finality_verified() {
+        println!("VERIFY: UNVERIFIED FINALITY :: BL-005 was not evaluated");
+        std::process::exit(2);
+    }
+}
+
+fn do_follow(args: &Args, kernel: &Kernel) {
+    let mut sink = match &args.path_to_follow_output {
+        Some(path_to_file) => {
+            FollowerSink::new_file(path_to_file).expect("failed to open follow output")
+        }
+        None => FollowerSink::new_stdout(),
+    };
+    if let Err(err) = kernel.follow(&mut sink, args.poll_interval) {
+        eprintln!("FOLLOW: stopped: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn do_pgm_aggregate(args: &Args, kernel: &Kernel) {
+    let prover = kernel.prover();
+    let vk = prover.vk().expect("failed to load program");
+
+    // Set stdin - extends latest era proof if one exists.
+    let stdin = match aggregation::get_latest_proof(&args.path_to_proofs) {
+        None => {
+            let block_with_proofs = match kernel.get_block_with_proofs(None) {
+                Ok(Some(inner)) => inner,
+                Ok(None) => panic!("Invalid trusted hash"),
+                Err(err) => panic!("Trusted block could not be fetched :: {err}"),
+            };
+            SP1Stdin::from(&WrappedSwitchBlockV2WithProofs(
+                block_with_proofs,
+                kernel.get_chain_name_digest(),
+                vk.hash_u32(),
+            ))
+        }
+        Some(previous_proof) => {
+            let previous_public_values =
+                match outputs::decode_public_values(previous_proof.public_values.as_slice()) {
+                    PublicValues::EraV2(inner) => inner,
+                    _ => panic!("Latest proof is not an era proof"),
+                };
+            aggregation::assert_era_public_values(
+                &previous_public_values,
+                vk,
+                kernel.config().trusted_block_hash(),
+            );
+            let previous_block_with_proofs = match kernel
+                .get_block_with_proofs(Some(*previous_public_values.block().block_hash()))
+            {
+                Ok(Some(inner)) => inner,
+                Ok(None) => panic!("Previous switch block is unavailable"),
+                Err(err) => panic!("Previous switch block could not be fetched :: {err}"),
+            };
+            let block_with_proofs = match kernel
+                .get_next_switch_block_with_proofs(*previous_public_values.block().height())
+            {
+                Ok(Some(inner)) => inner,
+                Ok(None) => panic!("Next switch block is unavailable"),
+                Err(err) => panic!("Next switch block could not be fetched :: {err}"),
+            };
+            SP1Stdin::from(&WrappedEraTransitionV2WithProofs(
+                previous_proof,
+                vk.clone(),
+                previous_block_with_proofs,
+                block_with_proofs,
+                kernel.get_chain_name_digest(),
+            ))
+        }
+    };
+
+    // Set proof - compressed so as to be verifiable by successor.
+    let proof = prover
+        .prove_compressed(&stdin)
+        .expect("failed to generate proof");
+    println!("PROOF: generation complete");
+
+    // Verify proof.
+    prover.verify(&proof).expect("failed to verify proof");
+    println!("PROOF: verification complete");
+
+    // Persist proof.
+    let public_values = outputs::decode_public_values(proof.public_values.as_slice());
+    println!("PROOF: public values : {:?}", public_values);
+    match &public_values {
+        PublicValues::EraV2(inner) => {
+            aggregation::assert_era_public_values(inner, vk, kernel.config().trusted_block_hash());
+            aggregation::set_latest_proof(&args.path_to_proofs, &proof, inner.block().era_id())
         }
+        _ => unreachable!(),
     }
+    println!("PROOF: saved to {}", args.path_to_proofs);
 }
 
-fn do_pgm_execute(args: &Args, stdin: &SP1Stdin) {
-    // Set VM client.
-    let client = ProverClient::new();
-    let (_, report) = client.execute(_ELF, stdin.clone()).run().unwrap();
+fn do_pgm_execute(kernel: &Kernel, stdin: &SP1Stdin) {
+    let (public_values, report) = kernel
+        .prover()
+        .execute(stdin)
+        .expect("failed to execute program");
 
     // Render report.
+    println!(
+        "EXECUTION: public values : {:?}",
+        outputs::decode_public_values(public_values.as_slice())
+    );
     println!(
         "EXECUTION: # vm cycles   : {}",
         report.total_instruction_count()
@@ -76,21 +369,55 @@ fn do_pgm_execute(args: &Args, stdin: &SP1Stdin) {
         "EXECUTION: # calls to sys: {}",
         report.total_syscall_count()
     );
+
+    // Render cycles spent within tracked spans, e.g. BL-004 finality signature verification.
+    let mut cycle_tracker: Vec<_> = report.cycle_tracker.iter().collect();
+    cycle_tracker.sort();
+    for (span, cycles) in cycle_tracker {
+        println!("EXECUTION: # vm cycles   : {cycles} :: {span}");
+    }
 }
 
-fn do_pgm_prove(_: &Args, stdin: &SP1Stdin) {
-    // Set VM client.
-    let client = ProverClient::new();
-    let (pk, vk) = client.setup(_ELF);
+fn do_pgm_prove(kernel: &Kernel, block_hash: &BlockHash, stdin: &SP1Stdin) {
+    let prover = kernel.prover();
 
-    // Set proof.
-    let proof = client
-        .prove(&pk, stdin.clone())
-        .run()
-        .expect("failed to generate proof");
-    println!("PROOF: generation complete");
+    // Set proof - skipped if previously generated from same inputs.
+    let inputs_digest = inputs::get_digest_of_stdin(stdin);
+    let proof = match kernel
+        .cache()
+        .get_proof(block_hash, &inputs_digest)
+        .expect("failed to read proof cache")
+    {
+        Some(encoded) => {
+            println!("PROOF: loaded from cache");
+            bincode::deserialize(&encoded).unwrap()
+        }
+        None => {
+            let proof = prover.prove(stdin).expect("failed to generate proof");
+            println!("PROOF: generation complete");
+            kernel
+                .cache()
+                .set_proof(
+                    block_hash,
+                    &inputs_digest,
+                    &bincode::serialize(&proof).unwrap(),
+                )
+                .expect("failed to cache proof");
+            proof
+        }
+    };
 
     // Verify proof.
-    client.verify(&proof, &vk).expect("failed to verify proof");
+    prover.verify(&proof).expect("failed to verify proof");
     println!("PROOF: verification complete");
+    println!(
+        "PROOF: public values : {:?}",
+        outputs::decode_public_values(proof.public_values.as_slice())
+    );
+
+    // Persist proof artifacts.
+    let path_to_proof = prover
+        .write_artifacts(&proof)
+        .expect("failed to write proof artifacts");
+    println!("PROOF: artifacts saved to {}", path_to_proof);
 }
@@ -0,0 +1,205 @@
+use crate::utils::inputs;
+use lkernel::Kernel;
+use ltypeset::chain::BlockHash;
+use serde::Serialize;
+use sp1_sdk::{SP1ProofWithPublicValues, SP1Stdin};
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+/// Identifier of a proving job, unique for lifetime of server process.
+pub(crate) type JobId = u64;
+
+/// Queue of proving jobs processed in submission order by a background worker.
+///
+/// N.B. A lock poisoned by a panicking thread is recovered, i.e. job statuses remain readable.
+pub(crate) struct JobQueue {
+    jobs: Arc<Mutex<BTreeMap<JobId, JobStatus>>>,
+    sender: Mutex<mpsc::Sender<Job>>,
+}
+
+/// Lifecycle of a proving job.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub(crate) enum JobStatus {
+    /// Job is awaiting worker.
+    Queued { block_hash: String },
+
+    /// Proof is being generated.
+    Running { block_hash: String },
+
+    /// Proof was generated, verified & its artifacts written to prover output directory.
+    Complete {
+        artifacts_id: String,
+        block_hash: String,
+    },
+
+    /// Proof generation failed.
+    Failed { block_hash: String, error: String },
+}
+
+struct Job {
+    block_hash: BlockHash,
+    id: JobId,
+    stdin: SP1Stdin,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl JobQueue {
+    /// Constructor: returns a new queue whose jobs are processed by a spawned worker thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - Kernel whose prover & cache are used to process jobs.
+    ///
+    pub(crate) fn new(kernel: Arc<Kernel>) -> Self {
+        let jobs = Arc::new(Mutex::new(BTreeMap::new()));
+        let (sender, receiver) = mpsc::channel::<Job>();
+
+        let worker_jobs = Arc::clone(&jobs);
+        thread::spawn(move || {
+            for job in receiver {
+                let block_hash = to_hex(&job.block_hash);
+                set_status(
+                    &worker_jobs,
+                    job.id,
+                    JobStatus::Running {
+                        block_hash: block_hash.clone(),
+                    },
+                );
+
+                // N.B. prover panics upon failure, hence unwind is caught so as to keep worker alive.
+                let status = match panic::catch_unwind(AssertUnwindSafe(|| {
+                    do_job(&kernel, &job.block_hash, &job.stdin)
+                })) {
+                    Ok(Ok(artifacts_id)) => JobStatus::Complete {
+                        artifacts_id,
+                        block_hash,
+                    },
+                    Ok(Err(error)) => JobStatus::Failed { block_hash, error },
+                    Err(_) => JobStatus::Failed {
+                        block_hash,
+                        error: String::from("prover panicked"),
+                    },
+                };
+                set_status(&worker_jobs, job.id, status);
+            }
+        });
+
+        Self {
+            jobs,
+            sender: Mutex::new(sender),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+impl JobQueue {
+    /// Enqueues a proving job & returns its identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - Hash of block to which proof will pertain.
+    /// * `stdin` - Program inputs.
+    ///
+    pub(crate) fn enqueue(&self, block_hash: BlockHash, stdin: SP1Stdin) -> JobId {
+        let id = {
+            let mut jobs = self.jobs.lock().unwrap_or_else(|err| err.into_inner());
+            let id = jobs.keys().next_back().map_or(1, |id| id + 1);
+            jobs.insert(
+                id,
+                JobStatus::Queued {
+                    block_hash: to_hex(&block_hash),
+                },
+            );
+            id
+        };
+        self.sender
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .send(Job {
+                block_hash,
+                id,
+                stdin,
+            })
+            .expect("job worker is not running");
+
+        id
+    }
+
+    /// Returns status of a job (if any).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of a previously enqueued job.
+    ///
+    pub(crate) fn get_status(&self, id: JobId) -> Option<JobStatus> {
+        self.jobs
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(&id)
+            .cloned()
+    }
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Generates (or loads from cache) a proof, verifies it & writes its artifacts, returning name of artifacts directory.
+fn do_job(kernel: &Kernel, block_hash: &BlockHash, stdin: &SP1Stdin) -> Result<String, String> {
+    let prover = kernel.prover();
+
+    // Set proof - skipped if previously generated from same inputs.
+    let inputs_digest = inputs::get_digest_of_stdin(stdin);
+    let proof: SP1ProofWithPublicValues = match kernel
+        .cache()
+        .get_proof(block_hash, &inputs_digest)
+        .map_err(|err| err.to_string())?
+    {
+        Some(encoded) => bincode::deserialize(&encoded).map_err(|err| err.to_string())?,
+        None => {
+            let proof = prover.prove(stdin).map_err(|err| err.to_string())?;
+            kernel
+                .cache()
+                .set_proof(
+                    block_hash,
+                    &inputs_digest,
+                    &bincode::serialize(&proof).map_err(|err| err.to_string())?,
+                )
+                .map_err(|err| err.to_string())?;
+            proof
+        }
+    };
+
+    // Verify proof & persist artifacts.
+    prover.verify(&proof).map_err(|err| err.to_string())?;
+    let path_to_artifacts = prover
+        .write_artifacts(&proof)
+        .map_err(|err| err.to_string())?;
+
+    Ok(path_to_artifacts.file_name().unwrap().to_owned())
+}
+
+fn set_status(jobs: &Mutex<BTreeMap<JobId, JobStatus>>, id: JobId, status: JobStatus) {
+    jobs.lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .insert(id, status);
+}
+
+pub(crate) fn to_hex(block_hash: &BlockHash) -> String {
+    hex::encode(block_hash.inner().as_slice())
+}
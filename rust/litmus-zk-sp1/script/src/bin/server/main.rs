@@ -0,0 +1,177 @@
+//! Light client daemon exposing a kernel over HTTP.
+//!
+//! Routes:
+//!
+//! ```text
+//! POST /                                      JSON-RPC 2.0 requests, see `rpc::handle`.
+//! GET  /artifacts/<artifacts id>/<fname>      proof artifacts, i.e. proof.bin | public-values.bin.
+//! GET  /artifacts/<fname>                     program artifacts, i.e. vkey.bin | vkey.hash.
+//! ```
+//!
+//! N.B. Each request is handled upon its own thread, i.e. a request walking a chain of blocks does
+//! not block other requests.
+
+// N.B. modules are shared with CLI binary, hence not all of their items are used.
+#[allow(dead_code)]
+#[path = "../fixtures/mod.rs"]
+mod fixtures;
+mod jobs;
+mod rpc;
+#[allow(dead_code)]
+#[path = "../utils/mod.rs"]
+mod utils;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Parser;
+use jobs::JobQueue;
+use lkernel::Kernel;
+use std::{fs, io::Read, sync::Arc, thread};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const FNAMES_OF_PROGRAM_ARTIFACTS: [&str; 2] = ["vkey.bin", "vkey.hash"];
+const FNAMES_OF_PROOF_ARTIFACTS: [&str; 2] = ["proof.bin", "public-values.bin"];
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// Socket address upon which to listen for requests.
+    #[clap(
+        long,
+        env = "LITMUS_ZK_SERVER_ADDRESS",
+        default_value = "127.0.0.1:7777"
+    )]
+    address: String,
+
+    #[clap(short, long, default_value = "content")]
+    path_to_config: Utf8PathBuf,
+}
+
+fn main() {
+    // Set args.
+    let args = Args::parse();
+    if args.path_to_config.exists() == false {
+        eprintln!("Error: Invalid config file path.");
+        std::process::exit(1);
+    }
+
+    // Set logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Set kernel - shared with job worker.
+    let kernel = Arc::new(Kernel::new(&args.path_to_config));
+    if let Err(err) = kernel.init() {
+        eprintln!("Error: Kernel initialisation failed :: {err}");
+        std::process::exit(1);
+    }
+
+    // Set job queue - shared with request handlers.
+    let queue = Arc::new(JobQueue::new(Arc::clone(&kernel)));
+
+    // Serve requests.
+    let server = Server::http(&args.address).expect("failed to bind server address");
+    println!("SERVER: listening on {}", args.address);
+    for request in server.incoming_requests() {
+        let kernel = Arc::clone(&kernel);
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || do_request(&kernel, &queue, request));
+    }
+}
+
+fn do_request(kernel: &Kernel, queue: &JobQueue, mut request: Request) {
+    let response = match request.method() {
+        Method::Post if request.url() == "/" => {
+            let mut body = String::new();
+            match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => Response::from_string(rpc::handle(kernel, queue, &body)).with_header(
+                    Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap(),
+                ),
+                Err(_) => Response::from_string("Invalid request body").with_status_code(400),
+            }
+        }
+        Method::Get => {
+            match get_path_to_artifact(kernel.prover().path_to_output(), request.url()) {
+                Some(path_to_artifact) => match fs::read(path_to_artifact) {
+                    Ok(bytes) => Response::from_data(bytes),
+                    Err(_) => Response::from_string("Not found").with_status_code(404),
+                },
+                None => Response::from_string("Not found").with_status_code(404),
+            }
+        }
+        _ => Response::from_string("Method not allowed").with_status_code(405),
+    };
+
+    if let Err(err) = request.respond(response) {
+        eprintln!("SERVER: failed to respond: {err}");
+    }
+}
+
+// Maps a request url to an artifact within prover output directory, rejecting any other path.
+fn get_path_to_artifact(path_to_output: &Utf8Path, url: &str) -> Option<Utf8PathBuf> {
+    let segments: Vec<&str> = url.strip_prefix("/artifacts/")?.split('/').collect();
+
+    match segments.as_slice() {
+        [fname] if FNAMES_OF_PROGRAM_ARTIFACTS.contains(fname) => Some(path_to_output.join(fname)),
+        [artifacts_id, fname]
+            if is_artifacts_id(artifacts_id) && FNAMES_OF_PROOF_ARTIFACTS.contains(fname) =>
+        {
+            Some(path_to_output.join(artifacts_id).join(fname))
+        }
+        _ => None,
+    }
+}
+
+// Predicate: is value a hex encoded blake2b digest, i.e. name of a proof artifacts directory ?
+fn is_artifacts_id(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// ------------------------------------------------------------------------
+// Tests.
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTIFACTS_ID: &str = "44682ea86b704fb3c65cd16f84a76b621e04bbdb3746280f25cf062220e471b4";
+
+    #[test]
+    fn test_that_artifact_urls_are_mapped_to_output_directory() {
+        let path_to_output = Utf8Path::new("proofs");
+
+        assert_eq!(
+            get_path_to_artifact(path_to_output, "/artifacts/vkey.hash"),
+            Some(Utf8PathBuf::from("proofs/vkey.hash"))
+        );
+        assert_eq!(
+            get_path_to_artifact(
+                path_to_output,
+                &format!("/artifacts/{ARTIFACTS_ID}/proof.bin")
+            ),
+            Some(Utf8PathBuf::from(format!(
+                "proofs/{ARTIFACTS_ID}/proof.bin"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_that_other_urls_are_rejected() {
+        let path_to_output = Utf8Path::new("proofs");
+
+        for url in [
+            "/",
+            "/artifacts/",
+            "/artifacts/config.toml",
+            "/artifacts/../vkey.bin",
+            "/artifacts/../../etc/passwd",
+            "/artifacts/abc/proof.bin",
+        ] {
+            assert_eq!(get_path_to_artifact(path_to_output, url), None);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use super::jobs::{self, JobId, JobQueue};
+use crate::fixtures::types::WrappedBlockRangeV2WithProofs;
+use lcodecs::binary::Encode;
+use lkernel::{Kernel, KernelError};
+use ltypeset::chain::{BlockHash, BlockID, BlockWithProofs};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sp1_sdk::SP1Stdin;
+use std::io::Error;
+
+// ------------------------------------------------------------------------
+// Constants.
+// ------------------------------------------------------------------------
+
+const JSONRPC_VERSION: &str = "2.0";
+
+// Maximum number of blocks walked forwards from trusted block in order to resolve a request.
+const MAX_CHAIN_LENGTH: u64 = 10_000;
+
+// Standard JSON-RPC error codes.
+const ERR_PARSE: i64 = -32700;
+const ERR_INVALID_REQUEST: i64 = -32600;
+const ERR_METHOD_NOT_FOUND: i64 = -32601;
+const ERR_INVALID_PARAMS: i64 = -32602;
+
+// Application error codes.
+const ERR_BLOCK_UNAVAILABLE: i64 = -32000;
+const ERR_VERIFICATION_FAILURE: i64 = -32001;
+const ERR_JOB_NOT_FOUND: i64 = -32002;
+const ERR_FETCH_FAILURE: i64 = -32003;
+
+// ------------------------------------------------------------------------
+// Declarations.
+// ------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Value,
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ResponseError>,
+    id: Value,
+    jsonrpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+/// Identifier of a block passed as a method parameter, e.g. `{"block_height": 12}`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum BlockIdParam {
+    BlockHash(String),
+    BlockHeight(u64),
+}
+
+#[derive(Debug, Deserialize)]
+struct JobIdParam {
+    job_id: JobId,
+}
+
+// ------------------------------------------------------------------------
+// Constructors.
+// ------------------------------------------------------------------------
+
+impl ResponseError {
+    fn new(code: i64, message: String) -> Self {
+        Self { code, message }
+    }
+
+    fn block_unavailable() -> Self {
+        Self::new(
+            ERR_BLOCK_UNAVAILABLE,
+            String::from("Block is unavailable or not a descendant of trusted block"),
+        )
+    }
+
+    fn fetch_failure(err: Error) -> Self {
+        Self::new(
+            ERR_FETCH_FAILURE,
+            format!("Block could not be fetched :: {err}"),
+        )
+    }
+
+    fn kernel_failure(err: KernelError) -> Self {
+        match err {
+            KernelError::Fetch(inner) => Self::fetch_failure(inner),
+            KernelError::Verification(inner) => {
+                Self::new(ERR_VERIFICATION_FAILURE, inner.to_string())
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Functions.
+// ------------------------------------------------------------------------
+
+/// Handles a JSON encoded JSON-RPC request & returns a JSON encoded response.
+///
+/// Supported methods:
+///
+/// * `get_block` - Returns a block verified by walking forwards from trusted block.
+/// * `prove_block` - Enqueues a job proving a block range from trusted block to a target block.
+/// * `get_job` - Returns status of a proving job.
+///
+/// N.B. Target blocks more than `MAX_CHAIN_LENGTH` blocks above trusted block are rejected.
+///
+/// # Arguments
+///
+/// * `kernel` - Kernel against which to resolve request.
+/// * `queue` - Queue to which proving jobs are submitted.
+/// * `body` - JSON encoded request.
+///
+pub(crate) fn handle(kernel: &Kernel, queue: &JobQueue, body: &str) -> String {
+    let response = match serde_json::from_str::<Request>(body) {
+        Err(err) => to_error(Value::Null, ERR_PARSE, err.to_string()),
+        Ok(request) if request.jsonrpc != JSONRPC_VERSION => to_error(
+            request.id,
+            ERR_INVALID_REQUEST,
+            String::from("Unsupported JSON-RPC version"),
+        ),
+        Ok(request) => {
+            let result = match request.method.as_str() {
+                "get_block" => get_block(kernel, request.params),
+                "get_job" => get_job(queue, request.params),
+                "prove_block" => prove_block(kernel, queue, request.params),
+                _ => Err(ResponseError::new(
+                    ERR_METHOD_NOT_FOUND,
+                    format!("Unknown method: {}", request.method),
+                )),
+            };
+            match result {
+                Ok(inner) => Response {
+                    error: None,
+                    id: request.id,
+                    jsonrpc: JSONRPC_VERSION,
+                    result: Some(inner),
+                },
+                Err(err) => Response {
+                    error: Some(err),
+                    id: request.id,
+                    jsonrpc: JSONRPC_VERSION,
+                    result: None,
+                },
+            }
+        }
+    };
+
+    serde_json::to_string(&response).unwrap()
+}
+
+// ------------------------------------------------------------------------
+// Methods.
+// ------------------------------------------------------------------------
+
+fn get_block(kernel: &Kernel, params: Value) -> Result<Value, ResponseError> {
+    let block_id = parse_block_id(params)?;
+    check_chain_length(kernel, block_id)?;
+    let block_with_proofs = kernel
+        .get_verified_block_with_proofs(block_id)
+        .map_err(ResponseError::kernel_failure)?
+        .ok_or_else(ResponseError::block_unavailable)?;
+
+    Ok(to_block_json(&block_with_proofs))
+}
+
+fn get_job(queue: &JobQueue, params: Value) -> Result<Value, ResponseError> {
+    let JobIdParam { job_id } = serde_json::from_value(params)
+        .map_err(|err| ResponseError::new(ERR_INVALID_PARAMS, err.to_string()))?;
+
+    match queue.get_status(job_id) {
+        Some(status) => {
+            let mut result = serde_json::to_value(status).unwrap();
+            result["job_id"] = json!(job_id);
+            Ok(result)
+        }
+        None => Err(ResponseError::new(
+            ERR_JOB_NOT_FOUND,
+            format!("Unknown job: {job_id}"),
+        )),
+    }
+}
+
+fn prove_block(kernel: &Kernel, queue: &JobQueue, params: Value) -> Result<Value, ResponseError> {
+    let block_id = parse_block_id(params)?;
+    check_chain_length(kernel, block_id)?;
+    let set_of_blocks_with_proofs = kernel
+        .get_chain_of_blocks_with_proofs(block_id)
+        .map_err(ResponseError::fetch_failure)?
+        .ok_or_else(ResponseError::block_unavailable)?;
+    let block_with_proofs = set_of_blocks_with_proofs
+        .last()
+        .ok_or_else(ResponseError::block_unavailable)?;
+    let block_hash = *block_with_proofs.block().hash();
+    let era_consensus_info = kernel
+        .get_era_consensus_info(block_with_proofs)
+        .map_err(ResponseError::kernel_failure)?
+        .ok_or_else(ResponseError::block_unavailable)?;
+    let stdin = SP1Stdin::from(&WrappedBlockRangeV2WithProofs(
+        set_of_blocks_with_proofs,
+        kernel.get_chain_name_digest(),
+        era_consensus_info,
+    ));
+
+    Ok(json!({
+        "block_hash": jobs::to_hex(&block_hash),
+        "job_id": queue.enqueue(block_hash, stdin),
+    }))
+}
+
+// ------------------------------------------------------------------------
+// Helpers.
+// ------------------------------------------------------------------------
+
+// Rejects a target block that is unavailable or too far above trusted block to be walked to.
+fn check_chain_length(kernel: &Kernel, block_id: BlockID) -> Result<(), ResponseError> {
+    let trusted_height = kernel
+        .get_block_with_proofs(None)
+        .map_err(ResponseError::fetch_failure)?
+        .ok_or_else(ResponseError::block_unavailable)?
+        .block()
+        .height()
+        .inner();
+    let target_height = match block_id {
+        BlockID::BlockHash(inner) => kernel
+            .get_block_with_proofs(Some(inner))
+            .map_err(ResponseError::fetch_failure)?
+            .ok_or_else(ResponseError::block_unavailable)?
+            .block()
+            .height()
+            .inner(),
+        BlockID::BlockHeight(inner) => inner.inner(),
+    };
+    if target_height.saturating_sub(trusted_height) >= MAX_CHAIN_LENGTH {
+        return Err(ResponseError::new(
+            ERR_INVALID_PARAMS,
+            format!("Block is more than {MAX_CHAIN_LENGTH} blocks above trusted block"),
+        ));
+    }
+
+    Ok(())
+}
+
+fn parse_block_id(params: Value) -> Result<BlockID, ResponseError> {
+    let param = serde_json::from_value::<BlockIdParam>(params)
+        .map_err(|err| ResponseError::new(ERR_INVALID_PARAMS, err.to_string()))?;
+
+    match param {
+        BlockIdParam::BlockHash(inner) => match hex::decode(&inner) {
+            Ok(bytes) if bytes.len() == 32 => {
+                Ok(BlockID::new_from_hash(BlockHash::from(bytes.as_slice())))
+            }
+            _ => Err(ResponseError::new(
+                ERR_INVALID_PARAMS,
+                String::from("Invalid block hash: expected 64 hex chars"),
+            )),
+        },
+        BlockIdParam::BlockHeight(inner) => Ok(BlockID::new_from_height(inner)),
+    }
+}
+
+// N.B. blocks are rendered as a summary plus their `lcodecs` binary encoding, which clients may decode in full.
+fn to_block_json(block_with_proofs: &BlockWithProofs) -> Value {
+    let block = block_with_proofs.block();
+
+    json!({
+        "block_hash": jobs::to_hex(block.hash()),
+        "encoded": hex::encode(block.encode().unwrap()),
+        "era_id": block.era_id().inner(),
+        "height": block.height().inner(),
+        "is_switch": block.is_switch(),
+        "signatures": block_with_proofs.proofs().len(),
+        "state_root_hash": hex::encode(block.state_root_hash().as_slice()),
+    })
+}
+
+fn to_error(id: Value, code: i64, message: String) -> Response {
+    Response {
+        error: Some(ResponseError::new(code, message)),
+        id,
+        jsonrpc: JSONRPC_VERSION,
+        result: None,
+    }
+}
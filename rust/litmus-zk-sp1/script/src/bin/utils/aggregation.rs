@@ -0,0 +1,80 @@
+use camino::{Utf8Path, Utf8PathBuf};
+use ltypeset::{
+    chain::{BlockHash, EraId},
+    crypto::Digest,
+    verification::EraPublicValues,
+};
+use sp1_sdk::{HashableKey, SP1ProofWithPublicValues, SP1VerifyingKey};
+use std::fs;
+
+// Name of file containing most recent proof within a chain of era proofs.
+const FNAME_OF_LATEST_PROOF: &str = "latest.bin";
+
+/// Returns most recent proof within a chain of era proofs (if any).
+///
+/// # Arguments
+///
+/// * `path_to_proofs` - Directory within which chain of era proofs is stored.
+///
+pub(crate) fn get_latest_proof(path_to_proofs: &Utf8Path) -> Option<SP1ProofWithPublicValues> {
+    let path_to_proof = path_to_proofs.join(FNAME_OF_LATEST_PROOF);
+    if !path_to_proof.exists() {
+        return None;
+    }
+
+    Some(SP1ProofWithPublicValues::load(path_to_proof).unwrap())
+}
+
+/// Asserts that an era proof's public values were committed by a program & anchored by a trusted block.
+///
+/// N.B. First proof within a chain of era proofs commits a verification key hash supplied by host,
+/// hence the committed hash must be checked against that of the program that is trusted.
+///
+/// # Arguments
+///
+/// * `public_values` - Values committed by an era proof.
+/// * `vkey` - Verification key of trusted program.
+/// * `trusted_block_hash` - Hash of trusted block from which chain of era proofs starts.
+///
+pub(crate) fn assert_era_public_values(
+    public_values: &EraPublicValues,
+    vkey: &SP1VerifyingKey,
+    trusted_block_hash: &BlockHash,
+) {
+    assert_eq!(
+        public_values.vkey_hash(),
+        &Digest::from(vkey.hash_bytes().as_slice()),
+        "Era proof was issued by a different program"
+    );
+    assert_eq!(
+        public_values.trusted_block_hash(),
+        trusted_block_hash,
+        "Era proof is not anchored by trusted block"
+    );
+}
+
+/// Persists a proof as most recent within a chain of era proofs, retaining a copy per era.
+///
+/// # Arguments
+///
+/// * `path_to_proofs` - Directory within which chain of era proofs is stored.
+/// * `proof` - Proof extending chain of era proofs.
+/// * `era_id` - ID of era of switch block verified by proof.
+///
+pub(crate) fn set_latest_proof(
+    path_to_proofs: &Utf8Path,
+    proof: &SP1ProofWithPublicValues,
+    era_id: &EraId,
+) {
+    fs::create_dir_all(path_to_proofs).unwrap();
+    proof
+        .save(get_path_to_era_proof(path_to_proofs, era_id))
+        .unwrap();
+    proof
+        .save(path_to_proofs.join(FNAME_OF_LATEST_PROOF))
+        .unwrap();
+}
+
+fn get_path_to_era_proof(path_to_proofs: &Utf8Path, era_id: &EraId) -> Utf8PathBuf {
+    path_to_proofs.join(format!("era-{}.bin", era_id.inner()))
+}
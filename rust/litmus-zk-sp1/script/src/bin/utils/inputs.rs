@@ -1,66 +1,149 @@
 use crate::fixtures::types::{
-    Fixtures, WrappedBlockV1WithProofs, WrappedBlockV2WithProofs, WrappedDigest, WrappedSignature,
+    Fixtures, WrappedBlockRangeV2WithProofs, WrappedBlockV1WithProofs, WrappedBlockV2WithProofs,
+    WrappedDigest, WrappedEraTransitionV2WithProofs, WrappedSignature, WrappedStateV2Inclusion,
+    WrappedSwitchBlockV2WithProofs, WrappedTransactionV2Inclusion,
 };
-use sp1_sdk::SP1Stdin;
+use lrequests::VerificationRequest;
+use ltypeset::crypto::Digest;
+use sp1_sdk::{HashableKey, SP1Proof, SP1Stdin};
 
-const VERIFICATION_TYPE_DIGEST: u8 = 0;
-const VERIFICATION_TYPE_SIGNATURE: u8 = 1;
-const VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS: u8 = 10;
-const VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS: u8 = 11;
+/// Returns a digest over a set of program inputs, e.g. for use as a cache key.
+///
+/// # Arguments
+///
+/// * `stdin` - Program inputs.
+///
+pub(crate) fn get_digest_of_stdin(stdin: &SP1Stdin) -> Digest {
+    let mut bytes: Vec<u8> = Vec::new();
+    for item in &stdin.buffer {
+        bytes.extend_from_slice((item.len() as u64).to_le_bytes().as_slice());
+        bytes.extend_from_slice(item);
+    }
+
+    Digest::from(lcrypto::get_hash_blake2b(bytes).as_slice())
+}
 
 impl From<Fixtures> for Vec<SP1Stdin> {
     fn from(value: Fixtures) -> Self {
         let mut result: Vec<SP1Stdin> = Vec::new();
         for item in value.set_of_digests {
-            result.push(SP1Stdin::try_from(&item).unwrap());
+            result.push(SP1Stdin::from(&item));
         }
         for item in value.set_of_signatures {
-            result.push(SP1Stdin::try_from(&item).unwrap());
+            result.push(SP1Stdin::from(&item));
         }
 
         result
     }
 }
 
-impl From<&WrappedDigest> for SP1Stdin {
-    fn from(value: &WrappedDigest) -> Self {
+impl From<&VerificationRequest> for SP1Stdin {
+    fn from(value: &VerificationRequest) -> Self {
         let mut vm_stdin = Self::new();
-        vm_stdin.write(&VERIFICATION_TYPE_DIGEST);
-        vm_stdin.write_vec(serde_cbor::to_vec(value.inner()).unwrap());
-        vm_stdin.write_vec(value.msg());
+        vm_stdin.write_vec(value.encode().unwrap());
 
         vm_stdin
     }
 }
 
+impl From<&WrappedDigest> for SP1Stdin {
+    fn from(value: &WrappedDigest) -> Self {
+        Self::from(&VerificationRequest::Digest {
+            digest: value.inner().to_owned(),
+            msg: value.msg(),
+        })
+    }
+}
+
 impl From<&WrappedSignature> for SP1Stdin {
     fn from(value: &WrappedSignature) -> Self {
-        let mut vm_stdin = Self::new();
-        vm_stdin.write(&VERIFICATION_TYPE_SIGNATURE);
-        vm_stdin.write_vec(serde_cbor::to_vec(value.sig()).unwrap());
-        vm_stdin.write_vec(serde_cbor::to_vec(value.vkey()).unwrap());
-        vm_stdin.write_vec(serde_cbor::to_vec(value.msg()).unwrap());
-
-        vm_stdin
+        Self::from(&VerificationRequest::Signature {
+            digest: value.msg().to_owned(),
+            sig: value.sig().to_owned(),
+            vkey: value.vkey().to_owned(),
+        })
     }
 }
 
 impl From<&WrappedBlockV1WithProofs> for SP1Stdin {
     fn from(value: &WrappedBlockV1WithProofs) -> Self {
-        let mut vm_stdin = Self::new();
-        vm_stdin.write(&VERIFICATION_TYPE_BLOCK_V1_WITH_PROOFS);
-        vm_stdin.write_vec(serde_cbor::to_vec(value.inner()).unwrap());
-
-        vm_stdin
+        Self::from(&VerificationRequest::BlockV1WithProofs {
+            block_with_proofs: value.inner().to_owned(),
+            era_consensus_info: value.era_consensus_info().to_owned(),
+        })
     }
 }
 
 impl From<&WrappedBlockV2WithProofs> for SP1Stdin {
     fn from(value: &WrappedBlockV2WithProofs) -> Self {
-        let mut vm_stdin = Self::new();
-        vm_stdin.write(&VERIFICATION_TYPE_BLOCK_V2_WITH_PROOFS);
-        vm_stdin.write_vec(serde_cbor::to_vec(value.inner()).unwrap());
-        vm_stdin.write_vec(serde_cbor::to_vec(value.chain_name_digest()).unwrap());
+        Self::from(&VerificationRequest::BlockV2WithProofs {
+            block_with_proofs: value.inner().to_owned(),
+            chain_name_digest: value.chain_name_digest().to_owned(),
+            era_consensus_info: value.era_consensus_info().to_owned(),
+        })
+    }
+}
+
+impl From<&WrappedBlockRangeV2WithProofs> for SP1Stdin {
+    fn from(value: &WrappedBlockRangeV2WithProofs) -> Self {
+        Self::from(&VerificationRequest::BlockRangeV2WithProofs {
+            chain_name_digest: value.chain_name_digest().to_owned(),
+            era_consensus_info: value.era_consensus_info().to_owned(),
+            set_of_blocks_with_proofs: value.inner().to_owned(),
+        })
+    }
+}
+
+impl From<&WrappedTransactionV2Inclusion> for SP1Stdin {
+    fn from(value: &WrappedTransactionV2Inclusion) -> Self {
+        Self::from(&VerificationRequest::TransactionV2Inclusion {
+            block_with_proofs: value.inner().to_owned(),
+            category: value.category(),
+            chain_name_digest: value.chain_name_digest().to_owned(),
+            era_consensus_info: value.era_consensus_info().to_owned(),
+            transaction_hash: value.transaction_hash().to_owned(),
+        })
+    }
+}
+
+impl From<&WrappedStateV2Inclusion> for SP1Stdin {
+    fn from(value: &WrappedStateV2Inclusion) -> Self {
+        Self::from(&VerificationRequest::StateV2Inclusion {
+            block_with_proofs: value.inner().to_owned(),
+            chain_name_digest: value.chain_name_digest().to_owned(),
+            era_consensus_info: value.era_consensus_info().to_owned(),
+            proof: value.proof().to_owned(),
+        })
+    }
+}
+
+impl From<&WrappedSwitchBlockV2WithProofs> for SP1Stdin {
+    fn from(value: &WrappedSwitchBlockV2WithProofs) -> Self {
+        Self::from(&VerificationRequest::SwitchBlockV2WithProofs {
+            block_with_proofs: value.inner().to_owned(),
+            chain_name_digest: value.chain_name_digest().to_owned(),
+            vkey_hash: value.vkey_hash().to_owned(),
+        })
+    }
+}
+
+impl From<&WrappedEraTransitionV2WithProofs> for SP1Stdin {
+    fn from(value: &WrappedEraTransitionV2WithProofs) -> Self {
+        let mut vm_stdin = Self::from(&VerificationRequest::EraTransitionV2WithProofs {
+            block_with_proofs: value.inner().to_owned(),
+            chain_name_digest: value.chain_name_digest().to_owned(),
+            previous_block_with_proofs: value.previous_inner().to_owned(),
+            previous_public_values: value.previous_proof().public_values.to_vec(),
+            vkey_hash: value.vkey().hash_u32(),
+        });
+
+        // N.B. previous proof is verified by zk-vm program as a deferred proof.
+        match &value.previous_proof().proof {
+            SP1Proof::Compressed(proof) => {
+                vm_stdin.write_proof(*proof.to_owned(), value.vkey().vk.to_owned())
+            }
+            _ => panic!("Previous era proof must be a compressed proof"),
+        }
 
         vm_stdin
     }
@@ -1,2 +1,4 @@
+pub(crate) mod aggregation;
 pub(crate) mod fsys;
-mod inputs;
+pub(crate) mod inputs;
+pub(crate) mod outputs;
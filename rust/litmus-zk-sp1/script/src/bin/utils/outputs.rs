@@ -0,0 +1,12 @@
+use lcodecs::binary::Decode;
+use ltypeset::verification::PublicValues;
+
+/// Decodes public values committed by zk-vm program.
+///
+/// # Arguments
+///
+/// * `bytes` - Binary encoded public values, e.g. as exposed by a proof or an execution.
+///
+pub(crate) fn decode_public_values(bytes: &[u8]) -> PublicValues {
+    PublicValues::decode_exact(bytes).expect("Invalid public values encoding")
+}